@@ -0,0 +1,205 @@
+//! Binary data of XML Schema: `xs:base64Binary` and `xs:hexBinary`, decoded into bytes.
+
+use crate::text_content::text_value;
+use std::fmt::{self, Display};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+const BASE64_ALPHABET: &[u8; 64] =
+  b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Bytes encoded in base 64, whitespaces being allowed between the characters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Base64Binary(pub Vec<u8>);
+
+/// Bytes encoded in hexadecimal, written with upper case digits.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HexBinary(pub Vec<u8>);
+
+/// Access to the decoded bytes.
+macro_rules! bytes {
+  ($($name:ident),*) => {
+    $(
+      impl $name {
+        pub fn into_inner(self) -> Vec<u8> {
+          self.0
+        }
+      }
+
+      impl Deref for $name {
+        type Target = Vec<u8>;
+
+        fn deref(&self) -> &Self::Target {
+          &self.0
+        }
+      }
+
+      impl DerefMut for $name {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+          &mut self.0
+        }
+      }
+
+      impl From<Vec<u8>> for $name {
+        fn from(bytes: Vec<u8>) -> Self {
+          $name(bytes)
+        }
+      }
+
+      impl From<$name> for Vec<u8> {
+        fn from(value: $name) -> Self {
+          value.0
+        }
+      }
+    )*
+  };
+}
+
+bytes!(Base64Binary, HexBinary);
+
+impl FromStr for Base64Binary {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let invalid = |reason: &str| format!("Invalid xs:base64Binary value {value:?}: {reason}");
+
+    let characters: Vec<u8> = value
+      .bytes()
+      .filter(|byte| !byte.is_ascii_whitespace())
+      .collect();
+    if !characters.len().is_multiple_of(4) {
+      return Err(invalid("length is not a multiple of 4"));
+    }
+
+    let padding = characters
+      .iter()
+      .rev()
+      .take_while(|byte| **byte == b'=')
+      .count();
+    if padding > 2 {
+      return Err(invalid("too much padding"));
+    }
+
+    let mut bytes = Vec::with_capacity(characters.len() / 4 * 3);
+    let mut buffer = 0_u32;
+    for (index, character) in characters[..characters.len() - padding].iter().enumerate() {
+      let sextet = BASE64_ALPHABET
+        .iter()
+        .position(|byte| byte == character)
+        .ok_or_else(|| invalid(&format!("unexpected character {:?}", *character as char)))?;
+
+      buffer = buffer << 6 | sextet as u32;
+      if index % 4 == 3 {
+        bytes.extend_from_slice(&buffer.to_be_bytes()[1..]);
+        buffer = 0;
+      }
+    }
+
+    // the bits of the last characters not part of a byte must be zeros
+    match padding {
+      1 if buffer & 0x3 == 0 => bytes.extend_from_slice(&(buffer >> 2).to_be_bytes()[2..]),
+      2 if buffer & 0xf == 0 => bytes.push((buffer >> 4) as u8),
+      0 => {}
+      _ => return Err(invalid("unexpected bits before the padding")),
+    }
+
+    Ok(Base64Binary(bytes))
+  }
+}
+
+impl Display for Base64Binary {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    for chunk in self.0.chunks(3) {
+      let mut buffer = [0_u8; 4];
+      buffer[1..=chunk.len()].copy_from_slice(chunk);
+      let bits = u32::from_be_bytes(buffer);
+
+      for index in 0..4 {
+        if index <= chunk.len() {
+          let sextet = (bits >> (18 - 6 * index)) & 0x3f;
+          write!(formatter, "{}", BASE64_ALPHABET[sextet as usize] as char)?;
+        } else {
+          write!(formatter, "=")?;
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for HexBinary {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let invalid = |reason: &str| format!("Invalid xs:hexBinary value {value:?}: {reason}");
+
+    if !value.len().is_multiple_of(2) {
+      return Err(invalid("odd number of digits"));
+    }
+
+    value
+      .as_bytes()
+      .chunks(2)
+      .map(|pair| {
+        std::str::from_utf8(pair)
+          .ok()
+          .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_hexdigit()))
+          .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+          .ok_or_else(|| invalid("unexpected character"))
+      })
+      .collect::<Result<Vec<u8>, String>>()
+      .map(HexBinary)
+  }
+}
+
+impl Display for HexBinary {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    for byte in &self.0 {
+      write!(formatter, "{byte:02X}")?;
+    }
+
+    Ok(())
+  }
+}
+
+text_value!(Base64Binary, HexBinary);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn base64() {
+    for (bytes, encoded) in [
+      (&b""[..], ""),
+      (b"f", "Zg=="),
+      (b"fo", "Zm8="),
+      (b"foo", "Zm9v"),
+      (b"foob", "Zm9vYg=="),
+      (&[0xff, 0xfe, 0x00, 0x10], "//4AEA=="),
+    ] {
+      let value: Base64Binary = encoded.parse().unwrap();
+      assert_eq!(*value, bytes);
+      assert_eq!(value.to_string(), encoded);
+    }
+
+    assert_eq!(*"Zm9v\n  Yg ==".parse::<Base64Binary>().unwrap(), b"foob");
+
+    assert!("Zm9".parse::<Base64Binary>().is_err());
+    assert!("Zm9v!A==".parse::<Base64Binary>().is_err());
+    assert!("Zh==".parse::<Base64Binary>().is_err());
+    assert!("Z===".parse::<Base64Binary>().is_err());
+  }
+
+  #[test]
+  fn hex() {
+    let value: HexBinary = "0fB7".parse().unwrap();
+    assert_eq!(*value, vec![0x0f, 0xb7]);
+    assert_eq!(value.to_string(), "0FB7");
+
+    assert!("0FB".parse::<HexBinary>().is_err());
+    assert!("0G".parse::<HexBinary>().is_err());
+    assert!("+1".parse::<HexBinary>().is_err());
+  }
+}
@@ -0,0 +1,15 @@
+//! Generate the types of XML Schemas from a build script, with the `build` feature.
+//!
+//! ```no_run
+//! // build.rs
+//! xml_schema::build::Builder::new()
+//!   .with_source("schemas/library.xsd")
+//!   .write()
+//!   .unwrap();
+//! ```
+//!
+//! The generated file is included in the crate with
+//! `include!(concat!(env!("OUT_DIR"), "/xml_schema.rs"));`.
+
+pub use xml_schema_generator::errors::Error;
+pub use xml_schema_generator::Builder;
@@ -0,0 +1,206 @@
+//! YaSerDe helpers for the enums generated from `xs:choice` content models.
+//!
+//! The generated enums are flattened in their parent structure: they are deserialized from
+//! the children elements which are not matched by the other fields of the parent.
+
+use crate::text_content;
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+/// Implemented by the enums generated for `xs:choice`, one alternative per element or nested
+/// sequence.
+pub trait Choice: Sized {
+  /// Deserialize the alternative matching the element the reader is positioned on,
+  /// returns `None` if this element is not one of the alternatives.
+  fn deserialize_alternative<R: Read>(
+    element_name: &str,
+    reader: &mut Deserializer<R>,
+  ) -> Option<Result<Self, String>>;
+}
+
+/// Repeated `xs:choice`, the alternatives are kept in document order. At least `MIN` of them are
+/// deserialized.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Choices<T, const MIN: usize = 0> {
+  pub items: Vec<T>,
+}
+
+impl<T: Choice, const MIN: usize> YaDeserialize for Choices<T, MIN> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let items = deserialize_all(reader)?;
+    if items.len() < MIN {
+      return Err(format!(
+        "Missing elements for the choice, expected at least {MIN}, got {}",
+        items.len()
+      ));
+    }
+
+    Ok(Choices { items })
+  }
+}
+
+impl<T: YaSerialize, const MIN: usize> YaSerialize for Choices<T, MIN> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    for item in &self.items {
+      item.serialize(writer)?;
+    }
+    Ok(())
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Optional `xs:choice`, a present alternative which does not deserialize is an error.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OptionalChoice<T> {
+  pub item: Option<T>,
+}
+
+impl<T> From<T> for OptionalChoice<T> {
+  fn from(item: T) -> Self {
+    OptionalChoice { item: Some(item) }
+  }
+}
+
+impl<T: Choice> YaDeserialize for OptionalChoice<T> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    Ok(OptionalChoice {
+      item: deserialize_all(reader)?.into_iter().next(),
+    })
+  }
+}
+
+impl<T: YaSerialize> YaSerialize for OptionalChoice<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    match &self.item {
+      Some(item) => item.serialize(writer),
+      None => Ok(()),
+    }
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Deserialize the first alternative found in the children of the current element.
+pub fn deserialize<T: Choice, R: Read>(reader: &mut Deserializer<R>) -> Result<T, String> {
+  deserialize_all(reader)?
+    .into_iter()
+    .next()
+    .ok_or_else(|| "Missing element for the choice".to_string())
+}
+
+/// Deserialize all the alternatives found in the children of the current element,
+/// other elements are skipped.
+pub fn deserialize_all<T: Choice, R: Read>(reader: &mut Deserializer<R>) -> Result<Vec<T>, String> {
+  if let XmlEvent::StartElement { .. } = reader.peek()? {
+    reader.next_event()?;
+  }
+
+  let mut items = vec![];
+  loop {
+    match reader.peek()?.to_owned() {
+      XmlEvent::StartElement { name, .. } => {
+        match T::deserialize_alternative(&name.local_name, reader) {
+          Some(item) => items.push(item?),
+          None => {
+            reader.next_event()?;
+            reader.skip_element(|_| {})?;
+          }
+        }
+      }
+      XmlEvent::EndElement { .. } | XmlEvent::EndDocument => break,
+      _ => {
+        reader.next_event()?;
+      }
+    }
+  }
+
+  Ok(items)
+}
+
+/// Deserialize an alternative with a structured content, consuming its end element.
+pub fn deserialize_element<T: YaDeserialize, R: Read>(
+  reader: &mut Deserializer<R>,
+) -> Result<T, String> {
+  let value = T::deserialize(reader)?;
+  reader.next_event()?;
+  Ok(value)
+}
+
+/// Deserialize an alternative with a text content, consuming its end element.
+pub fn deserialize_text<T, R>(reader: &mut Deserializer<R>) -> Result<T, String>
+where
+  T: FromStr,
+  T::Err: ToString,
+  R: Read,
+{
+  let value = text_content::deserialize(reader)?;
+  reader.next_event()?;
+  Ok(value)
+}
+
+/// Skip the text before the next element of a sequence alternative, and tell whether it is named
+/// `element_name`. The reader is left on this element.
+pub fn next_element_is<R: Read>(
+  element_name: &str,
+  reader: &mut Deserializer<R>,
+) -> Result<bool, String> {
+  loop {
+    match reader.peek()?.to_owned() {
+      XmlEvent::StartElement { name, .. } => return Ok(name.local_name == element_name),
+      XmlEvent::EndElement { .. } | XmlEvent::EndDocument => return Ok(false),
+      _ => {
+        reader.next_event()?;
+      }
+    }
+  }
+}
+
+/// Serialize an alternative with a structured content.
+pub fn serialize_element<T: YaSerialize, W: Write>(
+  value: &T,
+  element_name: &str,
+  writer: &mut Serializer<W>,
+) -> Result<(), String> {
+  writer.set_start_event_name(Some(element_name.to_string()));
+  writer.set_skip_start_end(false);
+  value.serialize(writer)
+}
+
+/// Serialize an alternative with a text content.
+pub fn serialize_text<T: Display, W: Write>(
+  value: &T,
+  element_name: &str,
+  writer: &mut Serializer<W>,
+) -> Result<(), String> {
+  writer.set_start_event_name(None);
+  writer.set_skip_start_end(false);
+  text_content::serialize(value, element_name, writer)
+}
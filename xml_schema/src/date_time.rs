@@ -0,0 +1,1061 @@
+//! Date and time types of XML Schema: `xs:dateTime`, `xs:dateTimeStamp`, `xs:date`, `xs:time`,
+//! `xs:duration`, `xs:gYear`, `xs:gYearMonth`, `xs:gMonthDay`, `xs:gDay` and `xs:gMonth`.
+//!
+//! The values are parsed and formatted following their lexical representation, the timezone
+//! being optional. With the `chrono` feature, the `chrono` module provides the same values
+//! backed by chrono types.
+
+use crate::text_content::text_value;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+
+/// Types used by the generated code for `xs:dateTime`, `xs:dateTimeStamp`, `xs:date` and
+/// `xs:time`: the ones of the `chrono` module when this crate has the `chrono` feature.
+pub mod generated {
+  #[cfg(feature = "chrono")]
+  pub use super::chrono::{Date, DateTime, DateTimeStamp, Time};
+  #[cfg(not(feature = "chrono"))]
+  pub use super::{Date, DateTime, DateTimeStamp, Time};
+}
+
+/// Timezone of a value, as an offset from UTC in minutes. `Z` is an offset of 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TimeZone {
+  pub offset_minutes: i16,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DateTime {
+  pub year: i32,
+  pub month: u8,
+  pub day: u8,
+  pub hour: u8,
+  pub minute: u8,
+  pub second: u8,
+  pub nanosecond: u32,
+  pub timezone: Option<TimeZone>,
+}
+
+/// `xs:dateTime` with a required timezone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeStamp(DateTime);
+
+#[derive(Clone, Copy, Debug)]
+pub struct Date {
+  pub year: i32,
+  pub month: u8,
+  pub day: u8,
+  pub timezone: Option<TimeZone>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Time {
+  pub hour: u8,
+  pub minute: u8,
+  pub second: u8,
+  pub nanosecond: u32,
+  pub timezone: Option<TimeZone>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Duration {
+  pub negative: bool,
+  pub years: u32,
+  pub months: u32,
+  pub days: u32,
+  pub hours: u32,
+  pub minutes: u32,
+  pub seconds: u32,
+  pub nanoseconds: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GYear {
+  pub year: i32,
+  pub timezone: Option<TimeZone>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GYearMonth {
+  pub year: i32,
+  pub month: u8,
+  pub timezone: Option<TimeZone>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GMonthDay {
+  pub month: u8,
+  pub day: u8,
+  pub timezone: Option<TimeZone>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GDay {
+  pub day: u8,
+  pub timezone: Option<TimeZone>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GMonth {
+  pub month: u8,
+  pub timezone: Option<TimeZone>,
+}
+
+impl Default for DateTime {
+  fn default() -> Self {
+    let date = Date::default();
+    DateTime {
+      year: date.year,
+      month: date.month,
+      day: date.day,
+      hour: 0,
+      minute: 0,
+      second: 0,
+      nanosecond: 0,
+      timezone: None,
+    }
+  }
+}
+
+impl Default for DateTimeStamp {
+  fn default() -> Self {
+    DateTimeStamp(DateTime {
+      timezone: Some(TimeZone::default()),
+      ..DateTime::default()
+    })
+  }
+}
+
+impl Default for Date {
+  fn default() -> Self {
+    Date {
+      year: 1970,
+      month: 1,
+      day: 1,
+      timezone: None,
+    }
+  }
+}
+
+impl Default for GYear {
+  fn default() -> Self {
+    GYear {
+      year: 1970,
+      timezone: None,
+    }
+  }
+}
+
+impl Default for GYearMonth {
+  fn default() -> Self {
+    GYearMonth {
+      year: 1970,
+      month: 1,
+      timezone: None,
+    }
+  }
+}
+
+impl Default for GMonthDay {
+  fn default() -> Self {
+    GMonthDay {
+      month: 1,
+      day: 1,
+      timezone: None,
+    }
+  }
+}
+
+impl Default for GDay {
+  fn default() -> Self {
+    GDay {
+      day: 1,
+      timezone: None,
+    }
+  }
+}
+
+impl Default for GMonth {
+  fn default() -> Self {
+    GMonth {
+      month: 1,
+      timezone: None,
+    }
+  }
+}
+
+impl DateTime {
+  pub fn date(&self) -> Date {
+    Date {
+      year: self.year,
+      month: self.month,
+      day: self.day,
+      timezone: self.timezone,
+    }
+  }
+
+  pub fn time(&self) -> Time {
+    Time {
+      hour: self.hour,
+      minute: self.minute,
+      second: self.second,
+      nanosecond: self.nanosecond,
+      timezone: self.timezone,
+    }
+  }
+}
+
+impl DateTimeStamp {
+  pub fn value(&self) -> &DateTime {
+    &self.0
+  }
+}
+
+impl DateTime {
+  fn instant(&self) -> Instant {
+    let days = days_from_civil(self.year, self.month, self.day);
+    Instant::new(
+      self.timezone,
+      days * 86_400 + self.time().seconds_of_day(),
+      self.nanosecond,
+    )
+  }
+}
+
+impl Date {
+  fn instant(&self) -> Instant {
+    let days = days_from_civil(self.year, self.month, self.day);
+    Instant::new(self.timezone, days * 86_400, 0)
+  }
+}
+
+/// Values are equal when they are the same instant, like `10:00:00Z` and `12:00:00+02:00`, and
+/// are ordered on the timeline when they both have a timezone or both have none, they are not
+/// comparable otherwise.
+macro_rules! timeline_ordering {
+  ($($name:ident),*) => {
+    $(
+      impl PartialEq for $name {
+        fn eq(&self, other: &Self) -> bool {
+          self.instant() == other.instant()
+        }
+      }
+
+      impl Eq for $name {}
+
+      impl Hash for $name {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+          self.instant().hash(state)
+        }
+      }
+
+      impl PartialOrd for $name {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+          self.instant().partial_cmp(&other.instant())
+        }
+      }
+    )*
+  };
+}
+
+timeline_ordering!(DateTime, Date, Time);
+
+impl Ord for DateTimeStamp {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self
+      .0
+      .instant()
+      .timeline()
+      .cmp(&other.0.instant().timeline())
+  }
+}
+
+impl PartialOrd for DateTimeStamp {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Time {
+  fn seconds_of_day(&self) -> i64 {
+    i64::from(self.hour) * 3600 + i64::from(self.minute) * 60 + i64::from(self.second)
+  }
+
+  fn instant(&self) -> Instant {
+    Instant::new(self.timezone, self.seconds_of_day(), self.nanosecond)
+  }
+}
+
+impl FromStr for TimeZone {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    parse_timezone(value)
+      .flatten()
+      .ok_or_else(|| format!("Invalid timezone {value:?}"))
+  }
+}
+
+impl FromStr for DateTime {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (year, month, day, rest) = take_date(value)?;
+      let (hour, minute, second, nanosecond, rest) = take_time(rest.strip_prefix('T')?)?;
+      Some(DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanosecond,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("dateTime", value))
+  }
+}
+
+impl FromStr for DateTimeStamp {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    DateTimeStamp::try_from(value.parse::<DateTime>()?)
+      .map_err(|_| invalid_value("dateTimeStamp", value))
+  }
+}
+
+impl TryFrom<DateTime> for DateTimeStamp {
+  type Error = String;
+
+  fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+    if value.timezone.is_some() {
+      Ok(DateTimeStamp(value))
+    } else {
+      Err(format!(
+        "Invalid xs:dateTimeStamp value {value}, without timezone"
+      ))
+    }
+  }
+}
+
+impl FromStr for Date {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (year, month, day, rest) = take_date(value)?;
+      Some(Date {
+        year,
+        month,
+        day,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("date", value))
+  }
+}
+
+impl FromStr for Time {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (hour, minute, second, nanosecond, rest) = take_time(value)?;
+      Some(Time {
+        hour,
+        minute,
+        second,
+        nanosecond,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("time", value))
+  }
+}
+
+impl FromStr for Duration {
+  type Err = String;
+
+  /// Lexical representation `-?PnYnMnDTnHnMnS`, each component being optional but at least
+  /// one being present.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (negative, rest) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+      };
+      let rest = rest.strip_prefix('P')?;
+      let (date_part, time_part) = match rest.find('T') {
+        Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+        None => (rest, None),
+      };
+
+      let mut duration = Duration {
+        negative,
+        ..Duration::default()
+      };
+
+      let mut designators = "YMD".chars();
+      let mut rest = date_part;
+      while !rest.is_empty() {
+        let (number, designator, remaining) = take_component(rest)?;
+        designators.find(|expected| *expected == designator)?;
+        let number = number.parse().ok()?;
+        match designator {
+          'Y' => duration.years = number,
+          'M' => duration.months = number,
+          _ => duration.days = number,
+        }
+        rest = remaining;
+      }
+
+      if let Some(time_part) = time_part {
+        if time_part.is_empty() {
+          return None;
+        }
+
+        let mut designators = "HMS".chars();
+        let mut rest = time_part;
+        while !rest.is_empty() {
+          let (number, designator, remaining) = take_component(rest)?;
+          designators.find(|expected| *expected == designator)?;
+          match designator {
+            'H' => duration.hours = number.parse().ok()?,
+            'M' => duration.minutes = number.parse().ok()?,
+            _ => {
+              let (seconds, nanoseconds) = take_seconds(number)?;
+              duration.seconds = seconds;
+              duration.nanoseconds = nanoseconds;
+            }
+          }
+          rest = remaining;
+        }
+      } else if date_part.is_empty() {
+        return None;
+      }
+
+      Some(duration)
+    };
+
+    parse().ok_or_else(|| invalid_value("duration", value))
+  }
+}
+
+impl FromStr for GYear {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (year, rest) = take_year(value)?;
+      Some(GYear {
+        year,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("gYear", value))
+  }
+}
+
+impl FromStr for GYearMonth {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (year, rest) = take_year(value)?;
+      let (month, rest) = take_number(rest.strip_prefix('-')?, 2)?;
+      if !(1..=12).contains(&month) {
+        return None;
+      }
+
+      Some(GYearMonth {
+        year,
+        month: month as u8,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("gYearMonth", value))
+  }
+}
+
+impl FromStr for GMonthDay {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (month, rest) = take_number(value.strip_prefix("--")?, 2)?;
+      let (day, rest) = take_number(rest.strip_prefix('-')?, 2)?;
+      if !(1..=12).contains(&month) || day < 1 || day > days_in_month(None, month) {
+        return None;
+      }
+
+      Some(GMonthDay {
+        month: month as u8,
+        day: day as u8,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("gMonthDay", value))
+  }
+}
+
+impl FromStr for GDay {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (day, rest) = take_number(value.strip_prefix("---")?, 2)?;
+      if !(1..=31).contains(&day) {
+        return None;
+      }
+
+      Some(GDay {
+        day: day as u8,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("gDay", value))
+  }
+}
+
+impl FromStr for GMonth {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let parse = || {
+      let (month, rest) = take_number(value.strip_prefix("--")?, 2)?;
+      if !(1..=12).contains(&month) {
+        return None;
+      }
+
+      Some(GMonth {
+        month: month as u8,
+        timezone: parse_timezone(rest)?,
+      })
+    };
+
+    parse().ok_or_else(|| invalid_value("gMonth", value))
+  }
+}
+
+impl Display for TimeZone {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    if self.offset_minutes == 0 {
+      return write!(formatter, "Z");
+    }
+
+    let sign = if self.offset_minutes < 0 { '-' } else { '+' };
+    let offset = self.offset_minutes.unsigned_abs();
+    write!(formatter, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+  }
+}
+
+impl Display for DateTime {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    format_year(formatter, self.year)?;
+    write!(formatter, "-{:02}-{:02}T", self.month, self.day)?;
+    format_time(
+      formatter,
+      self.hour,
+      self.minute,
+      self.second,
+      self.nanosecond,
+    )?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+impl Display for DateTimeStamp {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+impl Display for Date {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    format_year(formatter, self.year)?;
+    write!(formatter, "-{:02}-{:02}", self.month, self.day)?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+impl Display for Time {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    format_time(
+      formatter,
+      self.hour,
+      self.minute,
+      self.second,
+      self.nanosecond,
+    )?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+impl Display for Duration {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    if self.negative {
+      write!(formatter, "-")?;
+    }
+    write!(formatter, "P")?;
+
+    for (number, designator) in [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+      if number != 0 {
+        write!(formatter, "{number}{designator}")?;
+      }
+    }
+
+    let has_date = self.years != 0 || self.months != 0 || self.days != 0;
+    let has_seconds = self.seconds != 0 || self.nanoseconds != 0;
+    if self.hours == 0 && self.minutes == 0 && !has_seconds {
+      return if has_date {
+        Ok(())
+      } else {
+        write!(formatter, "T0S")
+      };
+    }
+
+    write!(formatter, "T")?;
+    if self.hours != 0 {
+      write!(formatter, "{}H", self.hours)?;
+    }
+    if self.minutes != 0 {
+      write!(formatter, "{}M", self.minutes)?;
+    }
+    if has_seconds {
+      write!(formatter, "{}", self.seconds)?;
+      format_fraction(formatter, self.nanoseconds)?;
+      write!(formatter, "S")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Display for GYear {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    format_year(formatter, self.year)?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+impl Display for GYearMonth {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    format_year(formatter, self.year)?;
+    write!(formatter, "-{:02}", self.month)?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+impl Display for GMonthDay {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "--{:02}-{:02}", self.month, self.day)?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+impl Display for GDay {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "---{:02}", self.day)?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+impl Display for GMonth {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "--{:02}", self.month)?;
+    format_timezone(formatter, &self.timezone)
+  }
+}
+
+text_value!(
+  DateTime,
+  DateTimeStamp,
+  Date,
+  Time,
+  Duration,
+  GYear,
+  GYearMonth,
+  GMonthDay,
+  GDay,
+  GMonth
+);
+
+fn invalid_value(kind: &str, value: &str) -> String {
+  format!("Invalid xs:{kind} value {value:?}")
+}
+
+/// Point of the timeline of a value: its local seconds and nanoseconds, normalized to UTC when
+/// the value has a timezone.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Instant {
+  has_timezone: bool,
+  seconds: i64,
+  nanoseconds: u32,
+}
+
+impl Instant {
+  fn new(timezone: Option<TimeZone>, seconds: i64, nanoseconds: u32) -> Self {
+    let offset = timezone.map_or(0, |timezone| i64::from(timezone.offset_minutes) * 60);
+
+    Instant {
+      has_timezone: timezone.is_some(),
+      seconds: seconds - offset,
+      nanoseconds,
+    }
+  }
+
+  fn timeline(&self) -> (i64, u32) {
+    (self.seconds, self.nanoseconds)
+  }
+}
+
+/// Instants are comparable when they both have a timezone or both have none.
+impl PartialOrd for Instant {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    if self.has_timezone != other.has_timezone {
+      return None;
+    }
+
+    Some(self.timeline().cmp(&other.timeline()))
+  }
+}
+
+/// Number of days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+  let year = i64::from(year) - i64::from(month <= 2);
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let month = i64::from(month);
+  let day_of_year =
+    (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+  era * 146_097 + day_of_era - 719_468
+}
+
+fn is_leap_year(year: i32) -> bool {
+  year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Number of days of the month, February having 29 days when the year is unknown.
+fn days_in_month(year: Option<i32>, month: u32) -> u32 {
+  match month {
+    2 => match year {
+      Some(year) if !is_leap_year(year) => 28,
+      _ => 29,
+    },
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+/// Exactly `count` digits at the start of `value`.
+fn take_number(value: &str, count: usize) -> Option<(u32, &str)> {
+  let digits = value.get(..count)?;
+  if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+
+  Some((digits.parse().ok()?, &value[count..]))
+}
+
+/// Signed year of at least 4 digits, without leading zeros beyond 4 digits.
+fn take_year(value: &str) -> Option<(i32, &str)> {
+  let (negative, rest) = match value.strip_prefix('-') {
+    Some(rest) => (true, rest),
+    None => (false, value),
+  };
+
+  let length = rest.bytes().take_while(u8::is_ascii_digit).count();
+  if length < 4 || (length > 4 && rest.starts_with('0')) {
+    return None;
+  }
+
+  let year: i32 = rest[..length].parse().ok()?;
+  Some((if negative { -year } else { year }, &rest[length..]))
+}
+
+/// `YYYY-MM-DD`
+fn take_date(value: &str) -> Option<(i32, u8, u8, &str)> {
+  let (year, rest) = take_year(value)?;
+  let (month, rest) = take_number(rest.strip_prefix('-')?, 2)?;
+  let (day, rest) = take_number(rest.strip_prefix('-')?, 2)?;
+
+  if !(1..=12).contains(&month) || day < 1 || day > days_in_month(Some(year), month) {
+    return None;
+  }
+
+  Some((year, month as u8, day as u8, rest))
+}
+
+/// `hh:mm:ss(.s+)?`, `24:00:00` being the end of the day.
+fn take_time(value: &str) -> Option<(u8, u8, u8, u32, &str)> {
+  let (hour, rest) = take_number(value, 2)?;
+  let (minute, rest) = take_number(rest.strip_prefix(':')?, 2)?;
+  let (second, rest) = take_number(rest.strip_prefix(':')?, 2)?;
+  let (nanosecond, rest) = take_fraction(rest)?;
+
+  let end_of_day = hour == 24 && minute == 0 && second == 0 && nanosecond == 0;
+  if (hour > 23 && !end_of_day) || minute > 59 || second > 59 {
+    return None;
+  }
+
+  Some((hour as u8, minute as u8, second as u8, nanosecond, rest))
+}
+
+/// Optional fractional part of seconds, as nanoseconds. Digits beyond the nanosecond are
+/// ignored.
+fn take_fraction(value: &str) -> Option<(u32, &str)> {
+  let rest = match value.strip_prefix('.') {
+    Some(rest) => rest,
+    None => return Some((0, value)),
+  };
+
+  let length = rest.bytes().take_while(u8::is_ascii_digit).count();
+  if length == 0 {
+    return None;
+  }
+
+  let digits = &rest[..length.min(9)];
+  let nanoseconds = digits.parse::<u32>().ok()? * 10_u32.pow(9 - digits.len() as u32);
+  Some((nanoseconds, &rest[length..]))
+}
+
+/// Component of a duration: a number followed by its designator.
+fn take_component(value: &str) -> Option<(&str, char, &str)> {
+  let length = value
+    .bytes()
+    .take_while(|byte| byte.is_ascii_digit() || *byte == b'.')
+    .count();
+  let designator = value[length..].chars().next()?;
+
+  Some((
+    &value[..length],
+    designator,
+    &value[length + designator.len_utf8()..],
+  ))
+}
+
+/// Seconds of a duration with an optional fractional part.
+fn take_seconds(value: &str) -> Option<(u32, u32)> {
+  let length = value.bytes().take_while(u8::is_ascii_digit).count();
+  if length == 0 {
+    return None;
+  }
+
+  let seconds = value[..length].parse().ok()?;
+  match take_fraction(&value[length..])? {
+    (nanoseconds, "") => Some((seconds, nanoseconds)),
+    _ => None,
+  }
+}
+
+/// Optional timezone ending the value: `Z` or `(+|-)hh:mm`. `None` if the value is invalid.
+fn parse_timezone(value: &str) -> Option<Option<TimeZone>> {
+  if value.is_empty() {
+    return Some(None);
+  }
+  if value == "Z" {
+    return Some(Some(TimeZone::default()));
+  }
+
+  let negative = match value.chars().next()? {
+    '+' => false,
+    '-' => true,
+    _ => return None,
+  };
+
+  let (hours, rest) = take_number(&value[1..], 2)?;
+  let (minutes, rest) = take_number(rest.strip_prefix(':')?, 2)?;
+  if !rest.is_empty() || minutes > 59 || hours > 14 || (hours == 14 && minutes != 0) {
+    return None;
+  }
+
+  let offset_minutes = (hours * 60 + minutes) as i16;
+  Some(Some(TimeZone {
+    offset_minutes: if negative {
+      -offset_minutes
+    } else {
+      offset_minutes
+    },
+  }))
+}
+
+fn format_year(formatter: &mut fmt::Formatter, year: i32) -> fmt::Result {
+  if year < 0 {
+    write!(formatter, "-")?;
+  }
+  write!(formatter, "{:04}", year.unsigned_abs())
+}
+
+fn format_time(
+  formatter: &mut fmt::Formatter,
+  hour: u8,
+  minute: u8,
+  second: u8,
+  nanosecond: u32,
+) -> fmt::Result {
+  write!(formatter, "{hour:02}:{minute:02}:{second:02}")?;
+  format_fraction(formatter, nanosecond)
+}
+
+fn format_fraction(formatter: &mut fmt::Formatter, nanoseconds: u32) -> fmt::Result {
+  if nanoseconds == 0 {
+    return Ok(());
+  }
+
+  let digits = format!("{nanoseconds:09}");
+  write!(formatter, ".{}", digits.trim_end_matches('0'))
+}
+
+fn format_timezone(formatter: &mut fmt::Formatter, timezone: &Option<TimeZone>) -> fmt::Result {
+  match timezone {
+    Some(timezone) => write!(formatter, "{timezone}"),
+    None => Ok(()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn date_time() {
+    let value: DateTime = "2002-05-30T09:30:10.5+02:00".parse().unwrap();
+    assert_eq!(value.year, 2002);
+    assert_eq!(value.day, 30);
+    assert_eq!(value.nanosecond, 500_000_000);
+    assert_eq!(value.timezone.unwrap().offset_minutes, 120);
+    assert_eq!(value.to_string(), "2002-05-30T09:30:10.5+02:00");
+
+    let value: DateTime = "-0044-03-15T24:00:00Z".parse().unwrap();
+    assert_eq!(value.to_string(), "-0044-03-15T24:00:00Z");
+
+    let utc: DateTime = "2002-05-30T07:00:00Z".parse().unwrap();
+    let local: DateTime = "2002-05-30T08:30:00+02:00".parse().unwrap();
+    assert!(local < utc);
+    assert!(utc < "2002-05-31T00:00:00Z".parse().unwrap());
+    assert_eq!(
+      utc.partial_cmp(&"2002-05-30T07:00:00".parse().unwrap()),
+      None
+    );
+
+    assert_eq!(
+      "2002-05-30T09:30:00+02:00".parse::<DateTime>().unwrap(),
+      "2002-05-30T07:30:00Z".parse::<DateTime>().unwrap()
+    );
+    assert_eq!(
+      "2002-05-30T24:00:00".parse::<DateTime>().unwrap(),
+      "2002-05-31T00:00:00".parse::<DateTime>().unwrap()
+    );
+    assert_ne!(utc, "2002-05-30T07:00:00".parse::<DateTime>().unwrap());
+
+    assert!("2002-05-30".parse::<DateTime>().is_err());
+    assert!("2002-02-29T00:00:00".parse::<DateTime>().is_err());
+    assert!("2002-05-30T09:30:10+15:00".parse::<DateTime>().is_err());
+    assert!("02-05-30T09:30:10".parse::<DateTime>().is_err());
+  }
+
+  #[test]
+  fn date_and_time() {
+    let value: Date = "2000-02-29-05:00".parse().unwrap();
+    assert_eq!(value.timezone.unwrap().offset_minutes, -300);
+    assert_eq!(value.to_string(), "2000-02-29-05:00");
+
+    let value: Time = "13:20:00".parse().unwrap();
+    assert_eq!(value.timezone, None);
+    assert_eq!(value.to_string(), "13:20:00");
+
+    assert!("25:00:00".parse::<Time>().is_err());
+  }
+
+  #[test]
+  fn same_instant() {
+    use std::collections::hash_map::DefaultHasher;
+
+    let hash = |value: &Time| {
+      let mut hasher = DefaultHasher::new();
+      value.hash(&mut hasher);
+      hasher.finish()
+    };
+
+    let utc: Time = "10:00:00Z".parse().unwrap();
+    let local: Time = "12:00:00+02:00".parse().unwrap();
+    assert_eq!(utc, local);
+    assert_eq!(utc.partial_cmp(&local), Some(Ordering::Equal));
+    assert_eq!(hash(&utc), hash(&local));
+  }
+
+  #[test]
+  fn date_time_stamp() {
+    let value: DateTimeStamp = "2002-05-30T09:30:10-06:00".parse().unwrap();
+    assert_eq!(value.value().hour, 9);
+    assert_eq!(value.to_string(), "2002-05-30T09:30:10-06:00");
+    assert!(value < "2002-05-30T16:00:00Z".parse().unwrap());
+    assert_eq!(DateTimeStamp::default().to_string(), "1970-01-01T00:00:00Z");
+
+    assert_eq!(
+      "2002-05-30T09:30:10".parse::<DateTimeStamp>(),
+      Err("Invalid xs:dateTimeStamp value \"2002-05-30T09:30:10\"".to_string())
+    );
+  }
+
+  #[test]
+  fn duration() {
+    let value: Duration = "-P1Y2M3DT10H30M12.25S".parse().unwrap();
+    assert!(value.negative);
+    assert_eq!(value.years, 1);
+    assert_eq!(value.minutes, 30);
+    assert_eq!(value.seconds, 12);
+    assert_eq!(value.nanoseconds, 250_000_000);
+    assert_eq!(value.to_string(), "-P1Y2M3DT10H30M12.25S");
+
+    assert_eq!("PT0S".parse::<Duration>().unwrap().to_string(), "PT0S");
+    assert_eq!("P2D".parse::<Duration>().unwrap().to_string(), "P2D");
+
+    assert!("P".parse::<Duration>().is_err());
+    assert!("P1DT".parse::<Duration>().is_err());
+    assert!("P1M1Y".parse::<Duration>().is_err());
+  }
+
+  #[test]
+  fn gregorian() {
+    assert_eq!("2002Z".parse::<GYear>().unwrap().to_string(), "2002Z");
+    assert_eq!("-0044".parse::<GYear>().unwrap().year, -44);
+    assert_eq!("10000".parse::<GYear>().unwrap().year, 10000);
+    assert_eq!(
+      "1999-05Z".parse::<GYearMonth>().unwrap().to_string(),
+      "1999-05Z"
+    );
+    assert_eq!(
+      "--02-29".parse::<GMonthDay>().unwrap().to_string(),
+      "--02-29"
+    );
+    assert_eq!("---31".parse::<GDay>().unwrap().to_string(), "---31");
+    assert_eq!(
+      "--12+01:00".parse::<GMonth>().unwrap().to_string(),
+      "--12+01:00"
+    );
+
+    assert!("--04-31".parse::<GMonthDay>().is_err());
+    assert!("--13".parse::<GMonth>().is_err());
+    assert!("02002".parse::<GYear>().is_err());
+    assert!("2002-05".parse::<GYear>().is_err());
+  }
+}
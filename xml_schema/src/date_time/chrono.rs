@@ -0,0 +1,366 @@
+//! `xs:dateTime`, `xs:dateTimeStamp`, `xs:date` and `xs:time` backed by chrono types.
+//!
+//! The XSD values have an optional timezone, kept as an optional offset next to the naive value.
+//! `24:00:00` is read as midnight, of the following day for a `xs:dateTime`.
+//!
+//! The chrono types themselves implement [`Lexical`], to be substituted to the XSD types with
+//! `type_mapping`: a `DateTime<Utc>` reads the values without timezone as UTC ones, the naive
+//! types reject the values with a timezone.
+
+use super::TimeZone;
+use crate::text_content::{text_value, Lexical};
+use ::chrono::{
+  Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone as _, Timelike, Utc,
+};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DateTime {
+  pub naive: NaiveDateTime,
+  pub offset: Option<FixedOffset>,
+}
+
+/// `xs:dateTime` with a required timezone, compared on the timeline like the chrono values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeStamp(pub ::chrono::DateTime<FixedOffset>);
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Date {
+  pub naive: NaiveDate,
+  pub offset: Option<FixedOffset>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Time {
+  pub naive: NaiveTime,
+  pub offset: Option<FixedOffset>,
+}
+
+impl DateTime {
+  /// The date and time in its timezone, `None` if the value has no timezone.
+  pub fn with_offset(&self) -> Option<::chrono::DateTime<FixedOffset>> {
+    self
+      .offset
+      .and_then(|offset| offset.from_local_datetime(&self.naive).single())
+  }
+}
+
+impl From<::chrono::DateTime<FixedOffset>> for DateTime {
+  fn from(value: ::chrono::DateTime<FixedOffset>) -> Self {
+    DateTime {
+      naive: value.naive_local(),
+      offset: Some(*value.offset()),
+    }
+  }
+}
+
+impl TryFrom<super::DateTime> for DateTime {
+  type Error = String;
+
+  fn try_from(value: super::DateTime) -> Result<Self, Self::Error> {
+    let date = Date::try_from(value.date())?;
+    let time = Time::try_from(value.time())?;
+
+    let mut naive = date.naive.and_time(time.naive);
+    if value.hour == 24 {
+      naive = naive
+        .checked_add_signed(::chrono::Duration::days(1))
+        .ok_or_else(|| format!("Date time {value} out of range"))?;
+    }
+
+    Ok(DateTime {
+      naive,
+      offset: date.offset,
+    })
+  }
+}
+
+impl TryFrom<super::Date> for Date {
+  type Error = String;
+
+  fn try_from(value: super::Date) -> Result<Self, Self::Error> {
+    let naive = NaiveDate::from_ymd_opt(value.year, value.month.into(), value.day.into())
+      .ok_or_else(|| format!("Date {value} out of range"))?;
+
+    Ok(Date {
+      naive,
+      offset: to_offset(value.timezone)?,
+    })
+  }
+}
+
+impl TryFrom<super::Time> for Time {
+  type Error = String;
+
+  fn try_from(value: super::Time) -> Result<Self, Self::Error> {
+    let naive = NaiveTime::from_hms_nano_opt(
+      u32::from(value.hour % 24),
+      value.minute.into(),
+      value.second.into(),
+      value.nanosecond,
+    )
+    .ok_or_else(|| format!("Time {value} out of range"))?;
+
+    Ok(Time {
+      naive,
+      offset: to_offset(value.timezone)?,
+    })
+  }
+}
+
+impl From<DateTime> for super::DateTime {
+  fn from(value: DateTime) -> Self {
+    let date = super::Date::from(Date {
+      naive: value.naive.date(),
+      offset: value.offset,
+    });
+    let time = super::Time::from(Time {
+      naive: value.naive.time(),
+      offset: value.offset,
+    });
+
+    super::DateTime {
+      year: date.year,
+      month: date.month,
+      day: date.day,
+      hour: time.hour,
+      minute: time.minute,
+      second: time.second,
+      nanosecond: time.nanosecond,
+      timezone: date.timezone,
+    }
+  }
+}
+
+impl From<Date> for super::Date {
+  fn from(value: Date) -> Self {
+    super::Date {
+      year: value.naive.year(),
+      month: value.naive.month() as u8,
+      day: value.naive.day() as u8,
+      timezone: from_offset(value.offset),
+    }
+  }
+}
+
+impl From<Time> for super::Time {
+  fn from(value: Time) -> Self {
+    super::Time {
+      hour: value.naive.hour() as u8,
+      minute: value.naive.minute() as u8,
+      second: value.naive.second() as u8,
+      nanosecond: value.naive.nanosecond(),
+      timezone: from_offset(value.offset),
+    }
+  }
+}
+
+/// Same equality and ordering as the runtime types: values are equal when they are the same
+/// instant, and are comparable if they both have a timezone or both have none.
+macro_rules! timeline_ordering {
+  ($($name:ident),*) => {
+    $(
+      impl PartialEq for $name {
+        fn eq(&self, other: &Self) -> bool {
+          super::$name::from(*self) == super::$name::from(*other)
+        }
+      }
+
+      impl Eq for $name {}
+
+      impl Hash for $name {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+          super::$name::from(*self).hash(state)
+        }
+      }
+
+      impl PartialOrd for $name {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+          super::$name::from(*self).partial_cmp(&super::$name::from(*other))
+        }
+      }
+    )*
+  };
+}
+
+timeline_ordering!(DateTime, Date, Time);
+
+macro_rules! lexical_representation {
+  ($($name:ident),*) => {
+    $(
+      impl FromStr for $name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          $name::try_from(value.parse::<super::$name>()?)
+        }
+      }
+
+      impl Display for $name {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+          write!(formatter, "{}", super::$name::from(*self))
+        }
+      }
+    )*
+  };
+}
+
+lexical_representation!(DateTime, Date, Time);
+
+impl FromStr for DateTimeStamp {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let value = value.parse::<super::DateTimeStamp>()?;
+    DateTime::try_from(*value.value())?
+      .with_offset()
+      .map(DateTimeStamp)
+      .ok_or_else(|| format!("Date time {value} out of range"))
+  }
+}
+
+impl Display for DateTimeStamp {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    DateTime::from(self.0).fmt(formatter)
+  }
+}
+
+text_value!(DateTime, DateTimeStamp, Date, Time);
+
+impl Lexical for ::chrono::DateTime<FixedOffset> {
+  fn from_lexical(value: &str) -> Result<Self, String> {
+    value.parse::<DateTimeStamp>().map(|value| value.0)
+  }
+
+  fn to_lexical(&self) -> String {
+    DateTimeStamp(*self).to_string()
+  }
+}
+
+impl Lexical for ::chrono::DateTime<Utc> {
+  fn from_lexical(value: &str) -> Result<Self, String> {
+    let value = value.parse::<DateTime>()?;
+    match value.offset {
+      Some(_) => value
+        .with_offset()
+        .map(|value| value.with_timezone(&Utc))
+        .ok_or_else(|| format!("Date time {value} out of range")),
+      None => Ok(Utc.from_utc_datetime(&value.naive)),
+    }
+  }
+
+  fn to_lexical(&self) -> String {
+    DateTimeStamp(self.fixed_offset()).to_string()
+  }
+}
+
+/// Lexical representation of the naive chrono types, without timezone.
+macro_rules! naive_lexical {
+  ($($naive:ty => $name:ident),*) => {
+    $(
+      impl Lexical for $naive {
+        fn from_lexical(value: &str) -> Result<Self, String> {
+          match value.parse::<$name>()? {
+            $name { naive, offset: None } => Ok(naive),
+            _ => Err(format!(
+              "Unexpected timezone in {:?} for a {}",
+              value,
+              stringify!($naive)
+            )),
+          }
+        }
+
+        fn to_lexical(&self) -> String {
+          $name {
+            naive: *self,
+            offset: None,
+          }
+          .to_string()
+        }
+      }
+    )*
+  };
+}
+
+naive_lexical!(NaiveDateTime => DateTime, NaiveDate => Date, NaiveTime => Time);
+
+fn to_offset(timezone: Option<TimeZone>) -> Result<Option<FixedOffset>, String> {
+  timezone
+    .map(|timezone| {
+      FixedOffset::east_opt(i32::from(timezone.offset_minutes) * 60)
+        .ok_or_else(|| format!("Timezone {timezone} out of range"))
+    })
+    .transpose()
+}
+
+fn from_offset(offset: Option<FixedOffset>) -> Option<TimeZone> {
+  offset.map(|offset| TimeZone {
+    offset_minutes: (offset.local_minus_utc() / 60) as i16,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn date_time() {
+    let value: DateTime = "2002-05-30T09:30:10.5+02:00".parse().unwrap();
+    assert_eq!(value.naive.hour(), 9);
+    assert_eq!(value.offset, FixedOffset::east_opt(7200));
+    assert_eq!(
+      value.with_offset().unwrap().to_rfc3339(),
+      "2002-05-30T09:30:10.500+02:00"
+    );
+    assert_eq!(value.to_string(), "2002-05-30T09:30:10.5+02:00");
+
+    let value: DateTime = "2002-12-31T24:00:00".parse().unwrap();
+    assert_eq!(value.offset, None);
+    assert_eq!(value.to_string(), "2003-01-01T00:00:00");
+
+    assert_eq!(
+      "2002-05-30T09:30:00+02:00".parse::<DateTime>().unwrap(),
+      "2002-05-30T07:30:00Z".parse::<DateTime>().unwrap()
+    );
+  }
+
+  #[test]
+  fn date_time_stamp() {
+    let value: DateTimeStamp = "2002-05-30T09:30:10-06:00".parse().unwrap();
+    assert_eq!(value.0.to_rfc3339(), "2002-05-30T09:30:10-06:00");
+    assert_eq!(value.to_string(), "2002-05-30T09:30:10-06:00");
+    assert_eq!(value, "2002-05-30T15:30:10Z".parse().unwrap());
+
+    assert!("2002-05-30T09:30:10".parse::<DateTimeStamp>().is_err());
+  }
+
+  #[test]
+  fn lexical() {
+    let value = ::chrono::DateTime::<Utc>::from_lexical("2020-01-01T01:00:00+01:00").unwrap();
+    assert_eq!(value.to_string(), "2020-01-01 00:00:00 UTC");
+    assert_eq!(value.to_lexical(), "2020-01-01T00:00:00Z");
+    assert_eq!(
+      ::chrono::DateTime::<Utc>::from_lexical("2020-01-01T00:00:00").unwrap(),
+      value
+    );
+
+    let value = NaiveDate::from_lexical("2000-02-29").unwrap();
+    assert_eq!(value.to_lexical(), "2000-02-29");
+    assert!(NaiveDate::from_lexical("2000-02-29Z").is_err());
+  }
+
+  #[test]
+  fn date_and_time() {
+    let value: Date = "2000-02-29Z".parse().unwrap();
+    assert_eq!(value.naive, NaiveDate::from_ymd_opt(2000, 2, 29).unwrap());
+    assert_eq!(value.to_string(), "2000-02-29Z");
+
+    let value: Time = "13:20:00-05:00".parse().unwrap();
+    assert_eq!(value.naive, NaiveTime::from_hms_opt(13, 20, 0).unwrap());
+    assert_eq!(value.to_string(), "13:20:00-05:00");
+  }
+}
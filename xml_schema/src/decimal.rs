@@ -0,0 +1,203 @@
+//! Exact decimal numbers of XML Schema: `xs:decimal`.
+//!
+//! With the `rust_decimal` feature, the `rust_decimal` module provides the same values backed
+//! by `rust_decimal::Decimal`.
+
+use crate::integer::{compare_digits, split_sign, Integer};
+use crate::text_content::text_value;
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal;
+
+/// Type used by the generated code for `xs:decimal`: the one of the `rust_decimal` module when
+/// this crate has the `rust_decimal` feature.
+pub mod generated {
+  #[cfg(feature = "rust_decimal")]
+  pub use super::rust_decimal::Decimal;
+  #[cfg(not(feature = "rust_decimal"))]
+  pub use super::Decimal;
+}
+
+/// Decimal number of any precision, `1.50` being equal to `1.5`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal {
+  negative: bool,
+  /// Decimal digits of the value without the point, without leading zeros, `"0"` for zero.
+  digits: String,
+  /// Number of digits after the point, without trailing zeros.
+  scale: usize,
+}
+
+impl Decimal {
+  pub fn is_negative(&self) -> bool {
+    self.negative
+  }
+
+  pub fn is_zero(&self) -> bool {
+    self.digits == "0"
+  }
+
+  /// Number of digits after the decimal point.
+  pub fn scale(&self) -> usize {
+    self.scale
+  }
+
+  /// The integer part of the value.
+  pub fn trunc(&self) -> Integer {
+    let length = self.digits.len().saturating_sub(self.scale);
+    Integer::new(self.negative, &self.digits[..length])
+  }
+
+  /// Closest floating point value.
+  pub fn to_f64(&self) -> f64 {
+    self.to_string().parse().unwrap_or_default()
+  }
+
+  fn new(negative: bool, integer_part: &str, fractional_part: &str) -> Self {
+    let fractional_part = fractional_part.trim_end_matches('0');
+    let digits = format!("{integer_part}{fractional_part}");
+    let digits = digits.trim_start_matches('0');
+
+    if digits.is_empty() {
+      Decimal::default()
+    } else {
+      Decimal {
+        negative,
+        digits: digits.to_string(),
+        scale: fractional_part.len(),
+      }
+    }
+  }
+
+  /// Digits of the absolute value with `scale` digits after the point.
+  fn digits_with_scale(&self, scale: usize) -> String {
+    if self.is_zero() {
+      return self.digits.clone();
+    }
+
+    format!("{}{}", self.digits, "0".repeat(scale - self.scale))
+  }
+}
+
+impl Default for Decimal {
+  fn default() -> Self {
+    Decimal {
+      negative: false,
+      digits: "0".to_string(),
+      scale: 0,
+    }
+  }
+}
+
+impl From<Integer> for Decimal {
+  fn from(value: Integer) -> Self {
+    Decimal::new(value.is_negative(), value.digits(), "")
+  }
+}
+
+impl Ord for Decimal {
+  fn cmp(&self, other: &Self) -> Ordering {
+    let scale = self.scale.max(other.scale);
+    let digits = self.digits_with_scale(scale);
+    let other_digits = other.digits_with_scale(scale);
+
+    match (self.negative, other.negative) {
+      (false, true) => Ordering::Greater,
+      (true, false) => Ordering::Less,
+      (false, false) => compare_digits(&digits, &other_digits),
+      (true, true) => compare_digits(&other_digits, &digits),
+    }
+  }
+}
+
+impl PartialOrd for Decimal {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl FromStr for Decimal {
+  type Err = String;
+
+  /// Lexical representation `[+-]?([0-9]+(\.[0-9]*)?|\.[0-9]+)`.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let (negative, number) = split_sign(value);
+    let (integer_part, fractional_part) = match number.find('.') {
+      Some(index) => (&number[..index], &number[index + 1..]),
+      None => (number, ""),
+    };
+
+    let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if (integer_part.is_empty() && fractional_part.is_empty())
+      || !is_digits(integer_part)
+      || !is_digits(fractional_part)
+    {
+      return Err(format!("Invalid xs:decimal value {value:?}"));
+    }
+
+    Ok(Decimal::new(negative, integer_part, fractional_part))
+  }
+}
+
+impl Display for Decimal {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    if self.negative {
+      write!(formatter, "-")?;
+    }
+    if self.scale == 0 {
+      return write!(formatter, "{}", self.digits);
+    }
+
+    let digits = format!("{:0>width$}", self.digits, width = self.scale + 1);
+    let (integer_part, fractional_part) = digits.split_at(digits.len() - self.scale);
+    write!(formatter, "{integer_part}.{fractional_part}")
+  }
+}
+
+text_value!(Decimal);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn decimal() {
+    let value: Decimal = "-0012.3400".parse().unwrap();
+    assert!(value.is_negative());
+    assert_eq!(value.scale(), 2);
+    assert_eq!(value.to_string(), "-12.34");
+    assert_eq!(value.trunc().to_string(), "-12");
+
+    assert_eq!(".05".parse::<Decimal>().unwrap().to_string(), "0.05");
+    assert_eq!("+3.".parse::<Decimal>().unwrap().to_string(), "3");
+    assert_eq!("-0.0".parse::<Decimal>().unwrap(), Decimal::default());
+    assert_eq!(
+      "12345678901234567890.000000000001"
+        .parse::<Decimal>()
+        .unwrap()
+        .to_string(),
+      "12345678901234567890.000000000001"
+    );
+
+    assert!(".".parse::<Decimal>().is_err());
+    assert!("1e5".parse::<Decimal>().is_err());
+    assert!("1.2.3".parse::<Decimal>().is_err());
+  }
+
+  #[test]
+  fn ordering() {
+    let values: Vec<Decimal> = ["-10", "-9.99", "-0.5", "0", "0.05", "0.5", "1.5", "10"]
+      .iter()
+      .map(|value| value.parse().unwrap())
+      .collect();
+
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(
+      "1.50".parse::<Decimal>().unwrap(),
+      "1.5".parse::<Decimal>().unwrap()
+    );
+  }
+}
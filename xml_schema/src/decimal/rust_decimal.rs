@@ -0,0 +1,70 @@
+//! `xs:decimal` backed by `rust_decimal::Decimal`, limited to 28 significant digits.
+
+use crate::text_content::{text_value, Lexical};
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal(pub ::rust_decimal::Decimal);
+
+impl Deref for Decimal {
+  type Target = ::rust_decimal::Decimal;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<::rust_decimal::Decimal> for Decimal {
+  fn from(value: ::rust_decimal::Decimal) -> Self {
+    Decimal(value)
+  }
+}
+
+impl FromStr for Decimal {
+  type Err = String;
+
+  /// The value is checked against the XSD lexical representation before being converted.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    value.parse::<super::Decimal>()?;
+
+    let value = value.strip_prefix('+').unwrap_or(value);
+    ::rust_decimal::Decimal::from_str(value)
+      .map(Decimal)
+      .map_err(|e| format!("Invalid xs:decimal value {value:?}: {e}"))
+  }
+}
+
+impl Display for Decimal {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+text_value!(Decimal);
+
+impl Lexical for ::rust_decimal::Decimal {
+  fn from_lexical(value: &str) -> Result<Self, String> {
+    value.parse::<Decimal>().map(|value| value.0)
+  }
+
+  fn to_lexical(&self) -> String {
+    Decimal(*self).to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn decimal() {
+    let value: Decimal = "+12.50".parse().unwrap();
+    assert_eq!(*value, ::rust_decimal::Decimal::new(1250, 2));
+    assert_eq!(value.to_string(), "12.50");
+
+    assert!("1e5".parse::<Decimal>().is_err());
+    assert!("123456789012345678901234567890".parse::<Decimal>().is_err());
+  }
+}
@@ -0,0 +1,149 @@
+//! Elements declared with a `default` value in the schema.
+//!
+//! The generated code declares a marker type per default value, used when the element is empty.
+
+use crate::text_content;
+use std::fmt::{self, Debug, Display};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::str::FromStr;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+/// Implemented by the marker types generated for the `default` values.
+pub trait DefaultValue {
+  const VALUE: &'static str;
+}
+
+/// Text content replaced by `V::VALUE` when it is empty.
+pub struct Defaulted<T, V> {
+  pub value: T,
+  marker: PhantomData<V>,
+}
+
+impl<T, V> Defaulted<T, V> {
+  pub fn new(value: T) -> Self {
+    Defaulted {
+      value,
+      marker: PhantomData,
+    }
+  }
+}
+
+impl<T, V> Deref for Defaulted<T, V> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    &self.value
+  }
+}
+
+impl<T: Clone, V> Clone for Defaulted<T, V> {
+  fn clone(&self) -> Self {
+    Defaulted::new(self.value.clone())
+  }
+}
+
+impl<T: PartialEq, V> PartialEq for Defaulted<T, V> {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
+impl<T: Debug, V> Debug for Defaulted<T, V> {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "Defaulted({:?})", self.value)
+  }
+}
+
+impl<T: Display, V> Display for Defaulted<T, V> {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "{}", self.value)
+  }
+}
+
+impl<T, V> Default for Defaulted<T, V>
+where
+  T: FromStr,
+  T::Err: Debug,
+  V: DefaultValue,
+{
+  fn default() -> Self {
+    let value = V::VALUE
+      .parse()
+      .unwrap_or_else(|e| panic!("Invalid default value {:?}: {:?}", V::VALUE, e));
+
+    Defaulted::new(value)
+  }
+}
+
+impl<T, V> FromStr for Defaulted<T, V>
+where
+  T: FromStr,
+  T::Err: ToString,
+  V: DefaultValue,
+{
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let value = if value.is_empty() { V::VALUE } else { value };
+
+    value
+      .parse()
+      .map(Defaulted::new)
+      .map_err(|e: T::Err| e.to_string())
+  }
+}
+
+impl<T, V> YaDeserialize for Defaulted<T, V>
+where
+  T: FromStr,
+  T::Err: ToString,
+  V: DefaultValue,
+{
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    text_content::deserialize(reader)
+  }
+}
+
+impl<T: Display, V> YaSerialize for Defaulted<T, V> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    text_content::serialize(self, "Defaulted", writer)
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Unit;
+
+  impl DefaultValue for Unit {
+    const VALUE: &'static str = "cm";
+  }
+
+  #[test]
+  fn parse() {
+    let value: Defaulted<String, Unit> = "".parse().unwrap();
+    assert_eq!(*value, "cm");
+
+    let value: Defaulted<String, Unit> = "mm".parse().unwrap();
+    assert_eq!(*value, "mm");
+
+    assert_eq!(*Defaulted::<String, Unit>::default(), "cm");
+  }
+}
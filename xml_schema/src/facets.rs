@@ -0,0 +1,210 @@
+//! Validation helpers used by the generated code to enforce `xs:restriction` facets.
+
+use crate::binary::{Base64Binary, HexBinary};
+use crate::list::List;
+use crate::text_content::{Lexical, Text};
+use regex::Regex;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// Length of a value as defined by the `length`, `minLength` and `maxLength` facets: a number of
+/// characters for strings, of octets for binary data and of items for lists.
+pub trait Length {
+  fn length(&self) -> usize;
+}
+
+impl Length for str {
+  fn length(&self) -> usize {
+    self.chars().count()
+  }
+}
+
+impl Length for String {
+  fn length(&self) -> usize {
+    self.as_str().length()
+  }
+}
+
+impl Length for Base64Binary {
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+impl Length for HexBinary {
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+impl<T> Length for List<T> {
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+impl<T: Lexical> Length for Text<T> {
+  fn length(&self) -> usize {
+    self.to_string().length()
+  }
+}
+
+/// Check the length of a value against the `length`, `minLength` and `maxLength` facets.
+pub fn check_length<T: Length + Display + ?Sized>(
+  value: &T,
+  length: Option<usize>,
+  min_length: Option<usize>,
+  max_length: Option<usize>,
+) -> Result<(), String> {
+  let count = value.length();
+  let value = value.to_string();
+
+  if let Some(length) = length {
+    if count != length {
+      return Err(format!(
+        "{value:?} has a length of {count}, expected exactly {length}"
+      ));
+    }
+  }
+
+  if let Some(min_length) = min_length {
+    if count < min_length {
+      return Err(format!(
+        "{value:?} has a length of {count}, expected at least {min_length}"
+      ));
+    }
+  }
+
+  if let Some(max_length) = max_length {
+    if count > max_length {
+      return Err(format!(
+        "{value:?} has a length of {count}, expected at most {max_length}"
+      ));
+    }
+  }
+
+  Ok(())
+}
+
+/// The `pattern` facets of a type, as XML Schema patterns and their translation into the `regex`
+/// crate syntax checked when generated. The regular expressions are compiled on the first check.
+pub struct Patterns {
+  patterns: &'static [(&'static str, &'static str)],
+  regexes: OnceLock<Result<Vec<Regex>, String>>,
+}
+
+impl Patterns {
+  pub const fn new(patterns: &'static [(&'static str, &'static str)]) -> Self {
+    Patterns {
+      patterns,
+      regexes: OnceLock::new(),
+    }
+  }
+
+  /// Check a value against the patterns, the value must match one of them.
+  pub fn check(&self, value: &str) -> Result<(), String> {
+    let regexes = self
+      .regexes
+      .get_or_init(|| {
+        self
+          .patterns
+          .iter()
+          .map(|(pattern, regex)| {
+            Regex::new(regex).map_err(|e| format!("invalid pattern {pattern:?}: {e}"))
+          })
+          .collect()
+      })
+      .as_ref()?;
+
+    if regexes.iter().any(|regex| regex.is_match(value)) {
+      return Ok(());
+    }
+
+    let patterns: Vec<&str> = self.patterns.iter().map(|(pattern, _)| *pattern).collect();
+    Err(format!("{value:?} does not match the pattern {patterns:?}"))
+  }
+}
+
+/// Check the number of digits of a decimal value against the `totalDigits` facet.
+pub fn check_total_digits(value: &str, total_digits: usize) -> Result<(), String> {
+  let (integer_part, fraction_part) = split_digits(value);
+  let integer_part = integer_part.trim_start_matches('0');
+  let fraction_part = fraction_part.trim_end_matches('0');
+
+  let count = integer_part.len() + fraction_part.len();
+  if count > total_digits {
+    return Err(format!(
+      "{value:?} has {count} digits, expected at most {total_digits}"
+    ));
+  }
+
+  Ok(())
+}
+
+/// Check the number of fractional digits of a decimal value against the `fractionDigits` facet.
+pub fn check_fraction_digits(value: &str, fraction_digits: usize) -> Result<(), String> {
+  let (_, fraction_part) = split_digits(value);
+
+  let count = fraction_part.trim_end_matches('0').len();
+  if count > fraction_digits {
+    return Err(format!(
+      "{value:?} has {count} fraction digits, expected at most {fraction_digits}"
+    ));
+  }
+
+  Ok(())
+}
+
+fn split_digits(value: &str) -> (&str, &str) {
+  let value = value.trim().trim_start_matches(['+', '-']);
+
+  match value.find('.') {
+    Some(position) => (&value[..position], &value[position + 1..]),
+    None => (value, ""),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn length() {
+    assert!(check_length("abc", Some(3), None, None).is_ok());
+    assert!(check_length("abcd", Some(3), None, None).is_err());
+    assert!(check_length("ab", None, Some(3), None).is_err());
+    assert!(check_length("abcd", None, None, Some(3)).is_err());
+    assert!(check_length("éàç", None, None, Some(3)).is_ok());
+
+    let bytes: Base64Binary = "AAEC".parse().unwrap();
+    assert!(check_length(&bytes, Some(3), None, None).is_ok());
+
+    let list: List<u8> = "1 2  3".parse().unwrap();
+    assert!(check_length(&list, None, Some(2), Some(3)).is_ok());
+    assert!(check_length(&list, None, None, Some(2)).is_err());
+  }
+
+  #[test]
+  fn patterns() {
+    static CODE: Patterns = Patterns::new(&[("[A-Z]{2}[0-9]{2}", "^(?:[A-Z]{2}[0-9]{2})$")]);
+    assert!(CODE.check("AB12").is_ok());
+    assert_eq!(
+      CODE.check("xAB12").unwrap_err(),
+      r#""xAB12" does not match the pattern ["[A-Z]{2}[0-9]{2}"]"#
+    );
+
+    static NUMBER: Patterns =
+      Patterns::new(&[("[A-Z]+", "^(?:[A-Z]+)$"), ("[0-9]+", "^(?:[0-9]+)$")]);
+    assert!(NUMBER.check("12").is_ok());
+  }
+
+  #[test]
+  fn digits() {
+    assert!(check_total_digits("123.45", 5).is_ok());
+    assert!(check_total_digits("-0123.450", 5).is_ok());
+    assert!(check_total_digits("123.456", 5).is_err());
+    assert!(check_fraction_digits("1.50", 1).is_ok());
+    assert!(check_fraction_digits("1.55", 1).is_err());
+    assert!(check_fraction_digits("12", 0).is_ok());
+  }
+}
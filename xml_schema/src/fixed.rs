@@ -0,0 +1,142 @@
+//! Values declared with a `fixed` constraint in the schema.
+//!
+//! The generated code declares a marker type per fixed value, any other value is rejected
+//! when deserializing. The values are compared once parsed as the type of the element or
+//! attribute, so that `01` is equal to a fixed `1` integer.
+
+use crate::text_content;
+use std::fmt::{self, Debug, Display};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+/// Implemented by the marker types generated for the `fixed` values.
+pub trait FixedValue {
+  const VALUE: &'static str;
+}
+
+/// Value of type `T` which can only be equal to `V::VALUE`.
+pub struct Fixed<T, V> {
+  marker: PhantomData<(T, V)>,
+}
+
+impl<T, V: FixedValue> Fixed<T, V> {
+  pub fn value(&self) -> &'static str {
+    V::VALUE
+  }
+}
+
+impl<T, V> Clone for Fixed<T, V> {
+  fn clone(&self) -> Self {
+    Fixed::default()
+  }
+}
+
+impl<T, V> Default for Fixed<T, V> {
+  fn default() -> Self {
+    Fixed {
+      marker: PhantomData,
+    }
+  }
+}
+
+impl<T, V> PartialEq for Fixed<T, V> {
+  fn eq(&self, _other: &Self) -> bool {
+    true
+  }
+}
+
+impl<T, V: FixedValue> Debug for Fixed<T, V> {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "Fixed({:?})", V::VALUE)
+  }
+}
+
+impl<T, V: FixedValue> Display for Fixed<T, V> {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "{}", V::VALUE)
+  }
+}
+
+impl<T, V> FromStr for Fixed<T, V>
+where
+  T: FromStr + PartialEq,
+  V: FixedValue,
+{
+  type Err = String;
+
+  /// An empty value stands for the fixed one, like for an empty element.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    if value.is_empty() || value == V::VALUE {
+      return Ok(Fixed::default());
+    }
+
+    match (value.parse::<T>(), V::VALUE.parse::<T>()) {
+      (Ok(value), Ok(fixed)) if value == fixed => Ok(Fixed::default()),
+      _ => Err(format!(
+        "Expected the fixed value {:?}, found {:?}",
+        V::VALUE,
+        value
+      )),
+    }
+  }
+}
+
+impl<T, V> YaDeserialize for Fixed<T, V>
+where
+  T: FromStr + PartialEq,
+  V: FixedValue,
+{
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    text_content::deserialize(reader)
+  }
+}
+
+impl<T, V: FixedValue> YaSerialize for Fixed<T, V> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    text_content::serialize(self, "Fixed", writer)
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Version;
+
+  impl FixedValue for Version {
+    const VALUE: &'static str = "1.0";
+  }
+
+  #[test]
+  fn parse() {
+    let fixed: Fixed<String, Version> = "1.0".parse().unwrap();
+    assert_eq!(fixed.value(), "1.0");
+    assert_eq!(fixed.to_string(), "1.0");
+
+    assert!("2.0".parse::<Fixed<String, Version>>().is_err());
+    assert!("1.00".parse::<Fixed<String, Version>>().is_err());
+  }
+
+  #[test]
+  fn parse_value() {
+    assert!("1.00".parse::<Fixed<f64, Version>>().is_ok());
+    assert!("2.0".parse::<Fixed<f64, Version>>().is_err());
+    assert!("one".parse::<Fixed<f64, Version>>().is_err());
+  }
+}
@@ -0,0 +1,128 @@
+//! YaSerDe helpers for the repeated `xs:group` references.
+//!
+//! A group reference with a `maxOccurs` and several particles is generated as a structure
+//! holding one occurence of the group. The occurences are flattened in their parent structure:
+//! the children elements which are not matched by the other fields of the parent are split into
+//! occurences, following the order of the particles of the group.
+
+use std::io::{Read, Write};
+use xml::reader::XmlEvent;
+use xml::writer::{EventWriter, XmlEvent as WriterEvent};
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+/// Implemented by the structures generated for the repeated groups.
+pub trait Group: Sized + YaDeserialize {
+  /// Position of the particle declaring the element in the group, and whether this particle can
+  /// be repeated. Returns `None` if the element is not declared by the group.
+  fn position(element_name: &str) -> Option<(usize, bool)>;
+}
+
+/// Repeated group, the occurences are kept in document order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Groups<T> {
+  pub items: Vec<T>,
+}
+
+impl<T: Group> YaDeserialize for Groups<T> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    if let XmlEvent::StartElement { .. } = reader.peek()? {
+      reader.next_event()?;
+    }
+
+    let mut occurences: Vec<Vec<XmlEvent>> = vec![];
+    let mut last_position: Option<usize> = None;
+    loop {
+      match reader.peek()?.to_owned() {
+        XmlEvent::StartElement { name, .. } => {
+          let event = reader.next_event()?;
+
+          let (position, multiple) = match T::position(&name.local_name) {
+            Some(position) => position,
+            None => {
+              reader.skip_element(|_| {})?;
+              continue;
+            }
+          };
+
+          // an element declared before the previous one, or by the same particle which can not
+          // be repeated, starts a new occurence
+          let starts_occurence = match last_position {
+            Some(last_position) => {
+              position < last_position || (position == last_position && !multiple)
+            }
+            None => true,
+          };
+          if starts_occurence {
+            occurences.push(vec![]);
+          }
+          last_position = Some(position);
+
+          if let Some(events) = occurences.last_mut() {
+            events.push(event);
+            reader.skip_element(|event| events.push(event.clone()))?;
+          }
+        }
+        XmlEvent::EndElement { .. } | XmlEvent::EndDocument => break,
+        _ => {
+          reader.next_event()?;
+        }
+      }
+    }
+
+    let items = occurences
+      .iter()
+      .map(|events| yaserde::de::from_str(&write_occurence::<T>(events)?))
+      .collect::<Result<Vec<T>, String>>()?;
+
+    Ok(Groups { items })
+  }
+}
+
+impl<T: YaSerialize> YaSerialize for Groups<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    for item in &self.items {
+      writer.set_start_event_name(None);
+      writer.set_skip_start_end(true);
+      item.serialize(writer)?;
+    }
+    Ok(())
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Document of an occurence, its elements being wrapped in an element named like the generated
+/// structure so that it is consumed as the root of the structure.
+fn write_occurence<T>(events: &[XmlEvent]) -> Result<String, String> {
+  let type_name = std::any::type_name::<T>();
+  let root = type_name.rsplit("::").next().unwrap_or(type_name);
+
+  let mut buffer = vec![];
+  let mut writer = EventWriter::new(&mut buffer);
+
+  writer
+    .write(WriterEvent::start_element(root))
+    .map_err(|error| error.to_string())?;
+  for event in events {
+    if let Some(event) = event.as_writer_event() {
+      writer.write(event).map_err(|error| error.to_string())?;
+    }
+  }
+  writer
+    .write(WriterEvent::end_element())
+    .map_err(|error| error.to_string())?;
+
+  String::from_utf8(buffer).map_err(|error| error.to_string())
+}
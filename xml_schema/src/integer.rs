@@ -0,0 +1,363 @@
+//! Integers of XML Schema without size limit: `xs:integer`, `xs:nonPositiveInteger`,
+//! `xs:negativeInteger`, `xs:nonNegativeInteger` and `xs:positiveInteger`.
+//!
+//! The values are kept as their decimal digits, converted to the primitive integers on demand.
+
+use crate::text_content::text_value;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Integer of any size.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Integer {
+  negative: bool,
+  /// Decimal digits, without leading zeros, `"0"` for zero.
+  digits: String,
+}
+
+/// Integer lower than or equal to 0.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonPositiveInteger(Integer);
+
+/// Integer lower than 0.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NegativeInteger(Integer);
+
+/// Integer greater than or equal to 0.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonNegativeInteger(Integer);
+
+/// Integer greater than 0.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PositiveInteger(Integer);
+
+impl Integer {
+  pub fn is_negative(&self) -> bool {
+    self.negative
+  }
+
+  pub fn is_zero(&self) -> bool {
+    self.digits == "0"
+  }
+
+  /// Decimal digits of the absolute value.
+  pub fn digits(&self) -> &str {
+    &self.digits
+  }
+
+  pub(crate) fn new(negative: bool, digits: &str) -> Self {
+    let digits = digits.trim_start_matches('0');
+
+    if digits.is_empty() {
+      Integer::default()
+    } else {
+      Integer {
+        negative,
+        digits: digits.to_string(),
+      }
+    }
+  }
+}
+
+impl NonPositiveInteger {
+  pub fn value(&self) -> &Integer {
+    &self.0
+  }
+}
+
+impl NegativeInteger {
+  pub fn value(&self) -> &Integer {
+    &self.0
+  }
+}
+
+impl NonNegativeInteger {
+  pub fn value(&self) -> &Integer {
+    &self.0
+  }
+}
+
+impl PositiveInteger {
+  pub fn value(&self) -> &Integer {
+    &self.0
+  }
+}
+
+impl Default for Integer {
+  fn default() -> Self {
+    Integer {
+      negative: false,
+      digits: "0".to_string(),
+    }
+  }
+}
+
+impl Default for NegativeInteger {
+  fn default() -> Self {
+    NegativeInteger(Integer::new(true, "1"))
+  }
+}
+
+impl Default for PositiveInteger {
+  fn default() -> Self {
+    PositiveInteger(Integer::new(false, "1"))
+  }
+}
+
+impl Ord for Integer {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self.negative, other.negative) {
+      (false, true) => Ordering::Greater,
+      (true, false) => Ordering::Less,
+      (false, false) => compare_digits(&self.digits, &other.digits),
+      (true, true) => compare_digits(&other.digits, &self.digits),
+    }
+  }
+}
+
+impl PartialOrd for Integer {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl FromStr for Integer {
+  type Err = String;
+
+  /// Lexical representation `[+-]?[0-9]+`.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let (negative, digits) = split_sign(value);
+
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+      return Err(format!("Invalid xs:integer value {value:?}"));
+    }
+
+    Ok(Integer::new(negative, digits))
+  }
+}
+
+impl FromStr for NonPositiveInteger {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    NonPositiveInteger::try_from(value.parse::<Integer>()?)
+  }
+}
+
+impl FromStr for NegativeInteger {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    NegativeInteger::try_from(value.parse::<Integer>()?)
+  }
+}
+
+impl FromStr for NonNegativeInteger {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    NonNegativeInteger::try_from(value.parse::<Integer>()?)
+  }
+}
+
+impl FromStr for PositiveInteger {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    PositiveInteger::try_from(value.parse::<Integer>()?)
+  }
+}
+
+impl TryFrom<Integer> for NonPositiveInteger {
+  type Error = String;
+
+  fn try_from(value: Integer) -> Result<Self, Self::Error> {
+    if value.negative || value.is_zero() {
+      Ok(NonPositiveInteger(value))
+    } else {
+      Err(format!("Invalid xs:nonPositiveInteger value {value}"))
+    }
+  }
+}
+
+impl TryFrom<Integer> for NegativeInteger {
+  type Error = String;
+
+  fn try_from(value: Integer) -> Result<Self, Self::Error> {
+    if value.negative {
+      Ok(NegativeInteger(value))
+    } else {
+      Err(format!("Invalid xs:negativeInteger value {value}"))
+    }
+  }
+}
+
+impl TryFrom<Integer> for NonNegativeInteger {
+  type Error = String;
+
+  fn try_from(value: Integer) -> Result<Self, Self::Error> {
+    if !value.negative {
+      Ok(NonNegativeInteger(value))
+    } else {
+      Err(format!("Invalid xs:nonNegativeInteger value {value}"))
+    }
+  }
+}
+
+impl TryFrom<Integer> for PositiveInteger {
+  type Error = String;
+
+  fn try_from(value: Integer) -> Result<Self, Self::Error> {
+    if !value.negative && !value.is_zero() {
+      Ok(PositiveInteger(value))
+    } else {
+      Err(format!("Invalid xs:positiveInteger value {value}"))
+    }
+  }
+}
+
+impl Display for Integer {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    if self.negative {
+      write!(formatter, "-")?;
+    }
+    write!(formatter, "{}", self.digits)
+  }
+}
+
+impl Display for NonPositiveInteger {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+impl Display for NegativeInteger {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+impl Display for NonNegativeInteger {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+impl Display for PositiveInteger {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+/// Conversions from and to the primitive integers, failing when the value does not fit.
+macro_rules! primitive_conversions {
+  ($($primitive:ty),*) => {
+    $(
+      impl From<$primitive> for Integer {
+        fn from(value: $primitive) -> Self {
+          value.to_string().parse().unwrap()
+        }
+      }
+
+      impl TryFrom<&Integer> for $primitive {
+        type Error = String;
+
+        fn try_from(value: &Integer) -> Result<Self, Self::Error> {
+          value
+            .to_string()
+            .parse()
+            .map_err(|_| format!("{} does not fit in {}", value, stringify!($primitive)))
+        }
+      }
+
+      impl TryFrom<Integer> for $primitive {
+        type Error = String;
+
+        fn try_from(value: Integer) -> Result<Self, Self::Error> {
+          <$primitive>::try_from(&value)
+        }
+      }
+    )*
+  };
+}
+
+primitive_conversions!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+text_value!(
+  Integer,
+  NonPositiveInteger,
+  NegativeInteger,
+  NonNegativeInteger,
+  PositiveInteger
+);
+
+pub(crate) fn split_sign(value: &str) -> (bool, &str) {
+  if let Some(rest) = value.strip_prefix('-') {
+    (true, rest)
+  } else {
+    (false, value.strip_prefix('+').unwrap_or(value))
+  }
+}
+
+/// Compare the digits of two natural numbers without leading zeros.
+pub(crate) fn compare_digits(digits: &str, other_digits: &str) -> Ordering {
+  digits
+    .len()
+    .cmp(&other_digits.len())
+    .then_with(|| digits.cmp(other_digits))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn integer() {
+    let value: Integer = "-0012345678901234567890".parse().unwrap();
+    assert!(value.is_negative());
+    assert_eq!(value.to_string(), "-12345678901234567890");
+    assert_eq!(i128::try_from(&value), Ok(-12345678901234567890));
+    assert!(i64::try_from(&value).is_err());
+
+    assert_eq!("+0".parse::<Integer>().unwrap(), Integer::from(0));
+    assert_eq!("-0".parse::<Integer>().unwrap().to_string(), "0");
+
+    assert!("1.0".parse::<Integer>().is_err());
+    assert!("-".parse::<Integer>().is_err());
+  }
+
+  #[test]
+  fn ordering() {
+    let values: Vec<Integer> = ["-100", "-99", "0", "9", "10"]
+      .iter()
+      .map(|value| value.parse().unwrap())
+      .collect();
+
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+
+  #[test]
+  fn signed_integers() {
+    assert!("0".parse::<NonPositiveInteger>().is_ok());
+    assert!("-5".parse::<NonPositiveInteger>().is_ok());
+    assert!("5".parse::<NonPositiveInteger>().is_err());
+
+    assert_eq!("-5".parse::<NegativeInteger>().unwrap().to_string(), "-5");
+    assert!("0".parse::<NegativeInteger>().is_err());
+
+    assert_eq!(
+      "+00184467440737095516150"
+        .parse::<NonNegativeInteger>()
+        .unwrap()
+        .to_string(),
+      "184467440737095516150"
+    );
+    assert!("-0".parse::<NonNegativeInteger>().is_ok());
+    assert!("-5".parse::<NonNegativeInteger>().is_err());
+
+    assert_eq!(PositiveInteger::default().to_string(), "1");
+    assert!("0".parse::<PositiveInteger>().is_err());
+  }
+}
@@ -2,3 +2,18 @@
 #[allow(unused_imports)]
 #[macro_use]
 extern crate xml_schema_derive;
+
+pub mod binary;
+#[cfg(feature = "build")]
+pub mod build;
+pub mod choice;
+pub mod date_time;
+pub mod decimal;
+pub mod default;
+pub mod facets;
+pub mod fixed;
+pub mod group;
+pub mod integer;
+pub mod list;
+pub mod nillable;
+pub mod text_content;
@@ -0,0 +1,124 @@
+//! Lists of values separated by whitespaces, like `xs:IDREFS`, `xs:NMTOKENS` and `xs:ENTITIES`.
+
+use crate::text_content;
+use std::fmt::{self, Display};
+use std::io::{Read, Write};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct List<T>(pub Vec<T>);
+
+impl<T> List<T> {
+  pub fn into_inner(self) -> Vec<T> {
+    self.0
+  }
+}
+
+impl<T> Deref for List<T> {
+  type Target = Vec<T>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<T> DerefMut for List<T> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+  fn from(items: Vec<T>) -> Self {
+    List(items)
+  }
+}
+
+impl<T> FromStr for List<T>
+where
+  T: FromStr,
+  T::Err: ToString,
+{
+  type Err = String;
+
+  /// Items are separated by any sequence of XML whitespaces.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    split_items(value)
+      .map(|item| {
+        item
+          .parse()
+          .map_err(|e: T::Err| format!("Invalid list item {:?}: {}", item, e.to_string()))
+      })
+      .collect::<Result<Vec<T>, String>>()
+      .map(List)
+  }
+}
+
+impl<T: Display> Display for List<T> {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    for (index, item) in self.0.iter().enumerate() {
+      if index > 0 {
+        write!(formatter, " ")?;
+      }
+      write!(formatter, "{item}")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl<T> YaDeserialize for List<T>
+where
+  T: FromStr,
+  T::Err: ToString,
+{
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    text_content::deserialize(reader)
+  }
+}
+
+impl<T: Display> YaSerialize for List<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    text_content::serialize(self, "List", writer)
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Items of a list, separated by spaces, tabs, carriage returns or line feeds.
+pub fn split_items(value: &str) -> impl Iterator<Item = &str> {
+  value
+    .split([' ', '\t', '\r', '\n'])
+    .filter(|item| !item.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    let list: List<String> = " id1\tid2\n  id3 ".parse().unwrap();
+    assert_eq!(*list, vec!["id1", "id2", "id3"]);
+    assert_eq!(list.to_string(), "id1 id2 id3");
+
+    assert!("".parse::<List<String>>().unwrap().is_empty());
+
+    let error = "1 two 3".parse::<List<u8>>().unwrap_err();
+    assert!(error.contains("\"two\""));
+  }
+}
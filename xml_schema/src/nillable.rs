@@ -0,0 +1,174 @@
+//! Elements declared with `nillable="true"`, which can be sent with `xsi:nil="true"`
+//! instead of their content.
+
+use crate::text_content;
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use xml::reader::XmlEvent as ReaderEvent;
+use xml::writer::XmlEvent as WriterEvent;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+pub const XML_SCHEMA_INSTANCE_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Nillable element with a structured content.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Nillable<T> {
+  Nil,
+  Value(T),
+}
+
+/// Nillable element with a text content.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NillableText<T> {
+  Nil,
+  Value(T),
+}
+
+impl<T: Default> Default for Nillable<T> {
+  fn default() -> Self {
+    Nillable::Value(T::default())
+  }
+}
+
+impl<T: Default> Default for NillableText<T> {
+  fn default() -> Self {
+    NillableText::Value(T::default())
+  }
+}
+
+impl<T> Nillable<T> {
+  pub fn is_nil(&self) -> bool {
+    matches!(self, Nillable::Nil)
+  }
+
+  pub fn value(&self) -> Option<&T> {
+    match self {
+      Nillable::Nil => None,
+      Nillable::Value(value) => Some(value),
+    }
+  }
+}
+
+impl<T> NillableText<T> {
+  pub fn is_nil(&self) -> bool {
+    matches!(self, NillableText::Nil)
+  }
+
+  pub fn value(&self) -> Option<&T> {
+    match self {
+      NillableText::Nil => None,
+      NillableText::Value(value) => Some(value),
+    }
+  }
+}
+
+impl<T: YaDeserialize> YaDeserialize for Nillable<T> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    if deserialize_nil(reader)? {
+      return Ok(Nillable::Nil);
+    }
+
+    T::deserialize(reader).map(Nillable::Value)
+  }
+}
+
+impl<T> YaDeserialize for NillableText<T>
+where
+  T: FromStr,
+  T::Err: ToString,
+{
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    if deserialize_nil(reader)? {
+      return Ok(NillableText::Nil);
+    }
+
+    text_content::deserialize(reader).map(NillableText::Value)
+  }
+}
+
+impl<T: YaSerialize> YaSerialize for Nillable<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    match self {
+      Nillable::Nil => serialize_nil("Nillable", writer),
+      Nillable::Value(value) => value.serialize(writer),
+    }
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+impl<T: Display> YaSerialize for NillableText<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    match self {
+      NillableText::Nil => serialize_nil("NillableText", writer),
+      NillableText::Value(value) => text_content::serialize(value, "NillableText", writer),
+    }
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Consume the current element up to its end element if it has `xsi:nil="true"`.
+///
+/// The end element is not consumed, like for the structures derived by YaSerDe.
+fn deserialize_nil<R: Read>(reader: &mut Deserializer<R>) -> Result<bool, String> {
+  let is_nil = match reader.peek()? {
+    ReaderEvent::StartElement { attributes, .. } => attributes.iter().any(|attribute| {
+      attribute.name.local_name == "nil"
+        && attribute.name.namespace.as_deref() == Some(XML_SCHEMA_INSTANCE_NAMESPACE)
+        && (attribute.value == "true" || attribute.value == "1")
+    }),
+    _ => false,
+  };
+
+  if is_nil {
+    reader.next_event()?;
+    while !matches!(
+      reader.peek()?,
+      ReaderEvent::EndElement { .. } | ReaderEvent::EndDocument
+    ) {
+      reader.next_event()?;
+    }
+  }
+
+  Ok(is_nil)
+}
+
+fn serialize_nil<W: Write>(default_name: &str, writer: &mut Serializer<W>) -> Result<(), String> {
+  let element_name = writer
+    .get_start_event_name()
+    .unwrap_or_else(|| default_name.to_string());
+
+  let start_event = WriterEvent::start_element(element_name.as_str())
+    .ns("xsi", XML_SCHEMA_INSTANCE_NAMESPACE)
+    .attr("xsi:nil", "true");
+  writer.write(start_event).map_err(|e| e.to_string())?;
+  writer
+    .write(WriterEvent::end_element())
+    .map_err(|e| e.to_string())
+}
@@ -0,0 +1,220 @@
+//! YaSerDe helpers for types represented by a single text value.
+//!
+//! The same value can be used as an attribute or as the text content of an element,
+//! the generated code delegates to these functions for types implementing `FromStr` and `Display`.
+
+use std::fmt::{self, Display};
+use std::io::{Read, Write};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+use xml::reader::XmlEvent as ReaderEvent;
+use xml::writer::XmlEvent as WriterEvent;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+/// Value written with the lexical representation of XML Schema, which can differ from the
+/// `Display` one, like `2020-01-01T00:00:00Z` for a `chrono::DateTime<chrono::Utc>`.
+///
+/// It is implemented for the primitive types, the types of this crate, and the chrono and
+/// rust_decimal types with the features of the same name. The other types implement it, or use
+/// [`lexical_from_str!`](crate::lexical_from_str) when their `FromStr` and `Display`
+/// implementations follow the lexical representation.
+pub trait Lexical: Sized {
+  fn from_lexical(value: &str) -> Result<Self, String>;
+
+  fn to_lexical(&self) -> String;
+}
+
+/// Implement [`Lexical`] with `FromStr` and `Display`.
+#[macro_export]
+macro_rules! lexical_from_str {
+  ($($name:ty),*) => {
+    $(
+      impl $crate::text_content::Lexical for $name {
+        fn from_lexical(value: &str) -> Result<Self, String> {
+          value.parse().map_err(|e: <$name as std::str::FromStr>::Err| e.to_string())
+        }
+
+        fn to_lexical(&self) -> String {
+          self.to_string()
+        }
+      }
+    )*
+  };
+}
+
+crate::lexical_from_str!(
+  String, bool, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Any type implementing `Lexical`, used as a text value.
+///
+/// The generated code wraps the types substituted to the built-in types with `type_mapping`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Text<T>(pub T);
+
+impl<T> Text<T> {
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T> Deref for Text<T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    &self.0
+  }
+}
+
+impl<T> DerefMut for Text<T> {
+  fn deref_mut(&mut self) -> &mut T {
+    &mut self.0
+  }
+}
+
+impl<T> From<T> for Text<T> {
+  fn from(value: T) -> Self {
+    Text(value)
+  }
+}
+
+impl<T: Lexical> FromStr for Text<T> {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    T::from_lexical(value).map(Text)
+  }
+}
+
+impl<T: Lexical> Display for Text<T> {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "{}", self.0.to_lexical())
+  }
+}
+
+impl<T: Lexical> YaDeserialize for Text<T> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    deserialize(reader)
+  }
+}
+
+impl<T: Lexical> YaSerialize for Text<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    serialize(self, "Text", writer)
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<xml::attribute::OwnedAttribute>,
+    source_namespace: xml::namespace::Namespace,
+  ) -> Result<
+    (
+      Vec<xml::attribute::OwnedAttribute>,
+      xml::namespace::Namespace,
+    ),
+    String,
+  > {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Deserialize a value from the text content of the current element.
+///
+/// The end element is not consumed, like for the structures derived by YaSerDe.
+pub fn deserialize<T, R>(reader: &mut Deserializer<R>) -> Result<T, String>
+where
+  T: FromStr,
+  T::Err: ToString,
+  R: Read,
+{
+  loop {
+    match reader.peek()?.to_owned() {
+      ReaderEvent::StartElement { .. } => {
+        reader.next_event()?;
+      }
+      ReaderEvent::Characters(text_content) => {
+        reader.next_event()?;
+        return text_content.parse().map_err(|e: T::Err| e.to_string());
+      }
+      ReaderEvent::EndElement { .. } => {
+        return "".parse().map_err(|e: T::Err| e.to_string());
+      }
+      event => return Err(format!("Unable to parse text content, found {event:?}")),
+    }
+  }
+}
+
+/// Serialize a value as text, enclosed in an element when it is not serialized as an attribute.
+pub fn serialize<T, W>(
+  value: &T,
+  default_name: &str,
+  writer: &mut Serializer<W>,
+) -> Result<(), String>
+where
+  T: Display,
+  W: Write,
+{
+  let skip_start_end = writer.skip_start_end();
+
+  if !skip_start_end {
+    let element_name = writer
+      .get_start_event_name()
+      .unwrap_or_else(|| default_name.to_string());
+    let start_event = WriterEvent::start_element(element_name.as_str());
+    writer.write(start_event).map_err(|e| e.to_string())?;
+  }
+
+  let content = value.to_string();
+  let data_event = WriterEvent::characters(&content);
+  writer.write(data_event).map_err(|e| e.to_string())?;
+
+  if !skip_start_end {
+    let end_event = WriterEvent::end_element();
+    writer.write(end_event).map_err(|e| e.to_string())?;
+  }
+
+  Ok(())
+}
+
+/// YaSerDe and [`Lexical`] implementations of types through their text representation, with
+/// `FromStr` and `Display`.
+macro_rules! text_value {
+  ($($name:ty),*) => {
+    $(
+      $crate::lexical_from_str!($name);
+
+      impl yaserde::YaDeserialize for $name {
+        fn deserialize<R: std::io::Read>(
+          reader: &mut yaserde::de::Deserializer<R>,
+        ) -> Result<Self, String> {
+          $crate::text_content::deserialize(reader)
+        }
+      }
+
+      impl yaserde::YaSerialize for $name {
+        fn serialize<W: std::io::Write>(
+          &self,
+          writer: &mut yaserde::ser::Serializer<W>,
+        ) -> Result<(), String> {
+          $crate::text_content::serialize(self, stringify!($name), writer)
+        }
+
+        fn serialize_attributes(
+          &self,
+          source_attributes: Vec<xml::attribute::OwnedAttribute>,
+          source_namespace: xml::namespace::Namespace,
+        ) -> Result<
+          (
+            Vec<xml::attribute::OwnedAttribute>,
+            xml::namespace::Namespace,
+          ),
+          String,
+        > {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    )*
+  };
+}
+
+pub(crate) use text_value;
@@ -0,0 +1,33 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn catalog_offline() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/catalog.xsd",
+    catalog = "tests/catalog.xml",
+    offline = "true"
+  )]
+  struct CatalogSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Delivery>
+    <Recipient>Jane</Recipient>
+    <Address xmlns:common="http://example.com/common">
+      <common:City>Paris</common:City>
+    </Address>
+  </Delivery>
+  "#;
+
+  let sample_1: types::Delivery = from_str(xml_1).unwrap();
+
+  let model = types::Delivery {
+    recipient: "Jane".to_string(),
+    address: types::import_common::types::Address {
+      city: "Paris".to_string(),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+}
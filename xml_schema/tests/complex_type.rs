@@ -5,7 +5,7 @@ use yaserde::ser::to_string;
 #[test]
 fn complex_type_string() {
   #[derive(Debug, XmlSchema)]
-  #[xml_schema(source = "xml_schema/tests/complex_type.xsd")]
+  #[xml_schema(source = "tests/complex_type.xsd")]
   struct ComplexTypeSchema;
 
   let xml_1 = r#"
@@ -31,3 +31,866 @@ fn complex_type_string() {
     r#"<?xml version="1.0" encoding="utf-8"?><ComplexListOfElements><Annotation>Test content</Annotation><Label>Label content</Label></ComplexListOfElements>"#
   );
 }
+
+#[test]
+fn complex_type_choice() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_choice.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Shape>
+    <Square>12</Square>
+  </Shape>
+  "#;
+
+  let sample_1: types::Shape = from_str(xml_1).unwrap();
+
+  let model = types::Shape {
+    choice: types::ShapeChoice::Square(12),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Shape><Square>12</Square></Shape>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Paragraph>
+    <Title>Choices</Title>
+    <Text>First</Text>
+    <Image source="image.png" />
+    <Text>Second</Text>
+  </Paragraph>
+  "#;
+
+  let sample_2: types::Paragraph = from_str(xml_2).unwrap();
+
+  let model = types::Paragraph {
+    title: "Choices".to_string(),
+    choices: xml_schema::choice::Choices {
+      items: vec![
+        types::ParagraphChoice::Text("First".to_string()),
+        types::ParagraphChoice::Image(types::Image {
+          source: Some("image.png".to_string()),
+        }),
+        types::ParagraphChoice::Text("Second".to_string()),
+      ],
+    },
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Paragraph><Title>Choices</Title><Text>First</Text><Image source="image.png" /><Text>Second</Text></Paragraph>"#
+  );
+
+  let xml_3 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Contact>
+    <Name>Ada</Name>
+    <Fax>442000000000</Fax>
+    <Note>Evenings</Note>
+  </Contact>
+  "#;
+
+  let sample_3: types::Contact = from_str(xml_3).unwrap();
+
+  let model = types::Contact {
+    name: "Ada".to_string(),
+    choice: types::ContactChoice::Fax(442000000000).into(),
+    note: "Evenings".to_string(),
+  };
+
+  assert_eq!(sample_3, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Contact><Name>Ada</Name><Fax>442000000000</Fax><Note>Evenings</Note></Contact>"#
+  );
+
+  let data = to_string(&types::Contact {
+    choice: Default::default(),
+    ..model
+  })
+  .unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Contact><Name>Ada</Name><Note>Evenings</Note></Contact>"#
+  );
+
+  let invalid = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Contact>
+    <Name>Ada</Name>
+    <Phone>unknown</Phone>
+    <Note>Evenings</Note>
+  </Contact>
+  "#;
+
+  assert!(from_str::<types::Contact>(invalid).is_err());
+
+  let xml_4 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Gallery>
+    <Image source="first.png" />
+    <Image source="second.png" />
+  </Gallery>
+  "#;
+
+  let sample_4: types::Gallery = from_str(xml_4).unwrap();
+
+  let model = types::Gallery {
+    choices: xml_schema::choice::Choices {
+      items: vec![
+        types::GalleryChoice::Image(types::Image {
+          source: Some("first.png".to_string()),
+        }),
+        types::GalleryChoice::Image(types::Image {
+          source: Some("second.png".to_string()),
+        }),
+      ],
+    },
+  };
+
+  assert_eq!(sample_4, model);
+
+  let empty = r#"<?xml version="1.0" encoding="UTF-8"?><Gallery></Gallery>"#;
+
+  assert_eq!(
+    from_str::<types::Gallery>(empty).unwrap_err(),
+    "Missing elements for the choice, expected at least 1, got 0"
+  );
+  let xml_5 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Glossary>
+    <Term>Choice</Term>
+    <Definition>One of several alternatives</Definition>
+    <Image source="choice.png" />
+    <Note>Sequences are alternatives too</Note>
+    <Term>Sequence</Term>
+    <Image source="sequence.png" />
+  </Glossary>
+  "#;
+
+  let sample_5: types::Glossary = from_str(xml_5).unwrap();
+
+  let model = types::Glossary {
+    choices: xml_schema::choice::Choices {
+      items: vec![
+        types::GlossaryChoice::TermDefinitionImage {
+          term: "Choice".to_string(),
+          definition: Some("One of several alternatives".to_string()),
+          image: types::Image {
+            source: Some("choice.png".to_string()),
+          },
+        },
+        types::GlossaryChoice::Note("Sequences are alternatives too".to_string()),
+        types::GlossaryChoice::TermDefinitionImage {
+          term: "Sequence".to_string(),
+          definition: None,
+          image: types::Image {
+            source: Some("sequence.png".to_string()),
+          },
+        },
+      ],
+    },
+  };
+
+  assert_eq!(sample_5, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Glossary><Term>Choice</Term><Definition>One of several alternatives</Definition><Image source="choice.png" /><Note>Sequences are alternatives too</Note><Term>Sequence</Term><Image source="sequence.png" /></Glossary>"#
+  );
+
+  let incomplete =
+    r#"<?xml version="1.0" encoding="UTF-8"?><Glossary><Term>Choice</Term></Glossary>"#;
+
+  assert_eq!(
+    from_str::<types::Glossary>(incomplete).unwrap_err(),
+    "Missing element Image of the TermDefinitionImage alternative"
+  );
+}
+
+#[test]
+fn complex_type_element_ref() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_element_ref.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Book>
+    <Title>Guide</Title>
+    <Author>
+      <Name>Jane</Name>
+    </Author>
+    <Author>
+      <Name>John</Name>
+    </Author>
+  </Book>
+  "#;
+
+  let sample_1: types::Book = from_str(xml_1).unwrap();
+
+  let model = types::Book {
+    title: "Guide".to_string(),
+    authors: vec![
+      Author {
+        name: "Jane".to_string(),
+      },
+      Author {
+        name: "John".to_string(),
+      },
+    ],
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Book><Title>Guide</Title><Author><Name>Jane</Name></Author><Author><Name>John</Name></Author></Book>"#
+  );
+}
+
+#[test]
+fn complex_type_attribute_group() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_attribute_group.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Section id="intro" lang="en">
+    <Label lang="fr">Introduction</Label>
+  </Section>
+  "#;
+
+  let sample_1: types::Section = from_str(xml_1).unwrap();
+
+  let model = types::Section {
+    label: types::Label {
+      content: "Introduction".to_string(),
+      lang: Some("fr".to_string()),
+    },
+    id: "intro".to_string(),
+    lang: Some("en".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Section id="intro" lang="en"><Label lang="fr">Introduction</Label></Section>"#
+  );
+}
+
+#[test]
+fn complex_type_group() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_group.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Person>
+    <FirstName>Ada</FirstName>
+    <LastName>Lovelace</LastName>
+    <Nickname>Enchantress of Numbers</Nickname>
+    <Phone>+44 20 0000 0000</Phone>
+  </Person>
+  "#;
+
+  let sample_1: types::Person = from_str(xml_1).unwrap();
+
+  let model = types::Person {
+    first_name: "Ada".to_string(),
+    last_name: "Lovelace".to_string(),
+    nicknames: vec!["Enchantress of Numbers".to_string()],
+    choice: types::PersonChoice::Phone("+44 20 0000 0000".to_string()).into(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Person><FirstName>Ada</FirstName><LastName>Lovelace</LastName><Nickname>Enchantress of Numbers</Nickname><Phone>+44 20 0000 0000</Phone></Person>"#
+  );
+
+  let author = types::Author {
+    first_name: None,
+    last_name: Some("Lovelace".to_string()),
+  };
+
+  let data = to_string(&author).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Author><LastName>Lovelace</LastName></Author>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Team>
+    <Name>Analytical Engine</Name>
+    <FirstName>Ada</FirstName>
+    <LastName>Lovelace</LastName>
+    <FirstName>Charles</FirstName>
+    <LastName>Babbage</LastName>
+  </Team>
+  "#;
+
+  let sample_2: types::Team = from_str(xml_2).unwrap();
+
+  let identity = |first_name: &str, last_name: &str| types::TeamIdentity {
+    first_name: first_name.to_string(),
+    last_name: last_name.to_string(),
+  };
+
+  let model = types::Team {
+    name: "Analytical Engine".to_string(),
+    identitys: xml_schema::group::Groups {
+      items: vec![identity("Ada", "Lovelace"), identity("Charles", "Babbage")],
+    },
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Team><Name>Analytical Engine</Name><FirstName>Ada</FirstName><LastName>Lovelace</LastName><FirstName>Charles</FirstName><LastName>Babbage</LastName></Team>"#
+  );
+}
+
+#[test]
+fn complex_type_extension() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_extension.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Manager id="m1" role="CTO">
+    <Name>Grace</Name>
+    <Company>Navy</Company>
+    <Report id="p1">
+      <Name>Ada</Name>
+    </Report>
+  </Manager>
+  "#;
+
+  let sample_1: types::Manager = from_str(xml_1).unwrap();
+
+  let model = types::Manager {
+    name: "Grace".to_string(),
+    company: "Navy".to_string(),
+    reports: vec![types::Person {
+      name: "Ada".to_string(),
+      id: "p1".to_string(),
+    }],
+    id: "m1".to_string(),
+    role: Some("CTO".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Manager id="m1" role="CTO"><Name>Grace</Name><Company>Navy</Company><Report id="p1"><Name>Ada</Name></Report></Manager>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Contractor id="c1">
+    <Name>Charles</Name>
+    <Rate>300</Rate>
+  </Contractor>
+  "#;
+
+  let sample_2: types::Contractor = from_str(xml_2).unwrap();
+
+  let model = types::Contractor {
+    name: "Charles".to_string(),
+    choice: types::ContractorChoice::Rate(300),
+    id: "c1".to_string(),
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Contractor id="c1"><Name>Charles</Name><Rate>300</Rate></Contractor>"#
+  );
+
+  let model = types::Visitor {
+    name: "Alan".to_string(),
+    badge_id: "B42".to_string(),
+    id: "v1".to_string(),
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Visitor id="v1"><Name>Alan</Name><BadgeId>B42</BadgeId></Visitor>"#
+  );
+  assert_eq!(from_str::<types::Visitor>(&data).unwrap(), model);
+}
+
+#[test]
+fn complex_type_restriction() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_restriction.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Link id="home">
+    <Title>Home</Title>
+  </Link>
+  "#;
+
+  let sample_1: types::Link = from_str(xml_1).unwrap();
+
+  let model = types::Link {
+    title: "Home".to_string(),
+    id: "home".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Link id="home"><Title>Home</Title></Link>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <CountryCode system="ISO 3166">FR</CountryCode>
+  "#;
+
+  let sample_2: types::CountryCode = from_str(xml_2).unwrap();
+
+  let model = types::CountryCode {
+    content: types::CountryCodeContent::new("FR".to_string()).unwrap(),
+    system: Some("ISO 3166".to_string()),
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><CountryCode system="ISO 3166">FR</CountryCode>"#
+  );
+
+  // the facets are checked when deserializing the text content
+  assert!(from_str::<types::CountryCode>(r#"<CountryCode>FRA</CountryCode>"#).is_err());
+  assert!(types::CountryCodeContent::new("FRA".to_string()).is_err());
+
+  // the facets of the restricted base apply to the derived restriction
+  let sample_3: types::UpperCountryCode =
+    from_str(r#"<UpperCountryCode system="ISO 3166">FR</UpperCountryCode>"#).unwrap();
+
+  assert_eq!(sample_3.content.content(), "FR");
+  assert!(
+    from_str::<types::UpperCountryCode>(r#"<UpperCountryCode>fr</UpperCountryCode>"#).is_err()
+  );
+  assert!(
+    from_str::<types::UpperCountryCode>(r#"<UpperCountryCode>FRA</UpperCountryCode>"#).is_err()
+  );
+}
+
+#[test]
+fn complex_type_attribute_default() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_attribute_default.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Section id="intro">
+    <Title>Introduction</Title>
+  </Section>
+  "#;
+
+  let sample_1: types::Section = from_str(xml_1).unwrap();
+
+  let model = types::Section {
+    title: "Introduction".to_string(),
+    id: "intro".to_string(),
+    lang: "en".to_string(),
+    level: 1,
+    version: Default::default(),
+    revision: Default::default(),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(model.version.value(), "1.0");
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Section id="intro" version="1.0" revision="1"><Title>Introduction</Title></Section>"#
+  );
+
+  let model = types::Section {
+    lang: "fr".to_string(),
+    level: 2,
+    ..model
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Section id="intro" lang="fr" level="2" version="1.0" revision="1"><Title>Introduction</Title></Section>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Section id="intro" version="2.0">
+    <Title>Introduction</Title>
+  </Section>
+  "#;
+
+  assert!(from_str::<types::Section>(xml_2).is_err());
+
+  // the fixed values are compared once parsed
+  let xml_3 = r#"<Section id="intro" revision="01"><Title>Introduction</Title></Section>"#;
+  assert!(from_str::<types::Section>(xml_3).is_ok());
+
+  let xml_4 = r#"<Section id="intro" revision="2"><Title>Introduction</Title></Section>"#;
+  assert!(from_str::<types::Section>(xml_4).is_err());
+}
+
+#[test]
+fn complex_type_serialize_default_attributes() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/complex_type_attribute_default.xsd",
+    serialize_default_attributes = "true"
+  )]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Section id="intro">
+    <Title>Introduction</Title>
+  </Section>
+  "#;
+
+  let sample_1: types::Section = from_str(xml_1).unwrap();
+
+  assert_eq!(*sample_1.lang, "en");
+  assert_eq!(*sample_1.level, 1);
+
+  let data = to_string(&sample_1).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Section id="intro" lang="en" level="1" version="1.0" revision="1"><Title>Introduction</Title></Section>"#
+  );
+}
+
+#[test]
+fn complex_type_element_value() {
+  use xml_schema::default::Defaulted;
+  use xml_schema::nillable::{Nillable, NillableText};
+
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_element_value.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Measure xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+    <Value xsi:nil="true"/>
+    <Unit></Unit>
+    <Scale>linear</Scale>
+    <Location xsi:nil="true"/>
+  </Measure>
+  "#;
+
+  let sample_1: types::Measure = from_str(xml_1).unwrap();
+
+  let model = types::Measure {
+    value: NillableText::Nil,
+    unit: Defaulted::new("cm".to_string()),
+    scale: Some(Default::default()),
+    location: Some(Nillable::Nil),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Measure><Value xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:nil="true" /><Unit>cm</Unit><Scale>linear</Scale><Location xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:nil="true" /></Measure>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Measure>
+    <Value>12.5</Value>
+    <Unit>mm</Unit>
+    <Location>
+      <X>3</X>
+    </Location>
+  </Measure>
+  "#;
+
+  let sample_2: types::Measure = from_str(xml_2).unwrap();
+
+  let model = types::Measure {
+    value: NillableText::Value(12.5),
+    unit: Defaulted::new("mm".to_string()),
+    scale: None,
+    location: Some(Nillable::Value(types::Point { x: "3".to_string() })),
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Measure><Value>12.5</Value><Unit>mm</Unit><Location><X>3</X></Location></Measure>"#
+  );
+
+  let xml_3 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Measure>
+    <Value>1</Value>
+    <Unit>cm</Unit>
+    <Scale>log</Scale>
+  </Measure>
+  "#;
+
+  assert!(from_str::<types::Measure>(xml_3).is_err());
+}
+
+#[test]
+fn complex_type_date_time() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_date_time.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Event day="2002-05-30" doors="19:30:00+02:00">
+    <Start>2002-05-30T20:00:00.5+02:00</Start>
+    <Length>PT2H30M</Length>
+    <Anniversary>--05-30</Anniversary>
+  </Event>
+  "#;
+
+  let sample_1: types::Event = from_str(xml_1).unwrap();
+
+  let model = types::Event {
+    start: "2002-05-30T20:00:00.5+02:00".parse().unwrap(),
+    length: "PT2H30M".parse().unwrap(),
+    anniversary: Some("--05-30".parse().unwrap()),
+    day: "2002-05-30".parse().unwrap(),
+    doors: Some("19:30:00+02:00".parse().unwrap()),
+  };
+
+  assert_eq!(sample_1, model);
+  assert!(model.start < "2002-05-30T19:00:00Z".parse().unwrap());
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Event day="2002-05-30" doors="19:30:00+02:00"><Start>2002-05-30T20:00:00.5+02:00</Start><Length>PT2H30M</Length><Anniversary>--05-30</Anniversary></Event>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Event day="2002-05-30">
+    <Start>2002-05-30 20:00:00</Start>
+    <Length>PT2H30M</Length>
+  </Event>
+  "#;
+
+  assert!(from_str::<types::Event>(xml_2).is_err());
+
+  let xml_3 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Timestamp source="clock">2002-05-30T20:00:00+02:00</Timestamp>
+  "#;
+
+  let sample_3: types::Timestamp = from_str(xml_3).unwrap();
+
+  let model = types::Timestamp {
+    content: "2002-05-30T20:00:00+02:00".parse().unwrap(),
+    source: Some("clock".to_string()),
+  };
+
+  assert_eq!(sample_3, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Timestamp source="clock">2002-05-30T20:00:00+02:00</Timestamp>"#
+  );
+
+  assert!(from_str::<types::Timestamp>(r#"<Timestamp>2002-05-30</Timestamp>"#).is_err());
+}
+
+#[test]
+fn complex_type_numeric() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_numeric.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Trade id="98765432109876543210">
+    <Price>1234.50</Price>
+    <Offset>-3</Offset>
+  </Trade>
+  "#;
+
+  let sample_1: types::Trade = from_str(xml_1).unwrap();
+
+  let model = types::Trade {
+    price: "1234.5".parse().unwrap(),
+    offset: Some("-3".parse().unwrap()),
+    id: "98765432109876543210".parse().unwrap(),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(model.id.to_string(), "98765432109876543210");
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Trade id="98765432109876543210"><Price>1234.5</Price><Offset>-3</Offset></Trade>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Trade id="1">
+    <Price>1234.50</Price>
+    <Offset>3</Offset>
+  </Trade>
+  "#;
+
+  assert!(from_str::<types::Trade>(xml_2).is_err());
+
+  // the text content of a simple content extension keeps the type of its base
+  let xml_3 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Money currency="EUR">12.50</Money>
+  "#;
+
+  let sample_3: types::Money = from_str(xml_3).unwrap();
+
+  let model = types::Money {
+    content: "12.5".parse().unwrap(),
+    currency: "EUR".to_string(),
+  };
+
+  assert_eq!(sample_3, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Money currency="EUR">12.5</Money>"#
+  );
+
+  let sample_4: types::Quantity = from_str(r#"<Quantity unit="kg">42</Quantity>"#).unwrap();
+
+  assert_eq!(*sample_4.content, 42);
+  assert_eq!(
+    to_string(&sample_4).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><Quantity unit="kg">42</Quantity>"#
+  );
+
+  assert!(from_str::<types::Money>(r#"<Money currency="EUR">twelve</Money>"#).is_err());
+}
+
+#[test]
+fn complex_type_binary() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_binary.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Picture checksum="c0ffee">
+    <Thumbnail>iVBORw0K
+      Gg==</Thumbnail>
+  </Picture>
+  "#;
+
+  let sample_1: types::Picture = from_str(xml_1).unwrap();
+
+  let model = types::Picture {
+    thumbnail: vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a].into(),
+    checksum: vec![0xc0, 0xff, 0xee].into(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Picture checksum="C0FFEE"><Thumbnail>iVBORw0KGg==</Thumbnail></Picture>"#
+  );
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Picture checksum="c0ffe">
+    <Thumbnail>iVBORw0KGg==</Thumbnail>
+  </Picture>
+  "#;
+
+  assert!(from_str::<types::Picture>(xml_2).is_err());
+
+  let xml_3 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Attachment mediaType="image/png">iVBORw0KGg==</Attachment>
+  "#;
+
+  let sample_3: types::Attachment = from_str(xml_3).unwrap();
+
+  let model = types::Attachment {
+    content: vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a].into(),
+    media_type: "image/png".to_string(),
+  };
+
+  assert_eq!(sample_3, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Attachment mediaType="image/png">iVBORw0KGg==</Attachment>"#
+  );
+
+  let sample_4: types::Digest = from_str(r#"<Digest algorithm="crc">c0ffee</Digest>"#).unwrap();
+
+  assert_eq!(sample_4.content, vec![0xc0, 0xff, 0xee].into());
+  assert_eq!(
+    to_string(&sample_4).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><Digest algorithm="crc">C0FFEE</Digest>"#
+  );
+}
+
+#[test]
+fn complex_type_builtin() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_builtin.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Node id="n1" links="n2  n3" kind="xs:string">
+    <Label>first</Label>
+    <Tags>
+      red green
+      blue
+    </Tags>
+  </Node>
+  "#;
+
+  let sample_1: types::Node = from_str(xml_1).unwrap();
+
+  let model = types::Node {
+    label: "first".to_string(),
+    tags: vec!["red".to_string(), "green".to_string(), "blue".to_string()].into(),
+    id: "n1".to_string(),
+    links: Some(vec!["n2".to_string(), "n3".to_string()].into()),
+    kind: Some("xs:string".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Node id="n1" links="n2 n3" kind="xs:string"><Label>first</Label><Tags>red green blue</Tags></Node>"#
+  );
+}
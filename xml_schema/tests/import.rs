@@ -0,0 +1,114 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn import_and_include() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/import.xsd")]
+  struct ImportSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Person>
+    <Name>Jane</Name>
+    <Address xmlns:common="http://example.com/common">
+      <common:City>Paris</common:City>
+    </Address>
+    <Contact email="jane@example.com" />
+    <Country>France</Country>
+  </Person>
+  "#;
+
+  let sample_1: types::Person = from_str(xml_1).unwrap();
+
+  let model = types::Person {
+    name: "Jane".to_string(),
+    address: types::import_common::types::Address {
+      city: "Paris".to_string(),
+    },
+    contact: types::Contact {
+      email: Some("jane@example.com".to_string()),
+    },
+    country: "France".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Person><Name>Jane</Name><Address xmlns:common="http://example.com/common"><common:City>Paris</common:City></Address><Contact email="jane@example.com" /><Country>France</Country></Person>"#
+  );
+
+  // the attribute group of the imported schema uses its own prefix for its types
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?><Parcel weight="2" code="FR" />"#;
+
+  let sample_2: types::Parcel = from_str(xml_2).unwrap();
+
+  let model = types::Parcel {
+    weight: Some(2),
+    code: Some(types::import_common::types::Code::new("FR".to_string()).unwrap()),
+  };
+
+  assert_eq!(sample_2, model);
+  assert!(from_str::<types::Parcel>(r#"<Parcel code="FRA" />"#).is_err());
+
+  // the imported base type of an extension uses its own prefix for its types
+  let xml_3 =
+    r#"<?xml version="1.0" encoding="UTF-8"?><Shipment weight="3"><Zone>EU</Zone></Shipment>"#;
+
+  let sample_3: types::Shipment = from_str(xml_3).unwrap();
+
+  let model = types::Shipment {
+    zone: types::import_common::types::Code::new("EU".to_string()).unwrap(),
+    weight: Some(3),
+  };
+
+  assert_eq!(sample_3, model);
+}
+
+#[test]
+fn import_diamond() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/import_diamond.xsd")]
+  struct ImportSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Company>
+    <Office xmlns:common="http://example.com/common">
+      <common:City>Paris</common:City>
+    </Office>
+    <Owner>
+      <Home xmlns:common="http://example.com/common">
+        <common:City>Lyon</common:City>
+      </Home>
+      <Details email="owner@example.com" />
+    </Owner>
+    <Contact email="office@example.com" />
+  </Company>
+  "#;
+
+  let sample_1: types::Company = from_str(xml_1).unwrap();
+
+  let model = types::Company {
+    office: types::import_common::types::Address {
+      city: "Paris".to_string(),
+    },
+    owner: types::import_contact::types::Owner {
+      home: types::import_common::types::Address {
+        city: "Lyon".to_string(),
+      },
+      details: types::import_contact::types::Contact {
+        email: Some("owner@example.com".to_string()),
+      },
+    },
+    contact: types::Contact {
+      email: Some("office@example.com".to_string()),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  // re-exported by the imported schema importing it too
+  let _: &types::import_contact::types::import_common::types::Address = &model.office;
+}
@@ -0,0 +1,38 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn inline_schema() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(inline = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+      <xs:include schemaLocation="tests/import_included.xsd"/>
+      <xs:complexType name="Member">
+        <xs:sequence>
+          <xs:element name="Name" type="xs:string"/>
+          <xs:element name="Contact" type="Contact"/>
+        </xs:sequence>
+        <xs:attribute name="id" type="xs:unsignedInt" use="required"/>
+      </xs:complexType>
+    </xs:schema>
+  "#)]
+  struct InlineSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Member id="7"><Name>Jane</Name><Contact email="jane@example.com" /></Member>"#;
+
+  let sample_1: types::Member = from_str(xml_1).unwrap();
+
+  let model = types::Member {
+    name: "Jane".to_string(),
+    contact: types::Contact {
+      email: Some("jane@example.com".to_string()),
+    },
+    id: 7,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+}
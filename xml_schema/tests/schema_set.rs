@@ -0,0 +1,64 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn shared_imports() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/schema_set_customer.xsd",
+    source = "tests/schema_set_order.xsd"
+  )]
+  struct OrderSchemas;
+
+  let address = import_common::types::Address {
+    city: "Paris".to_string(),
+  };
+
+  let model = schema_set_order::types::Order {
+    customer: schema_set_customer::types::Customer {
+      name: "Jane".to_string(),
+      address: address.clone(),
+    },
+    shipping_address: address,
+    country: "France".to_string(),
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Order><Customer xmlns:cust="http://example.com/customer"><cust:Name>Jane</cust:Name><cust:Address xmlns:common="http://example.com/common"><common:City>Paris</common:City></cust:Address></Customer><ShippingAddress xmlns:common="http://example.com/common"><common:City>Paris</common:City></ShippingAddress><Country>France</Country></Order>"#
+  );
+
+  let sample: schema_set_order::types::Order = from_str(&data).unwrap();
+
+  assert_eq!(sample, model);
+}
+
+#[test]
+fn target_prefixes() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/schema_set_customer.xsd",
+    target_prefix = "customer",
+    source = "tests/schema_set_order.xsd"
+  )]
+  struct OrderSchemas;
+
+  let model = schema_set_customer::types::Customer {
+    name: "Jane".to_string(),
+    address: import_common::types::Address {
+      city: "Paris".to_string(),
+    },
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><customer:Customer xmlns:customer="http://example.com/customer"><customer:Name>Jane</customer:Name><customer:Address xmlns:common="http://example.com/common"><common:City>Paris</common:City></customer:Address></customer:Customer>"#
+  );
+
+  let sample: schema_set_customer::types::Customer = from_str(&data).unwrap();
+
+  assert_eq!(sample, model);
+}
@@ -5,10 +5,7 @@ use yaserde::ser::to_string;
 #[test]
 fn simple_type_string() {
   #[derive(Debug, XmlSchema)]
-  #[xml_schema(
-    source = "xml_schema/tests/simple_type_string.xsd",
-    target_prefix = "st"
-  )]
+  #[xml_schema(source = "tests/simple_type_string.xsd", target_prefix = "st")]
   struct SimpleTypeSchema;
 
   let xml_1 = r#"
@@ -30,7 +27,7 @@ fn simple_type_string() {
 #[test]
 fn simple_type_list() {
   #[derive(Debug, XmlSchema)]
-  #[xml_schema(source = "xml_schema/tests/simple_type_list.xsd")]
+  #[xml_schema(source = "tests/simple_type_list.xsd")]
   struct SimpleTypeSchema;
 
   let xml_1 = r#"
@@ -44,7 +41,9 @@ fn simple_type_list() {
     strings: Some(types::StringList {
       items: vec!["value1".to_string(), "value2".to_string()],
     }),
-    integers: Some(types::IntegerList { items: vec![3, 6] }),
+    integers: Some(types::IntegerList {
+      items: vec![3.into(), 6.into()],
+    }),
     booleans: Some(types::BooleanList {
       items: vec![true, false],
     }),
@@ -58,3 +57,194 @@ fn simple_type_list() {
     r#"<?xml version="1.0" encoding="utf-8"?><BaseType strings="value1 value2" integers="3 6" booleans="true false" />"#
   );
 }
+
+#[test]
+fn simple_type_list_items() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/simple_type_list.xsd")]
+  struct SimpleTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Selection>
+    <Sizes>
+      10	20
+      30
+    </Sizes>
+    <Tags>red  green</Tags>
+  </Selection>
+  "#;
+
+  let sample_1: types::Selection = from_str(xml_1).unwrap();
+
+  let model = types::Selection {
+    sizes: types::SizeList {
+      items: vec![
+        types::SizeListItem::new(10).unwrap(),
+        types::SizeListItem::new(20).unwrap(),
+        types::SizeListItem::new(30).unwrap(),
+      ],
+    },
+    tags: types::TagList::new(types::StringList {
+      items: vec!["red".to_string(), "green".to_string()],
+    })
+    .unwrap(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Selection><Sizes>10 20 30</Sizes><Tags>red green</Tags></Selection>"#
+  );
+
+  let error = "10 x 30".parse::<types::SizeList>().unwrap_err();
+  assert!(error.contains("\"x\""));
+  assert!("10 200".parse::<types::SizeList>().is_err());
+
+  assert!("".parse::<types::TagList>().is_err());
+  assert!("a b c d".parse::<types::TagList>().is_err());
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Selection><Sizes>10 x</Sizes><Tags>red</Tags></Selection>
+  "#;
+  assert!(from_str::<types::Selection>(xml_2).is_err());
+}
+
+#[test]
+fn simple_type_enumeration() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/simple_type_enumeration.xsd")]
+  struct SimpleTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Picture scan="interlaced">
+    <Range>narrow-range</Range>
+  </Picture>
+  "#;
+
+  let sample_1: types::Picture = from_str(xml_1).unwrap();
+
+  let model = types::Picture {
+    range: types::ColorRange::NarrowRange,
+    scan: types::ScanType::Interlaced,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Picture scan="interlaced"><Range>narrow-range</Range></Picture>"#
+  );
+
+  assert_eq!(types::ColorRange::default(), types::ColorRange::Full);
+  assert_eq!(
+    "full".parse::<types::ColorRange>(),
+    Ok(types::ColorRange::Full)
+  );
+  assert!("unknown".parse::<types::ColorRange>().is_err());
+  assert_eq!(types::ScanType::Progressive.to_string(), "progressive");
+
+  assert_eq!("self".parse(), Ok(types::Source::ValueSelf));
+  assert_eq!("X2".parse(), Ok(types::Source::X2));
+  assert_eq!("x".parse(), Ok(types::Source::X3));
+}
+
+#[test]
+fn simple_type_facets() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/simple_type_facets.xsd")]
+  struct SimpleTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Track volume="80" gain="-3.25">
+    <Code>fra</Code>
+  </Track>
+  "#;
+
+  let sample_1: types::Track = from_str(xml_1).unwrap();
+
+  let model = types::Track {
+    code: types::LanguageCode::new("fra".to_string()).unwrap(),
+    volume: types::Volume::new(80).unwrap(),
+    gain: Some(types::Gain::new("-3.25".parse().unwrap()).unwrap()),
+    price: None,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Track volume="80" gain="-3.25"><Code>fra</Code></Track>"#
+  );
+
+  assert!(types::Volume::new(101).is_err());
+  assert!(types::LanguageCode::new("fr".to_string()).is_err());
+  assert!(types::LanguageCode::new("FRA".to_string()).is_err());
+  assert!(types::Gain::new("12".parse().unwrap()).is_err());
+  assert!(types::Gain::new("1.125".parse().unwrap()).is_err());
+
+  // the pattern is checked on the text as written, before parsing the value
+  assert!("1.50".parse::<types::Price>().is_ok());
+  assert!("1.5".parse::<types::Price>().is_err());
+  assert!(
+    from_str::<types::Track>(r#"<Track volume="80" price="1.50"><Code>fra</Code></Track>"#).is_ok()
+  );
+
+  assert_eq!(types::LanguageCode::default().content(), "aaa");
+  assert_eq!(*types::Volume::default().content(), 0);
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Track volume="180">
+    <Code>fra</Code>
+  </Track>
+  "#;
+
+  let sample_2: Result<types::Track, String> = from_str(xml_2);
+  assert!(sample_2.is_err());
+}
+
+#[test]
+fn simple_type_union() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/simple_type_union.xsd")]
+  struct SimpleTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Clip duration="infinite">
+    <Start>10:00:00:00</Start>
+  </Clip>
+  "#;
+
+  let sample_1: types::Clip = from_str(xml_1).unwrap();
+
+  let model = types::Clip {
+    start: types::Timecode::String("10:00:00:00".to_string()),
+    duration: types::Duration::Member1(types::DurationMember1::Infinite),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Clip duration="250">
+    <Start>900</Start>
+  </Clip>
+  "#;
+
+  let sample_2: types::Clip = from_str(xml_2).unwrap();
+
+  let model = types::Clip {
+    start: types::Timecode::UnsignedInt(900),
+    duration: types::Duration::UnsignedInt(250),
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Clip duration="250"><Start>900</Start></Clip>"#
+  );
+}
@@ -0,0 +1,153 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use xml_schema::text_content::Text;
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+/// Boolean written as `1` or `0`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Flag(bool);
+
+impl FromStr for Flag {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value {
+      "true" | "1" => Ok(Flag(true)),
+      "false" | "0" => Ok(Flag(false)),
+      _ => Err(format!("Invalid flag {value:?}")),
+    }
+  }
+}
+
+impl Display for Flag {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "{}", self.0 as u8)
+  }
+}
+
+/// Position in frames, at 25 frames per second.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timecode(u32);
+
+impl FromStr for Timecode {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    value
+      .split(':')
+      .map(|item| item.parse::<u32>().map_err(|e| e.to_string()))
+      .collect::<Result<Vec<u32>, String>>()
+      .and_then(|items| match items.as_slice() {
+        [hours, minutes, seconds, frames] => Ok(Timecode(
+          ((hours * 60 + minutes) * 60 + seconds) * 25 + frames,
+        )),
+        _ => Err(format!("Invalid timecode {value:?}")),
+      })
+  }
+}
+
+impl Display for Timecode {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    let seconds = self.0 / 25;
+    write!(
+      formatter,
+      "{:02}:{:02}:{:02}:{:02}",
+      seconds / 3600,
+      seconds / 60 % 60,
+      seconds % 60,
+      self.0 % 25
+    )
+  }
+}
+
+xml_schema::lexical_from_str!(Flag, Timecode);
+
+#[derive(
+  Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize,
+)]
+pub struct Location {
+  #[yaserde(attribute)]
+  pub x: Option<i32>,
+}
+
+#[test]
+fn type_mapping() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/type_mapping.xsd",
+    type_mapping = "xs:boolean: crate::Flag",
+    type_mapping = "Timecode: crate::Timecode",
+    type_mapping = "Location: crate::Location"
+  )]
+  struct TypeMappingSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Marker enabled="true">
+    <Position>00:01:02:10</Position>
+    <Location x="12" />
+    <Title>Intro</Title>
+  </Marker>
+  "#;
+
+  let sample_1: types::Marker = from_str(xml_1).unwrap();
+
+  let model = types::Marker {
+    position: Text(Timecode(1560)),
+    location: Some(Location { x: Some(12) }),
+    title: "Intro".to_string(),
+    enabled: Some(Text(Flag(true))),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Marker enabled="1"><Position>00:01:02:10</Position><Location x="12" /><Title>Intro</Title></Marker>"#
+  );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_type_mapping() {
+  use chrono::{TimeZone, Utc};
+
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    inline = r#"
+      <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:complexType name="Event">
+          <xs:sequence>
+            <xs:element name="Start" type="xs:dateTime"/>
+          </xs:sequence>
+          <xs:attribute name="end" type="xs:dateTime"/>
+        </xs:complexType>
+      </xs:schema>
+    "#,
+    type_mapping = "xs:dateTime: chrono::DateTime<chrono::Utc>"
+  )]
+  struct ChronoSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?>
+  <Event end="2020-01-01T12:30:00+02:00">
+    <Start>2020-01-01T08:00:00Z</Start>
+  </Event>
+  "#;
+
+  let sample_1: types::Event = from_str(xml_1).unwrap();
+
+  let model = types::Event {
+    start: Text(Utc.with_ymd_and_hms(2020, 1, 1, 8, 0, 0).unwrap()),
+    end: Some(Text(Utc.with_ymd_and_hms(2020, 1, 1, 10, 30, 0).unwrap())),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Event end="2020-01-01T10:30:00Z"><Start>2020-01-01T08:00:00Z</Start></Event>"#
+  );
+}
@@ -1,37 +1,108 @@
-use proc_macro2::{token_stream::IntoIter, Delimiter, TokenTree};
+use proc_macro2::{token_stream::IntoIter, Delimiter, Span, TokenTree};
 use std::collections::BTreeMap;
-use syn::Attribute;
+use syn::{Attribute, LitStr, Type};
+use xml_schema_generator::errors::Error;
+
+const KEYS: [&str; 11] = [
+  "cache_directory",
+  "catalog",
+  "inline",
+  "log_level",
+  "module_namespace_mapping",
+  "offline",
+  "serialize_default_attributes",
+  "source",
+  "store_generated_code",
+  "target_prefix",
+  "type_mapping",
+];
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct XmlSchemaAttribute {
+  pub cache_directory: Option<String>,
+  pub catalog: Option<String>,
   pub log_level: log::Level,
   pub module_namespace_mappings: BTreeMap<String, String>,
-  pub source: String,
+  pub offline: bool,
+  pub serialize_default_attributes: bool,
+  pub source: Source,
   pub store_generated_code: Option<String>,
   pub target_prefix: Option<String>,
+  /// Prefixes of the target namespaces by source, when there are several of them.
+  pub target_prefixes: BTreeMap<String, String>,
+  pub type_mappings: BTreeMap<String, String>,
+}
+
+/// Schemas to generate, files or URLs, or the content of one.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Source {
+  Locations(Vec<String>),
+  Inline(String),
+}
+
+/// Error of the `xml_schema` attribute, reported on the tokens causing it when they are known.
+#[derive(Debug)]
+pub struct AttributeError {
+  pub error: Error,
+  pub span: Option<Span>,
+}
+
+impl AttributeError {
+  fn spanned(error: Error, span: Span) -> Self {
+    AttributeError {
+      error,
+      span: Some(span),
+    }
+  }
 }
 
-fn get_value(iter: &mut IntoIter) -> Option<String> {
-  if let (Some(TokenTree::Punct(operator)), Some(TokenTree::Literal(value))) =
-    (iter.next(), iter.next())
-  {
-    if operator.as_char() == '=' {
-      Some(value.to_string().replace('\"', ""))
-    } else {
-      panic!("Missing `=` operator");
+impl From<Error> for AttributeError {
+  fn from(error: Error) -> Self {
+    AttributeError { error, span: None }
+  }
+}
+
+/// The string value following the key, and the span of its literal.
+fn get_value(iter: &mut IntoIter, key: &str) -> Result<(String, Span), Error> {
+  match (iter.next(), iter.next()) {
+    (Some(TokenTree::Punct(operator)), Some(TokenTree::Literal(value))) => {
+      if operator.as_char() == '=' {
+        let span = value.span();
+        syn::parse2::<LitStr>(TokenTree::Literal(value).into())
+          .map(|value| (value.value(), span))
+          .map_err(|_| {
+            Error::new(format!("Bad value for {key}, a string is expected"))
+              .with_hint(format!("write `{key} = \"value\"`"))
+          })
+      } else {
+        Err(
+          Error::new(format!("Missing `=` operator after {key}"))
+            .with_hint(format!("write `{key} = \"value\"`")),
+        )
+      }
     }
-  } else {
-    panic!("Missing items to get the associated value");
+    _ => Err(
+      Error::new(format!("Missing value for {key}"))
+        .with_hint(format!("write `{key} = \"value\"`")),
+    ),
   }
 }
 
 impl XmlSchemaAttribute {
-  pub fn parse(attrs: &[Attribute]) -> XmlSchemaAttribute {
+  /// Parse the `xml_schema` attributes, all the errors found are returned.
+  pub fn parse(attrs: &[Attribute]) -> Result<XmlSchemaAttribute, Vec<AttributeError>> {
+    let mut cache_directory = None;
+    let mut catalog = None;
     let mut log_level = log::Level::Warn;
     let mut module_namespace_mappings = BTreeMap::new();
-    let mut source = None;
+    let mut offline = false;
+    let mut serialize_default_attributes = false;
+    let mut sources = vec![];
+    let mut inline = None;
     let mut store_generated_code = None;
-    let mut target_prefix = None;
+    let mut target_prefixes = vec![];
+    let mut type_mappings = BTreeMap::new();
+    let mut errors: Vec<AttributeError> = vec![];
 
     for attr in attrs.iter() {
       if !attr.path.is_ident("xml_schema") {
@@ -45,41 +116,91 @@ impl XmlSchemaAttribute {
 
           while let Some(item) = attr_iter.next() {
             if let TokenTree::Ident(ident) = item {
-              match ident.to_string().as_str() {
+              let key = ident.to_string();
+              if !KEYS.contains(&key.as_str()) {
+                errors.push(AttributeError::spanned(
+                  Error::new(format!("Bad XmlSchema attribute: {key}"))
+                    .with_hint(format!("expected one of {}", KEYS.join(", "))),
+                  ident.span(),
+                ));
+                break;
+              }
+
+              let (value, span) = match get_value(&mut attr_iter, &key) {
+                Ok(value) => value,
+                Err(error) => {
+                  errors.push(AttributeError::spanned(error, ident.span()));
+                  break;
+                }
+              };
+
+              match key.as_str() {
+                "cache_directory" => cache_directory = Some(value),
+                "catalog" => catalog = Some(value),
+                "inline" => inline = Some(value),
                 "module_namespace_mapping" => {
-                  if let Some(module_namespace_mapping) = get_value(&mut attr_iter) {
-                    let splitted: Vec<&str> = module_namespace_mapping.split(": ").collect();
-                    if splitted.len() == 2 {
-                      module_namespace_mappings
-                        .insert(splitted[0].to_owned(), splitted[1].to_owned());
-                    }
-                    if splitted.len() == 1 {
-                      module_namespace_mappings.insert("".to_owned(), splitted[0].to_owned());
-                    }
+                  let splitted: Vec<&str> = value.split(": ").collect();
+                  if splitted.len() == 2 {
+                    module_namespace_mappings
+                      .insert(splitted[0].to_owned(), splitted[1].to_owned());
                   }
-                }
-                "source" => {
-                  source = get_value(&mut attr_iter);
-                }
-                "store_generated_code" => {
-                  store_generated_code = get_value(&mut attr_iter);
-                }
-                "target_prefix" => {
-                  target_prefix = get_value(&mut attr_iter);
-                }
-                "log_level" => {
-                  if let Some(value) = get_value(&mut attr_iter) {
-                    log_level = match value.as_ref() {
-                      "trace" => log::Level::Trace,
-                      "debug" => log::Level::Debug,
-                      "info" => log::Level::Info,
-                      "warn" => log::Level::Warn,
-                      "error" => log::Level::Error,
-                      _ => panic!("Bad log level: {}", value),
-                    };
+                  if splitted.len() == 1 {
+                    module_namespace_mappings.insert("".to_owned(), splitted[0].to_owned());
                   }
                 }
-                _ => panic!("Bad XmlSchema attribute: {}", ident),
+                "offline" => match value.as_ref() {
+                  "true" => offline = true,
+                  "false" => offline = false,
+                  _ => errors.push(AttributeError::spanned(
+                    Error::new(format!("Bad offline value: {value}"))
+                      .with_hint("expected \"true\" or \"false\""),
+                    span,
+                  )),
+                },
+                "serialize_default_attributes" => match value.as_ref() {
+                  "true" => serialize_default_attributes = true,
+                  "false" => serialize_default_attributes = false,
+                  _ => errors.push(AttributeError::spanned(
+                    Error::new(format!("Bad serialize_default_attributes value: {value}"))
+                      .with_hint("expected \"true\" or \"false\""),
+                    span,
+                  )),
+                },
+                "source" => sources.push(value),
+                "store_generated_code" => store_generated_code = Some(value),
+                "target_prefix" => target_prefixes.push((sources.last().cloned(), value, span)),
+                "type_mapping" => match value.split_once(": ") {
+                  Some((xml_type, rust_type)) => match syn::parse_str::<Type>(rust_type) {
+                    Ok(_) => {
+                      type_mappings.insert(xml_type.to_owned(), rust_type.trim().to_owned());
+                    }
+                    Err(error) => errors.push(AttributeError::spanned(
+                      Error::new(format!(
+                        "Invalid Rust type {rust_type:?} in the type mapping of {xml_type:?}: {error}"
+                      ))
+                      .with_hint("expected a Rust type path, like `crate::module::Type`"),
+                      span,
+                    )),
+                  },
+                  None => errors.push(AttributeError::spanned(
+                    Error::new(format!("Bad type_mapping value: {value}"))
+                      .with_hint("expected \"prefix:name: rust::Type\""),
+                    span,
+                  )),
+                },
+                "log_level" => match value.as_ref() {
+                  "trace" => log_level = log::Level::Trace,
+                  "debug" => log_level = log::Level::Debug,
+                  "info" => log_level = log::Level::Info,
+                  "warn" => log_level = log::Level::Warn,
+                  "error" => log_level = log::Level::Error,
+                  _ => errors.push(AttributeError::spanned(
+                    Error::new(format!("Bad log level: {value}"))
+                      .with_hint("expected one of trace, debug, info, warn, error"),
+                    span,
+                  )),
+                },
+                _ => unreachable!(),
               }
             }
           }
@@ -87,16 +208,61 @@ impl XmlSchemaAttribute {
       }
     }
 
-    if source.is_none() {
-      panic!("Unable to expand schema, missing source paramater");
+    // with several sources, each target_prefix applies to the source preceding it
+    let mut target_prefix = None;
+    let mut prefixes_by_source = BTreeMap::new();
+    for (source, prefix, span) in target_prefixes {
+      match source {
+        _ if sources.len() <= 1 => target_prefix = Some(prefix),
+        Some(source) => {
+          prefixes_by_source.insert(source, prefix);
+        }
+        None => errors.push(AttributeError::spanned(
+          Error::new("The target_prefix applies to the source preceding it")
+            .with_hint("move the target_prefix after the source of its schema"),
+          span,
+        )),
+      }
     }
 
-    XmlSchemaAttribute {
-      log_level,
-      module_namespace_mappings,
-      source: source.unwrap(),
-      store_generated_code,
-      target_prefix,
+    let source = match (sources.is_empty(), inline) {
+      (false, Some(_)) => {
+        errors.push(
+          Error::new("Both source and inline schemas are set")
+            .with_hint("keep one of `source` and `inline`")
+            .into(),
+        );
+        None
+      }
+      (false, None) => Some(Source::Locations(sources)),
+      (true, Some(content)) => Some(Source::Inline(content)),
+      (true, None) => {
+        errors.push(
+          Error::new("Unable to expand schema, missing source parameter")
+            .with_hint(
+              "add `#[xml_schema(source = \"path/to/schema.xsd\")]`, or an `inline` schema",
+            )
+            .into(),
+        );
+        None
+      }
+    };
+
+    match source {
+      Some(source) if errors.is_empty() => Ok(XmlSchemaAttribute {
+        cache_directory,
+        catalog,
+        log_level,
+        module_namespace_mappings,
+        offline,
+        serialize_default_attributes,
+        source,
+        store_generated_code,
+        target_prefix,
+        target_prefixes: prefixes_by_source,
+        type_mappings,
+      }),
+      _ => Err(errors),
     }
   }
 }
@@ -137,10 +303,13 @@ mod tests {
   }
 
   #[test]
-  #[should_panic]
   fn parse_empty_attributes() {
     let attributes = vec![];
-    XmlSchemaAttribute::parse(&attributes);
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+    assert_eq!(
+      errors[0].error.message,
+      "Unable to expand schema, missing source parameter"
+    );
   }
 
   #[test]
@@ -148,13 +317,85 @@ mod tests {
     let attributes = generate_attributes(r#"(source = "schema.xsd")"#);
     assert_eq!(
       XmlSchemaAttribute {
+        cache_directory: None,
+        catalog: None,
         log_level: log::Level::Warn,
         module_namespace_mappings: BTreeMap::new(),
-        source: "schema.xsd".to_string(),
+        offline: false,
+        serialize_default_attributes: false,
+        source: Source::Locations(vec!["schema.xsd".to_string()]),
         store_generated_code: None,
         target_prefix: None,
+        target_prefixes: BTreeMap::new(),
+        type_mappings: BTreeMap::new(),
       },
-      XmlSchemaAttribute::parse(&attributes)
+      XmlSchemaAttribute::parse(&attributes).unwrap()
+    );
+  }
+
+  #[test]
+  fn parse_inline_attribute() {
+    let attributes = generate_attributes(
+      r###"(inline = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"/>"#)"###,
+    );
+    assert_eq!(
+      XmlSchemaAttribute::parse(&attributes).unwrap().source,
+      Source::Inline(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"/>"#.to_string())
+    );
+
+    let attributes = generate_attributes(r#"(source = "schema.xsd", inline = "<xs:schema/>")"#);
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+    assert_eq!(
+      errors[0].error.message,
+      "Both source and inline schemas are set"
+    );
+  }
+
+  #[test]
+  fn parse_many_sources() {
+    let attributes =
+      generate_attributes(r#"(source = "order.xsd", source = "customer.xsd", log_level = "info")"#);
+    assert_eq!(
+      XmlSchemaAttribute::parse(&attributes).unwrap().source,
+      Source::Locations(vec!["order.xsd".to_string(), "customer.xsd".to_string()])
+    );
+
+    let attributes = generate_attributes(
+      r#"(source = "order.xsd", target_prefix = "ord", source = "customer.xsd", target_prefix = "cus")"#,
+    );
+    let attributes = XmlSchemaAttribute::parse(&attributes).unwrap();
+    assert_eq!(attributes.target_prefix, None);
+    assert_eq!(
+      attributes.target_prefixes,
+      BTreeMap::from([
+        ("customer.xsd".to_string(), "cus".to_string()),
+        ("order.xsd".to_string(), "ord".to_string()),
+      ])
+    );
+
+    let attributes = generate_attributes(
+      r#"(target_prefix = "ord", source = "order.xsd", source = "customer.xsd")"#,
+    );
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+    assert_eq!(
+      errors[0].error.message,
+      "The target_prefix applies to the source preceding it"
+    );
+  }
+
+  #[test]
+  fn parse_escaped_value() {
+    let attributes = generate_attributes(r#"(source = "schemas/\"quoted\".xsd")"#);
+    assert_eq!(
+      XmlSchemaAttribute::parse(&attributes).unwrap().source,
+      Source::Locations(vec![r#"schemas/"quoted".xsd"#.to_string()])
+    );
+
+    let attributes = generate_attributes(r#"(source = 12)"#);
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+    assert_eq!(
+      errors[0].error.message,
+      "Bad value for source, a string is expected"
     );
   }
 
@@ -165,13 +406,19 @@ mod tests {
     );
     assert_eq!(
       XmlSchemaAttribute {
+        cache_directory: None,
+        catalog: None,
         log_level: log::Level::Debug,
         module_namespace_mappings: BTreeMap::new(),
-        source: "schema.xsd".to_string(),
+        offline: false,
+        serialize_default_attributes: false,
+        source: Source::Locations(vec!["schema.xsd".to_string()]),
         store_generated_code: Some("sample.rs".to_string()),
         target_prefix: Some("prefix".to_string()),
+        target_prefixes: BTreeMap::new(),
+        type_mappings: BTreeMap::new(),
       },
-      XmlSchemaAttribute::parse(&attributes)
+      XmlSchemaAttribute::parse(&attributes).unwrap()
     );
   }
 
@@ -180,37 +427,55 @@ mod tests {
     let attributes = generate_attributes(r#"(source = "schema.xsd", log_level="info")"#);
     assert_eq!(
       XmlSchemaAttribute {
+        cache_directory: None,
+        catalog: None,
         log_level: log::Level::Info,
         module_namespace_mappings: BTreeMap::new(),
-        source: "schema.xsd".to_string(),
+        offline: false,
+        serialize_default_attributes: false,
+        source: Source::Locations(vec!["schema.xsd".to_string()]),
         store_generated_code: None,
         target_prefix: None,
+        target_prefixes: BTreeMap::new(),
+        type_mappings: BTreeMap::new(),
       },
-      XmlSchemaAttribute::parse(&attributes)
+      XmlSchemaAttribute::parse(&attributes).unwrap()
     );
 
     let attributes = generate_attributes(r#"(source = "schema.xsd", log_level="warn")"#);
     assert_eq!(
       XmlSchemaAttribute {
+        cache_directory: None,
+        catalog: None,
         log_level: log::Level::Warn,
         module_namespace_mappings: BTreeMap::new(),
-        source: "schema.xsd".to_string(),
+        offline: false,
+        serialize_default_attributes: false,
+        source: Source::Locations(vec!["schema.xsd".to_string()]),
         store_generated_code: None,
         target_prefix: None,
+        target_prefixes: BTreeMap::new(),
+        type_mappings: BTreeMap::new(),
       },
-      XmlSchemaAttribute::parse(&attributes)
+      XmlSchemaAttribute::parse(&attributes).unwrap()
     );
 
     let attributes = generate_attributes(r#"(source = "schema.xsd", log_level="error")"#);
     assert_eq!(
       XmlSchemaAttribute {
+        cache_directory: None,
+        catalog: None,
         log_level: log::Level::Error,
         module_namespace_mappings: BTreeMap::new(),
-        source: "schema.xsd".to_string(),
+        offline: false,
+        serialize_default_attributes: false,
+        source: Source::Locations(vec!["schema.xsd".to_string()]),
         store_generated_code: None,
         target_prefix: None,
+        target_prefixes: BTreeMap::new(),
+        type_mappings: BTreeMap::new(),
       },
-      XmlSchemaAttribute::parse(&attributes)
+      XmlSchemaAttribute::parse(&attributes).unwrap()
     );
   }
 
@@ -232,42 +497,130 @@ mod tests {
 
     assert_eq!(
       XmlSchemaAttribute {
+        cache_directory: None,
+        catalog: None,
         log_level: log::Level::Warn,
         module_namespace_mappings: mapping,
-        source: "schema.xsd".to_string(),
+        offline: false,
+        serialize_default_attributes: false,
+        source: Source::Locations(vec!["schema.xsd".to_string()]),
         store_generated_code: None,
         target_prefix: None,
+        target_prefixes: BTreeMap::new(),
+        type_mappings: BTreeMap::new(),
       },
+      XmlSchemaAttribute::parse(&attributes).unwrap()
+    );
+  }
+
+  #[test]
+  fn parse_serialize_default_attributes() {
+    let attributes =
+      generate_attributes(r#"(source = "schema.xsd", serialize_default_attributes = "true")"#);
+    assert!(
+      XmlSchemaAttribute::parse(&attributes)
+        .unwrap()
+        .serialize_default_attributes
+    );
+  }
+
+  #[test]
+  fn parse_loading_attributes() {
+    let attributes = generate_attributes(
+      r#"(source = "http://example.com/schema.xsd", catalog = "catalog.xml", cache_directory = "schemas", offline = "true")"#,
+    );
+    let attributes = XmlSchemaAttribute::parse(&attributes).unwrap();
+
+    assert_eq!(attributes.catalog, Some("catalog.xml".to_string()));
+    assert_eq!(attributes.cache_directory, Some("schemas".to_string()));
+    assert!(attributes.offline);
+
+    let attributes = generate_attributes(r#"(source = "schema.xsd", offline = "yes")"#);
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+    assert_eq!(errors[0].error.message, "Bad offline value: yes");
+  }
+
+  #[test]
+  fn parse_type_mapping() {
+    let attributes = generate_attributes(
+      r#"(
+      source = "schema.xsd",
+      type_mapping = "xs:dateTime: chrono::DateTime<chrono::Utc>",
+      type_mapping = "tns:Timecode: crate::Timecode"
+    )"#,
+    );
+    let mut type_mappings = BTreeMap::new();
+    type_mappings.insert(
+      "xs:dateTime".to_string(),
+      "chrono::DateTime<chrono::Utc>".to_string(),
+    );
+    type_mappings.insert("tns:Timecode".to_string(), "crate::Timecode".to_string());
+
+    assert_eq!(
       XmlSchemaAttribute::parse(&attributes)
+        .unwrap()
+        .type_mappings,
+      type_mappings
     );
   }
 
   #[test]
-  #[should_panic]
+  fn parse_bad_type_mapping() {
+    let attributes =
+      generate_attributes(r#"(source = "schema.xsd", type_mapping = "xs:dateTime")"#);
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+    assert_eq!(
+      errors[0].error.message,
+      "Bad type_mapping value: xs:dateTime"
+    );
+    assert!(errors[0].span.is_some());
+
+    let attributes =
+      generate_attributes(r#"(source = "schema.xsd", type_mapping = "xs:dateTime: not a type")"#);
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+    assert!(errors[0]
+      .error
+      .message
+      .starts_with(r#"Invalid Rust type "not a type" in the type mapping of "xs:dateTime""#));
+    assert!(errors[0].span.is_some());
+  }
+
+  #[test]
   fn parse_bad_log_level() {
     let attributes = generate_attributes(r#"(source = "schema.xsd", log_level="quiet")"#);
 
-    XmlSchemaAttribute::parse(&attributes);
+    assert!(XmlSchemaAttribute::parse(&attributes).is_err());
   }
 
   #[test]
-  #[should_panic]
   fn parse_bad_attribute() {
     let attributes = generate_attributes(r#"(source = "schema.xsd", bad-key="bad_value")"#);
-    XmlSchemaAttribute::parse(&attributes);
+    assert!(XmlSchemaAttribute::parse(&attributes).is_err());
   }
 
   #[test]
-  #[should_panic]
   fn parse_missing_value() {
     let attributes = generate_attributes(r#"(source = "schema.xsd", log_level)"#);
-    XmlSchemaAttribute::parse(&attributes);
+    assert!(XmlSchemaAttribute::parse(&attributes).is_err());
   }
 
   #[test]
-  #[should_panic]
   fn parse_bad_association() {
     let attributes = generate_attributes(r#"(source = "schema.xsd", log_level + "debug")"#);
-    XmlSchemaAttribute::parse(&attributes);
+    assert!(XmlSchemaAttribute::parse(&attributes).is_err());
+  }
+
+  #[test]
+  fn parse_many_errors() {
+    let attributes =
+      generate_attributes(r#"(log_level = "quiet", serialize_default_attributes = "yes")"#);
+    let errors = XmlSchemaAttribute::parse(&attributes).unwrap_err();
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].error.message, "Bad log level: quiet");
+    assert_eq!(
+      errors[0].error.hint,
+      Some("expected one of trace, debug, info, warn, error".to_string())
+    );
   }
 }
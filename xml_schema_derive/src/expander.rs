@@ -1,25 +1,153 @@
-use crate::attribute::XmlSchemaAttribute;
-use crate::xsd::Xsd;
+use crate::attribute::{AttributeError, Source, XmlSchemaAttribute};
 use log::info;
 use proc_macro2::TokenStream;
+use std::path::Path;
+use xml_schema_generator::errors::{self, Error};
+use xml_schema_generator::loader::is_remote;
+use xml_schema_generator::{Loader, Xsd, XsdSet};
 
-pub fn expand_derive(ast: &syn::DeriveInput) -> Result<TokenStream, String> {
-  let attributes = XmlSchemaAttribute::parse(&ast.attrs);
+pub fn expand_derive(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
+  let attributes =
+    XmlSchemaAttribute::parse(&ast.attrs).map_err(|errors| compile_errors(ast, errors))?;
   let _ = simple_logger::init_with_level(attributes.log_level);
 
   info!("{:?}", attributes);
 
-  let xsd = Xsd::new_from_file(
-    ast.ident.to_string(),
-    ast.vis.clone(),
-    &attributes.source,
-    &attributes.module_namespace_mappings,
-  )?;
-  let generated = xsd.implement(&attributes.target_prefix);
+  let catalog = attributes.catalog.as_deref().map(in_manifest_directory);
+
+  let mut loader = Loader::new().with_offline(attributes.offline);
+  if let Some(catalog) = &catalog {
+    loader = loader
+      .with_catalog(catalog)
+      .map_err(|error| compile_errors(ast, vec![Error::new(error)]))?;
+  }
+  if let Some(cache_directory) = &attributes.cache_directory {
+    loader = loader.with_cache_directory(&in_manifest_directory(cache_directory));
+  }
+
+  errors::take();
+  let (generated, sources) = match &attributes.source {
+    Source::Locations(sources) if sources.len() > 1 => {
+      let sources: Vec<String> = sources
+        .iter()
+        .map(|source| in_manifest_directory(source))
+        .collect();
+      let target_prefixes = attributes
+        .target_prefixes
+        .iter()
+        .map(|(source, target_prefix)| (in_manifest_directory(source), target_prefix.clone()))
+        .collect();
+
+      let xsd_set = XsdSet::new_from_files(
+        ast.ident.to_string(),
+        ast.vis.clone(),
+        &sources,
+        &target_prefixes,
+        &attributes.module_namespace_mappings,
+        &loader,
+      )
+      .map_err(|error| compile_errors(ast, vec![Error::new(error)]))?
+      .with_serialize_default_attributes(attributes.serialize_default_attributes)
+      .with_type_mappings(&attributes.type_mappings)
+      .map_err(|error| compile_errors(ast, vec![error]))?;
+
+      (xsd_set.implement(), xsd_set.sources().clone())
+    }
+    source => {
+      let xsd = match source {
+        Source::Locations(sources) => Xsd::new_from_file(
+          ast.ident.to_string(),
+          ast.vis.clone(),
+          &in_manifest_directory(&sources[0]),
+          &attributes.module_namespace_mappings,
+          &loader,
+        ),
+        Source::Inline(content) => Xsd::new_inline(
+          ast.ident.to_string(),
+          ast.vis.clone(),
+          content,
+          &format!("<inline schema of {}>", ast.ident),
+          &in_manifest_directory(""),
+          &attributes.module_namespace_mappings,
+          &loader,
+        ),
+      }
+      .map_err(|error| compile_errors(ast, vec![Error::new(error)]))?
+      .with_serialize_default_attributes(attributes.serialize_default_attributes)
+      .with_type_mappings(&attributes.type_mappings)
+      .map_err(|error| compile_errors(ast, vec![error]))?;
+
+      (
+        xsd.implement(&attributes.target_prefix),
+        xsd.sources().clone(),
+      )
+    }
+  };
+
+  let errors = errors::take();
+  if !errors.is_empty() {
+    return Err(compile_errors(ast, errors));
+  }
 
   if let Some(store_generated_code) = &attributes.store_generated_code {
-    std::fs::write(store_generated_code, generated.to_string()).map_err(|e| e.to_string())?;
+    std::fs::write(
+      in_manifest_directory(store_generated_code),
+      generated.to_string(),
+    )
+    .map_err(|e| compile_errors(ast, vec![Error::new(e.to_string())]))?;
+  }
+
+  // include the local schemas, for the crate to be compiled again when they change
+  let dependencies: TokenStream = sources
+    .iter()
+    .chain(&catalog)
+    .filter(|source| !is_remote(source))
+    .map(|source| {
+      quote!(
+        const _: &[u8] = include_bytes!(#source);
+      )
+    })
+    .collect();
+
+  Ok(quote! {
+    #generated
+    #dependencies
+  })
+}
+
+/// Path relative to the manifest directory of the crate deriving the schema, like `include_bytes!`
+/// ones are relative to the source file.
+fn in_manifest_directory(path: &str) -> String {
+  match std::env::var_os("CARGO_MANIFEST_DIR") {
+    Some(manifest_directory) if !is_remote(path) => Path::new(&manifest_directory)
+      .join(path)
+      .to_string_lossy()
+      .to_string(),
+    _ => path.to_string(),
   }
+}
+
+/// Errors reported on their tokens in the `xml_schema` attribute, on the whole attribute, or on
+/// the derived type without it.
+fn compile_errors<E: Into<AttributeError>>(ast: &syn::DeriveInput, errors: Vec<E>) -> syn::Error {
+  let attribute = ast
+    .attrs
+    .iter()
+    .find(|attr| attr.path.is_ident("xml_schema"));
 
-  Ok(generated)
+  errors
+    .into_iter()
+    .map(|error| {
+      let AttributeError { error, span } = error.into();
+      match (span, attribute) {
+        (Some(span), _) => syn::Error::new(span, error),
+        (None, Some(attribute)) => syn::Error::new_spanned(attribute, error),
+        (None, None) => syn::Error::new_spanned(&ast.ident, error),
+      }
+    })
+    .reduce(|mut errors, error| {
+      errors.combine(error);
+      errors
+    })
+    .unwrap_or_else(|| syn::Error::new_spanned(&ast.ident, "Unable to expand schema"))
 }
@@ -0,0 +1,334 @@
+use crate::errors::{self, Error};
+use crate::loader::{is_remote, Loader};
+use crate::xsd::{get_module_name, Xsd};
+use proc_macro2::TokenStream;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// Generate the types of XML Schemas from a build script, in a file to include in the crate.
+///
+/// Each source is generated in a public module named after its file name, with the prefix given
+/// to it when it declares a target namespace.
+///
+/// ```no_run
+/// let output = xml_schema_generator::Builder::new()
+///   .with_source("schemas/library.xsd")
+///   .with_target_prefix("lib")
+///   .write()
+///   .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+  sources: Vec<Source>,
+  module_namespace_mappings: BTreeMap<String, String>,
+  type_mappings: BTreeMap<String, String>,
+  serialize_default_attributes: bool,
+  catalog: Option<String>,
+  cache_directory: Option<String>,
+  offline: bool,
+  output: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug)]
+struct Source {
+  location: String,
+  target_prefix: Option<String>,
+}
+
+impl Builder {
+  pub fn new() -> Self {
+    Builder::default()
+  }
+
+  /// Add a schema to generate, a local file or an HTTP resource.
+  pub fn with_source(mut self, source: &str) -> Self {
+    self.sources.push(Source {
+      location: source.to_string(),
+      target_prefix: None,
+    });
+    self
+  }
+
+  /// Prefix of the target namespace of the last added source.
+  pub fn with_target_prefix(mut self, target_prefix: &str) -> Self {
+    if let Some(source) = self.sources.last_mut() {
+      source.target_prefix = Some(target_prefix.to_string());
+    }
+    self
+  }
+
+  /// Use a Rust module for a namespace instead of loading its schema.
+  pub fn with_module_namespace_mapping(mut self, namespace: &str, module: &str) -> Self {
+    self
+      .module_namespace_mappings
+      .insert(namespace.to_string(), module.to_string());
+    self
+  }
+
+  /// Substitute a Rust type to an XML Schema type, named with the prefixes of the schema.
+  pub fn with_type_mapping(mut self, xml_type: &str, rust_type: &str) -> Self {
+    self
+      .type_mappings
+      .insert(xml_type.to_string(), rust_type.to_string());
+    self
+  }
+
+  pub fn with_serialize_default_attributes(mut self, serialize_default_attributes: bool) -> Self {
+    self.serialize_default_attributes = serialize_default_attributes;
+    self
+  }
+
+  /// Map the remote schemas to local files with an OASIS XML Catalog.
+  pub fn with_catalog(mut self, catalog: &str) -> Self {
+    self.catalog = Some(catalog.to_string());
+    self
+  }
+
+  /// Store the remote schemas in a directory, to load them from it afterwards.
+  pub fn with_cache_directory(mut self, cache_directory: &str) -> Self {
+    self.cache_directory = Some(cache_directory.to_string());
+    self
+  }
+
+  /// Fail on the remote schemas neither mapped by the catalog nor cached.
+  pub fn with_offline(mut self, offline: bool) -> Self {
+    self.offline = offline;
+    self
+  }
+
+  /// File to write the generated code to, relative to `OUT_DIR` (`xml_schema.rs` by default).
+  pub fn with_output(mut self, output: &str) -> Self {
+    self.output = Some(PathBuf::from(output));
+    self
+  }
+
+  /// Generated code of all the sources, formatted.
+  pub fn generate(&self) -> Result<String, Vec<Error>> {
+    let schemas = self.load()?;
+    self.format(&schemas)
+  }
+
+  /// Generated code of each source, formatted, by module name.
+  pub fn generate_modules(&self) -> Result<BTreeMap<String, String>, Vec<Error>> {
+    let schemas = self.load()?;
+
+    let modules: Vec<(String, TokenStream)> = self
+      .sources
+      .iter()
+      .zip(schemas.iter())
+      .map(|(source, (xsd, target_prefix))| {
+        (
+          get_module_name(&source.location),
+          xsd.implement_schema(target_prefix),
+        )
+      })
+      .collect();
+
+    modules
+      .into_iter()
+      .map(|(module_name, generated)| Ok((module_name, unparse(generated)?)))
+      .collect()
+  }
+
+  /// Write the generated code, and ask cargo to run the build script again when a local schema
+  /// changes. Returns the path of the written file.
+  pub fn write(&self) -> Result<PathBuf, Vec<Error>> {
+    let output = self.output_path()?;
+    let schemas = self.load()?;
+    let code = self.format(&schemas)?;
+
+    fs::write(&output, code)
+      .map_err(|e| vec![Error::new(format!("{}: {}", output.display(), e))])?;
+
+    let sources: BTreeSet<&String> = schemas
+      .iter()
+      .flat_map(|(xsd, _)| xsd.sources())
+      .chain(&self.catalog)
+      .collect();
+    for source in sources {
+      if !is_remote(source) {
+        println!("cargo:rerun-if-changed={source}");
+      }
+    }
+
+    Ok(output)
+  }
+
+  fn load(&self) -> Result<Vec<(Xsd, &Option<String>)>, Vec<Error>> {
+    if self.sources.is_empty() {
+      return Err(vec![
+        Error::new("No schema to generate").with_hint("add a source with `with_source`")
+      ]);
+    }
+
+    let loader = self.loader().map_err(|error| vec![error])?;
+
+    errors::take();
+    let mut module_names = BTreeSet::new();
+    let mut schemas = vec![];
+
+    for Source {
+      location: source,
+      target_prefix,
+    } in &self.sources
+    {
+      let module_name = get_module_name(source);
+      if !module_names.insert(module_name.clone()) {
+        return Err(vec![Error::new(format!(
+          "{source}: module {module_name} is already generated for another source"
+        ))
+        .with_hint("rename one of the schema files")]);
+      }
+
+      let xsd = Xsd::new_from_file(
+        module_name,
+        syn::parse_quote!(pub),
+        source,
+        &self.module_namespace_mappings,
+        &loader,
+      )
+      .map_err(|error| vec![Error::new(error)])?
+      .with_serialize_default_attributes(self.serialize_default_attributes)
+      .with_type_mappings(&self.type_mappings)
+      .map_err(|error| vec![error])?;
+
+      schemas.push((xsd, target_prefix));
+    }
+
+    Ok(schemas)
+  }
+
+  fn loader(&self) -> Result<Loader, Error> {
+    let mut loader = Loader::new().with_offline(self.offline);
+
+    if let Some(catalog) = &self.catalog {
+      loader = loader.with_catalog(catalog)?;
+    }
+    if let Some(cache_directory) = &self.cache_directory {
+      loader = loader.with_cache_directory(cache_directory);
+    }
+
+    Ok(loader)
+  }
+
+  fn format(&self, schemas: &[(Xsd, &Option<String>)]) -> Result<String, Vec<Error>> {
+    let generated: TokenStream = schemas
+      .iter()
+      .map(|(xsd, target_prefix)| xsd.implement_module(target_prefix))
+      .collect();
+
+    unparse(generated)
+  }
+
+  fn output_path(&self) -> Result<PathBuf, Vec<Error>> {
+    let output = self
+      .output
+      .clone()
+      .unwrap_or_else(|| PathBuf::from("xml_schema.rs"));
+
+    if output.is_absolute() {
+      return Ok(output);
+    }
+
+    let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+      vec![Error::new("OUT_DIR is not set")
+        .with_hint("run the builder from a build script, or set an absolute output path")]
+    })?;
+
+    Ok(PathBuf::from(out_dir).join(output))
+  }
+}
+
+/// Format the generated code, once all the errors are reported.
+fn unparse(generated: TokenStream) -> Result<String, Vec<Error>> {
+  let errors = errors::take();
+  if !errors.is_empty() {
+    return Err(errors);
+  }
+
+  let file = syn::parse2::<syn::File>(generated)
+    .map_err(|e| vec![Error::new(format!("Invalid generated code: {e}"))])?;
+
+  Ok(prettyplease::unparse(&file))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn generate_modules() {
+    let code = Builder::new()
+      .with_source("../xml_schema/tests/simple_type_string.xsd")
+      .with_target_prefix("st")
+      .with_source("../xml_schema/tests/complex_type.xsd")
+      .generate()
+      .unwrap();
+
+    assert!(code.contains("pub mod simple_type_string {"));
+    assert!(code.contains("pub mod complex_type {"));
+    assert!(code.contains("pub mod types {"));
+  }
+
+  #[test]
+  fn generate_module_files() {
+    let modules = Builder::new()
+      .with_source("../xml_schema/tests/complex_type.xsd")
+      .with_source("../xml_schema/tests/import.xsd")
+      .generate_modules()
+      .unwrap();
+
+    assert_eq!(
+      modules.keys().collect::<Vec<_>>(),
+      vec!["complex_type", "import"]
+    );
+    assert!(modules["complex_type"].starts_with("pub mod types {"));
+    assert!(modules["import"].contains("pub mod import_common {"));
+  }
+
+  #[test]
+  fn write_output() {
+    let output = std::env::temp_dir().join("xml_schema_generator_write_output.rs");
+
+    let written = Builder::new()
+      .with_source("../xml_schema/tests/simple_type_list.xsd")
+      .with_output(output.to_str().unwrap())
+      .write()
+      .unwrap();
+
+    assert_eq!(written, output);
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(code.contains("pub mod simple_type_list {"));
+    fs::remove_file(output).unwrap();
+  }
+
+  #[test]
+  fn missing_source() {
+    let errors = Builder::new().generate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "No schema to generate");
+
+    let errors = Builder::new()
+      .with_source("../xml_schema/tests/missing.xsd")
+      .generate()
+      .unwrap_err();
+    assert!(errors[0]
+      .message
+      .starts_with("../xml_schema/tests/missing.xsd: "));
+  }
+
+  #[test]
+  fn duplicated_module() {
+    let errors = Builder::new()
+      .with_source("../xml_schema/tests/complex_type.xsd")
+      .with_source("../xml_schema/tests/../tests/complex_type.xsd")
+      .generate()
+      .unwrap_err();
+
+    assert_eq!(
+      errors[0].message,
+      "../xml_schema/tests/../tests/complex_type.xsd: module complex_type is already generated for another source"
+    );
+  }
+}
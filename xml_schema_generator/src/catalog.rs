@@ -0,0 +1,164 @@
+//! OASIS XML Catalog, mapping the remote schemas to local files.
+//!
+//! The `uri` and `system` entries map a location (or the namespace of an `xs:import` without
+//! `schemaLocation`), the `rewriteURI` and `rewriteSystem` entries map the locations starting with
+//! a prefix. Relative files are resolved against the catalog file.
+
+use crate::xsd::resolve_location;
+use std::fs;
+use std::io::Cursor;
+use xml::reader::{EventReader, XmlEvent};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Catalog {
+  entries: Vec<Entry>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Entry {
+  Exact {
+    name: String,
+    uri: String,
+  },
+  Rewrite {
+    prefix: String,
+    rewrite_prefix: String,
+  },
+}
+
+impl Catalog {
+  pub fn from_file(path: &str) -> Result<Self, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    Catalog::parse(&content, path).map_err(|e| format!("{path}: {e}"))
+  }
+
+  /// Parse a catalog, its relative files being resolved against `base`.
+  pub fn parse(content: &str, base: &str) -> Result<Self, String> {
+    let mut parser = EventReader::new(Cursor::new(content));
+    let mut entries = vec![];
+
+    loop {
+      match parser.next().map_err(|e| e.to_string())? {
+        XmlEvent::StartElement {
+          name, attributes, ..
+        } => {
+          let get_attribute = |attribute_name: &str| {
+            attributes
+              .iter()
+              .find(|attribute| attribute.name.local_name == attribute_name)
+              .map(|attribute| attribute.value.clone())
+          };
+
+          let (key, value) = match name.local_name.as_str() {
+            "uri" => ("name", "uri"),
+            "system" => ("systemId", "uri"),
+            "rewriteURI" => ("uriStartString", "rewritePrefix"),
+            "rewriteSystem" => ("systemIdStartString", "rewritePrefix"),
+            _ => continue,
+          };
+
+          let (key, value) = match (get_attribute(key), get_attribute(value)) {
+            (Some(key), Some(value)) => (key, resolve_location(base, &value)),
+            _ => {
+              return Err(format!(
+                "{} entry without {} and {} attributes",
+                name.local_name, key, value
+              ))
+            }
+          };
+
+          entries.push(if name.local_name.starts_with("rewrite") {
+            Entry::Rewrite {
+              prefix: key,
+              rewrite_prefix: value,
+            }
+          } else {
+            Entry::Exact {
+              name: key,
+              uri: value,
+            }
+          });
+        }
+        XmlEvent::EndDocument => break,
+        _ => {}
+      }
+    }
+
+    Ok(Catalog { entries })
+  }
+
+  /// Local file of a location, an exact entry taking precedence over the longest rewritten prefix.
+  pub fn resolve(&self, location: &str) -> Option<String> {
+    let exact = self.entries.iter().find_map(|entry| match entry {
+      Entry::Exact { name, uri } if name == location => Some(uri.clone()),
+      _ => None,
+    });
+
+    exact.or_else(|| {
+      self
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+          Entry::Rewrite {
+            prefix,
+            rewrite_prefix,
+          } if location.starts_with(prefix.as_str()) => Some((prefix, rewrite_prefix)),
+          _ => None,
+        })
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(prefix, rewrite_prefix)| format!("{}{}", rewrite_prefix, &location[prefix.len()..]))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn resolve_entries() {
+    let catalog = Catalog::parse(
+      r#"<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+        <uri name="http://example.com/common" uri="common.xsd"/>
+        <system systemId="http://example.com/main.xsd" uri="/schemas/main.xsd"/>
+        <group>
+          <rewriteURI uriStartString="http://example.com/" rewritePrefix="remote/"/>
+          <rewriteSystem systemIdStartString="http://example.com/v2/" rewritePrefix="v2/"/>
+        </group>
+      </catalog>"#,
+      "schemas/catalog.xml",
+    )
+    .unwrap();
+
+    assert_eq!(
+      catalog.resolve("http://example.com/common"),
+      Some("schemas/common.xsd".to_string())
+    );
+    assert_eq!(
+      catalog.resolve("http://example.com/main.xsd"),
+      Some("/schemas/main.xsd".to_string())
+    );
+    assert_eq!(
+      catalog.resolve("http://example.com/types/base.xsd"),
+      Some("schemas/remote/types/base.xsd".to_string())
+    );
+    assert_eq!(
+      catalog.resolve("http://example.com/v2/base.xsd"),
+      Some("schemas/v2/base.xsd".to_string())
+    );
+    assert_eq!(catalog.resolve("http://example.org/base.xsd"), None);
+  }
+
+  #[test]
+  fn invalid_entry() {
+    let error = Catalog::parse(
+      r#"<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+        <uri name="http://example.com/common"/>
+      </catalog>"#,
+      "catalog.xml",
+    )
+    .unwrap_err();
+
+    assert_eq!(error, "uri entry without name and uri attributes");
+  }
+}
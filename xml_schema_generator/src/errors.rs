@@ -0,0 +1,125 @@
+//! Errors found while generating the code, reported as compilation errors once the whole
+//! schema has been generated.
+
+use std::cell::RefCell;
+use std::fmt::{self, Display};
+
+thread_local! {
+  static ERRORS: RefCell<Vec<Error>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Error of a schema or of the `xml_schema` attribute, with the schema node it comes from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Error {
+  pub message: String,
+  pub location: Option<Location>,
+  /// How to fix the error.
+  pub hint: Option<String>,
+}
+
+/// Position of a node in a schema file, line and column starting at 1.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Location {
+  pub file: String,
+  pub position: Option<(u64, u64)>,
+}
+
+impl Error {
+  pub fn new(message: impl Into<String>) -> Self {
+    Error {
+      message: message.into(),
+      ..Default::default()
+    }
+  }
+
+  pub fn at(mut self, location: Option<Location>) -> Self {
+    self.location = location;
+    self
+  }
+
+  pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+    self.hint = Some(hint.into());
+    self
+  }
+}
+
+impl From<String> for Error {
+  fn from(message: String) -> Self {
+    Error::new(message)
+  }
+}
+
+impl Display for Error {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "{}", self.message)?;
+
+    if let Some(location) = &self.location {
+      write!(formatter, "\n --> {location}")?;
+    }
+    if let Some(hint) = &self.hint {
+      write!(formatter, "\n  = hint: {hint}")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Display for Location {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    match self.position {
+      Some((line, column)) => write!(formatter, "{}:{}:{}", self.file, line, column),
+      None => write!(formatter, "{}", self.file),
+    }
+  }
+}
+
+/// Report an error, the same error found many times being reported once.
+pub fn report(error: impl Into<Error>) {
+  let error = error.into();
+
+  ERRORS.with(|errors| {
+    let mut errors = errors.borrow_mut();
+    if !errors.contains(&error) {
+      errors.push(error);
+    }
+  });
+}
+
+/// Errors reported since the last call.
+pub fn take() -> Vec<Error> {
+  ERRORS.with(|errors| errors.borrow_mut().drain(..).collect())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    let error = Error::new("Unknown group \"Content\"")
+      .at(Some(Location {
+        file: "schema.xsd".to_string(),
+        position: Some((12, 5)),
+      }))
+      .with_hint("declare the group with `xs:group name=\"Content\"`");
+
+    assert_eq!(
+      error.to_string(),
+      "Unknown group \"Content\"\n --> schema.xsd:12:5\n  = hint: declare the group with `xs:group name=\"Content\"`"
+    );
+    assert_eq!(Error::new("Bad schema").to_string(), "Bad schema");
+  }
+
+  #[test]
+  fn accumulate() {
+    take();
+    report("first".to_string());
+    report(Error::new("second"));
+    report(Error::new("second"));
+
+    let errors = take();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[1].message, "second");
+    assert!(take().is_empty());
+  }
+}
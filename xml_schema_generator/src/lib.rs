@@ -0,0 +1,17 @@
+//! Generate the Rust types of XSD - XML Schema - files, used by `xml-schema-derive` and from
+//! build scripts with the [`Builder`].
+
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate yaserde_derive;
+
+mod builder;
+pub mod catalog;
+pub mod errors;
+pub mod loader;
+mod xsd;
+
+pub use builder::Builder;
+pub use loader::Loader;
+pub use xsd::{Xsd, XsdSet};
@@ -0,0 +1,245 @@
+//! Load the schema sources, local files or HTTP resources, mapped by an XML catalog and stored in
+//! a cache directory to build without network access.
+
+use crate::catalog::Catalog;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default)]
+pub struct Loader {
+  catalog: Catalog,
+  cache_directory: Option<PathBuf>,
+  offline: bool,
+}
+
+impl Loader {
+  pub fn new() -> Self {
+    Loader::default()
+  }
+
+  /// Map the locations with the OASIS XML Catalog file at `path`.
+  pub fn with_catalog(mut self, path: &str) -> Result<Self, String> {
+    self.catalog = Catalog::from_file(path)?;
+    Ok(self)
+  }
+
+  /// Directory where the HTTP resources are stored, under their scheme, host and path.
+  pub fn with_cache_directory(mut self, cache_directory: &str) -> Self {
+    self.cache_directory = Some(PathBuf::from(cache_directory));
+    self
+  }
+
+  /// Fail instead of fetching an HTTP resource which is neither mapped by the catalog nor cached.
+  pub fn with_offline(mut self, offline: bool) -> Self {
+    self.offline = offline;
+    self
+  }
+
+  /// Location of a source after the mapping of the catalog, canonicalized when it is a local file
+  /// to load it once whatever the path naming it.
+  pub fn resolve(&self, location: &str) -> String {
+    let location = self
+      .catalog
+      .resolve(location)
+      .unwrap_or_else(|| location.to_string());
+
+    if is_remote(&location) {
+      return location;
+    }
+
+    fs::canonicalize(&location)
+      .map(|path| path.to_string_lossy().to_string())
+      .unwrap_or(location)
+  }
+
+  /// Location of the schema of a namespace imported without `schemaLocation`, from the catalog.
+  pub fn resolve_namespace(&self, namespace: &str) -> Option<String> {
+    self.catalog.resolve(namespace)
+  }
+
+  /// Content of a source, resolved with [`Loader::resolve`] beforehand.
+  pub fn load(&self, source: &str) -> Result<String, String> {
+    let content = if is_remote(source) {
+      self.load_remote(source)?
+    } else {
+      log::info!("Load schema {}", source);
+      fs::read_to_string(source).map_err(|e| format!("{source}: {e}"))?
+    };
+
+    // skip BOM header, can be present on some files
+    let content = if content.as_bytes().starts_with(&[0xef, 0xbb, 0xbf]) {
+      content[3..].to_owned()
+    } else {
+      content
+    };
+
+    Ok(content)
+  }
+
+  fn load_remote(&self, source: &str) -> Result<String, String> {
+    let cache_path = self
+      .cache_directory
+      .as_ref()
+      .map(|cache_directory| cache_directory.join(cache_key(source)));
+
+    if let Some(cache_path) = &cache_path {
+      if cache_path.is_file() {
+        log::info!(
+          "Load cached schema {} from {}",
+          source,
+          cache_path.display()
+        );
+        return fs::read_to_string(cache_path)
+          .map_err(|e| format!("{}: {}", cache_path.display(), e));
+      }
+    }
+
+    let store_hint = match &cache_path {
+      Some(cache_path) => format!("store it as {}", cache_path.display()),
+      None => "set a cache directory".to_string(),
+    };
+
+    if self.offline {
+      return Err(format!(
+        "{source}: network access is disabled, map it in a catalog or {store_hint}"
+      ));
+    }
+
+    let content =
+      fetch(source).map_err(|e| format!("{source}: {e}, map it in a catalog or {store_hint}"))?;
+
+    if let Some(cache_path) = &cache_path {
+      store(cache_path, &content).map_err(|e| format!("{}: {}", cache_path.display(), e))?;
+    }
+
+    Ok(content)
+  }
+}
+
+#[cfg(feature = "http")]
+fn fetch(source: &str) -> Result<String, String> {
+  log::info!("Load HTTP schema {}", source);
+  reqwest::blocking::get(source)
+    .and_then(|response| response.error_for_status())
+    .and_then(|response| response.text())
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "http"))]
+fn fetch(_source: &str) -> Result<String, String> {
+  Err("HTTP resources need the `http` feature".to_string())
+}
+
+fn store(path: &Path, content: &str) -> std::io::Result<()> {
+  if let Some(directory) = path.parent() {
+    fs::create_dir_all(directory)?;
+  }
+  fs::write(path, content)
+}
+
+/// Whether a location is an HTTP resource.
+pub fn is_remote(location: &str) -> bool {
+  location.starts_with("http://") || location.starts_with("https://")
+}
+
+/// Relative path of an URL in the cache directory, like `http/example.com/schemas/main.xsd`.
+///
+/// The characters out of the portable file names are percent-encoded, as the `.` and `..`
+/// segments and the empty ones, so that two URLs never share a path.
+fn cache_key(url: &str) -> PathBuf {
+  let (scheme, url) = url.split_once("://").unwrap_or(("", url));
+
+  std::iter::once(scheme.to_string())
+    .chain(url.split('/').map(|segment| {
+      match segment {
+        "" => "%".to_string(),
+        "." => "%2E".to_string(),
+        ".." => "%2E%2E".to_string(),
+        _ => segment
+          .chars()
+          .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => c.to_string(),
+            _ => {
+              let mut bytes = [0; 4];
+              c.encode_utf8(&mut bytes)
+                .bytes()
+                .map(|byte| format!("%{byte:02X}"))
+                .collect()
+            }
+          })
+          .collect(),
+      }
+    }))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cache_keys() {
+    assert_eq!(
+      cache_key("http://example.com/schemas/main.xsd"),
+      PathBuf::from("http/example.com/schemas/main.xsd")
+    );
+    assert_eq!(
+      cache_key("https://example.com:8080/../main.xsd?version=2"),
+      PathBuf::from("https/example.com%3A8080/%2E%2E/main.xsd%3Fversion%3D2")
+    );
+    assert_ne!(
+      cache_key("http://example.com/main.xsd"),
+      cache_key("https://example.com/main.xsd")
+    );
+    assert_ne!(
+      cache_key("http://example.com/main.xsd?v"),
+      cache_key("http://example.com/main.xsd_v")
+    );
+    assert_ne!(
+      cache_key("http://example.com/main%3F.xsd"),
+      cache_key("http://example.com/main?.xsd")
+    );
+    assert_ne!(
+      cache_key("http://example.com//main.xsd"),
+      cache_key("http://example.com/main.xsd")
+    );
+  }
+
+  #[test]
+  fn load_cached() {
+    let cache_directory = std::env::temp_dir().join(format!(
+      "xml_schema_generator_load_cached_{}",
+      std::process::id()
+    ));
+    let cache_path = cache_directory.join("http/example.com/cached.xsd");
+    store(&cache_path, "<xs:schema/>").unwrap();
+
+    let loader = Loader::new()
+      .with_cache_directory(cache_directory.to_str().unwrap())
+      .with_offline(true);
+
+    assert_eq!(
+      loader.load("http://example.com/cached.xsd").unwrap(),
+      "<xs:schema/>"
+    );
+    assert_eq!(
+      loader.load("http://example.com/missing.xsd").unwrap_err(),
+      format!(
+        "http://example.com/missing.xsd: network access is disabled, map it in a catalog or store it as {}",
+        cache_directory.join("http/example.com/missing.xsd").display()
+      )
+    );
+
+    fs::remove_dir_all(cache_directory).unwrap();
+  }
+
+  #[test]
+  fn load_offline() {
+    let loader = Loader::new().with_offline(true);
+
+    assert_eq!(
+      loader.load("https://example.com/main.xsd").unwrap_err(),
+      "https://example.com/main.xsd: network access is disabled, map it in a catalog or set a cache directory"
+    );
+  }
+}
@@ -0,0 +1,662 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  default_value, rust_types_mapping::RustTypesMapping, simple_type::SimpleType, Implementation,
+  XsdContext,
+};
+use heck::{CamelCase, SnakeCase};
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "attribute",
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Attribute {
+  #[yaserde(prefix = "xs", attribute)]
+  pub name: Option<String>,
+  #[yaserde(rename = "type", attribute)]
+  pub kind: Option<String>,
+  #[yaserde(attribute)]
+  pub default: Option<String>,
+  #[yaserde(attribute)]
+  pub fixed: Option<String>,
+  #[yaserde(rename = "use", attribute)]
+  pub required: Required,
+  #[yaserde(rename = "ref", attribute)]
+  pub reference: Option<String>,
+  #[yaserde(rename = "simpleType")]
+  pub simple_type: Option<SimpleType>,
+}
+
+#[derive(Clone, Debug, PartialEq, YaDeserialize)]
+// variants named after the values of the `use` attribute
+#[allow(clippy::enum_variant_names)]
+pub enum Required {
+  #[yaserde(rename = "optional")]
+  Optional,
+  #[yaserde(rename = "required")]
+  Required,
+  #[yaserde(rename = "prohibited")]
+  Prohibited,
+}
+
+impl Default for Required {
+  fn default() -> Self {
+    Required::Optional
+  }
+}
+
+impl Implementation for Attribute {
+  fn implement(
+    &self,
+    _namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    if self.required == Required::Prohibited {
+      return quote!();
+    }
+
+    match (&self.name, &self.reference) {
+      (Some(_), _) => self.implement_field(prefix, context, sub_types_name_prefix),
+      (None, Some(reference)) => {
+        let _scope = context.enter("attribute", "ref", reference);
+        match self.get_reference(context) {
+          Some(attribute) => attribute.implement_field(prefix, context, sub_types_name_prefix),
+          None => {
+            errors::report(
+              Error::new(format!("Unknown attribute {reference:?}"))
+                .at(context.locate(&["ref"], reference))
+                .with_hint(
+                  "declare the attribute in the schema, or import the schema declaring it",
+                ),
+            );
+            quote!()
+          }
+        }
+      }
+      (None, None) => {
+        errors::report(
+          Error::new("Attribute without name")
+            .at(context.locate_schema())
+            .with_hint(
+              "add a `name` attribute, or a `ref` attribute referencing a global attribute",
+            ),
+        );
+        quote!()
+      }
+    }
+  }
+}
+
+impl Attribute {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    for name in self.kind.iter_mut().chain(self.reference.iter_mut()) {
+      *name = map(name);
+    }
+    if let Some(simple_type) = self.simple_type.as_mut() {
+      simple_type.map_qualified_names(map);
+    }
+  }
+
+  /// Field of a named attribute, or of the global attribute resolved from a reference.
+  fn implement_field(
+    &self,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    let raw_name = self.name.as_deref().unwrap_or_default();
+    let _scope = context.enter("attribute", "name", raw_name);
+    let name = raw_name.to_snake_case();
+
+    let name = if name == "type" {
+      "kind".to_string()
+    } else {
+      name
+    };
+
+    let field_name = Ident::new(&name, Span::call_site());
+
+    let rust_type = self.get_type_implementation(context, prefix);
+
+    // a default value equal to the declared one is omitted when serialized, unless the
+    // defaults are serialized
+    let (rust_type, default) = match (&self.fixed, &self.default) {
+      (Some(_), _) => {
+        let fixed_value = self.get_value_name("fixed", sub_types_name_prefix);
+        (
+          quote!(xml_schema::fixed::Fixed<#rust_type, #fixed_value>),
+          None,
+        )
+      }
+      (None, Some(_)) if context.serialize_default_attributes() => {
+        let default_value = self.get_value_name("default", sub_types_name_prefix);
+        (
+          quote!(xml_schema::default::Defaulted<#rust_type, #default_value>),
+          None,
+        )
+      }
+      (None, Some(_)) => (
+        rust_type,
+        Some(self.get_default_function_name(sub_types_name_prefix)),
+      ),
+      _ if self.required == Required::Optional => (quote!(Option<#rust_type>), None),
+      _ => (rust_type, None),
+    };
+
+    let attributes = if name == raw_name {
+      quote!(attribute)
+    } else {
+      quote!(attribute, rename=#raw_name)
+    };
+
+    let attributes = match default {
+      Some(default) => {
+        let default = default.to_string();
+        quote!(#attributes, default=#default)
+      }
+      None => attributes,
+    };
+
+    quote!(
+      #[yaserde(#attributes)]
+      pub #field_name: #rust_type,
+    )
+  }
+
+  fn get_type_implementation(&self, context: &XsdContext, prefix: &Option<String>) -> TokenStream {
+    match (
+      self.reference.as_ref(),
+      self.kind.as_ref(),
+      self.simple_type.as_ref(),
+    ) {
+      (None, Some(kind), None) => RustTypesMapping::get(context, kind),
+      (Some(reference), None, None) => RustTypesMapping::get(context, reference),
+      (None, None, Some(simple_type)) => simple_type.get_type_implementation(context, prefix),
+      (_, _, _) => {
+        let name = self.name.as_deref().unwrap_or_default();
+        errors::report(
+          Error::new(format!(
+            "Invalid type declaration for the attribute {name:?}"
+          ))
+          .at(context.locate(&["name"], name))
+          .with_hint("use either a `type` attribute, a `ref` attribute or a `xs:simpleType`"),
+        );
+        quote!(String)
+      }
+    }
+  }
+
+  /// Function returning the `default` value, or marker type of the `fixed` or serialized
+  /// `default` value, declared next to the structure named `sub_types_name_prefix` holding the
+  /// attribute. The value is checked against the type of the attribute when it is known.
+  pub fn get_value_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    if self.required == Required::Prohibited {
+      return quote!();
+    }
+    if self.name.is_none() {
+      return self
+        .get_reference(context)
+        .map(|attribute| attribute.get_value_implementation(context, prefix, sub_types_name_prefix))
+        .unwrap_or_default();
+    }
+
+    let (kind, value) = match (&self.fixed, &self.default) {
+      (Some(fixed), _) => ("fixed", fixed),
+      (None, Some(default)) => ("default", default),
+      (None, None) => return quote!(),
+    };
+
+    let valid = default_value::is_valid_value(
+      context,
+      self.kind.as_deref(),
+      self.simple_type.as_ref(),
+      value,
+    );
+    if valid == Some(false) {
+      let name = self.name.as_deref().unwrap_or_default();
+      errors::report(
+        Error::new(format!(
+          "Invalid {kind} value {value:?} for the attribute {name:?}"
+        ))
+        .at(context.locate(&[kind], value))
+        .with_hint("use a value of the type of the attribute"),
+      );
+      return quote!();
+    }
+
+    if kind == "fixed" || context.serialize_default_attributes() {
+      let value_name = self.get_value_name(kind, sub_types_name_prefix);
+      let value_trait = if kind == "fixed" {
+        quote!(xml_schema::fixed::FixedValue)
+      } else {
+        quote!(xml_schema::default::DefaultValue)
+      };
+
+      return quote!(
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct #value_name;
+
+        impl #value_trait for #value_name {
+          const VALUE: &'static str = #value;
+        }
+      );
+    }
+
+    let function_name = self.get_default_function_name(sub_types_name_prefix);
+    let rust_type = self.get_type_implementation(context, prefix);
+    let message = format!("Invalid default value {:?}", value);
+
+    quote!(
+      pub fn #function_name() -> #rust_type {
+        #value.parse().expect(#message)
+      }
+    )
+  }
+
+  /// The global attribute referenced by `ref`, with the `use`, `default` and `fixed` value of
+  /// the reference.
+  fn get_reference(&self, context: &XsdContext) -> Option<Attribute> {
+    let attribute = context.get_attribute(self.reference.as_ref()?)?;
+
+    Some(Attribute {
+      default: self.default.clone().or_else(|| attribute.default.clone()),
+      fixed: self.fixed.clone().or_else(|| attribute.fixed.clone()),
+      required: self.required.clone(),
+      reference: None,
+      ..attribute.clone()
+    })
+  }
+
+  fn get_default_function_name(&self, sub_types_name_prefix: &Option<&str>) -> Ident {
+    let name = format!(
+      "default_{}_{}",
+      sub_types_name_prefix.unwrap_or_default(),
+      self.name.as_deref().unwrap_or_default()
+    );
+
+    Ident::new(&name.to_snake_case(), Span::call_site())
+  }
+
+  fn get_value_name(&self, kind: &str, sub_types_name_prefix: &Option<&str>) -> Ident {
+    let name = format!(
+      "{}_{}_{}",
+      sub_types_name_prefix.unwrap_or_default(),
+      self.name.as_deref().unwrap_or_default(),
+      kind
+    );
+
+    Ident::new(&name.to_camel_case(), Span::call_site())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::BTreeMap;
+  use std::str::FromStr;
+
+  #[test]
+  fn default_required() {
+    assert_eq!(Required::default(), Required::Optional);
+  }
+
+  #[test]
+  fn prohibited_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      required: Required::Prohibited,
+      simple_type: None,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &None);
+
+    assert!(implementation.is_empty());
+  }
+
+  #[test]
+  fn default_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      default: Some("en".to_string()),
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &Some("Book"));
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, default="default_book_language")]
+        pub language: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    let implementation = attribute.get_value_implementation(&context, &None, &Some("Book"));
+
+    let expected = TokenStream::from_str(
+      r#"
+        pub fn default_book_language() -> String {
+          "en".parse().expect("Invalid default value \"en\"")
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn serialized_default_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      default: Some("en".to_string()),
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_serialize_default_attributes(true);
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &Some("Book"));
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute)]
+        pub language: xml_schema::default::Defaulted<String, BookLanguageDefault> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    let implementation = attribute.get_value_implementation(&context, &None, &Some("Book"));
+
+    assert!(implementation
+      .to_string()
+      .contains("impl xml_schema :: default :: DefaultValue for BookLanguageDefault"));
+  }
+
+  #[test]
+  fn invalid_default_attribute() {
+    let attribute = Attribute {
+      name: Some("level".to_string()),
+      kind: Some("xs:unsignedInt".to_string()),
+      default: Some("high".to_string()),
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    errors::take();
+    attribute.get_value_implementation(&context, &None, &Some("Book"));
+
+    assert_eq!(
+      errors::take()[0].message,
+      r#"Invalid default value "high" for the attribute "level""#
+    );
+  }
+
+  #[test]
+  fn string_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      required: Required::Required,
+      simple_type: None,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute)]
+        pub language: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn optional_string_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      required: Required::Optional,
+      simple_type: None,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute)]
+        pub language: Option<String> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn type_attribute() {
+    let attribute = Attribute {
+      name: Some("type".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      required: Required::Optional,
+      simple_type: None,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, rename="type")]
+        pub kind: Option<String> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn reference_type_attribute() {
+    let attribute = Attribute {
+      name: Some("type".to_string()),
+      kind: None,
+      reference: Some("MyType".to_string()),
+      required: Required::Optional,
+      simple_type: None,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, rename="type")]
+        pub kind: Option<MyType> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn bad_type_attribute() {
+    let attribute = Attribute {
+      name: Some("type".to_string()),
+      kind: None,
+      reference: None,
+      required: Required::Optional,
+      simple_type: None,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    errors::take();
+    attribute.implement(&TokenStream::new(), &None, &context, &None);
+
+    let errors = errors::take();
+    assert_eq!(
+      errors[0].message,
+      r#"Invalid type declaration for the attribute "type""#
+    );
+  }
+
+  #[test]
+  fn attribute_without_name() {
+    let attribute = Attribute {
+      name: None,
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      required: Required::Optional,
+      simple_type: None,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    errors::take();
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context, &None)
+      .to_string();
+    assert!(implementation.is_empty());
+    assert_eq!(errors::take()[0].message, "Attribute without name");
+  }
+
+  #[test]
+  fn referenced_attribute() {
+    let attribute = Attribute {
+      reference: Some("xml:lang".to_string()),
+      required: Required::Required,
+      ..Default::default()
+    };
+
+    let global_attribute = Attribute {
+      name: Some("lang".to_string()),
+      kind: Some("xs:string".to_string()),
+      default: Some("en".to_string()),
+      ..Default::default()
+    };
+
+    let context = XsdContext::new(
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns:xml="http://www.w3.org/XML/1998/namespace">
+        <xs:complexType name="Book"><xs:attribute ref="xml:lang" use="required"/></xs:complexType>
+      </xs:schema>"#,
+    )
+    .unwrap()
+    .with_attributes(&BTreeMap::from([(
+      (
+        "http://www.w3.org/XML/1998/namespace".to_string(),
+        "lang".to_string(),
+      ),
+      global_attribute,
+    )]));
+
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &Some("Book"));
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, default="default_book_lang")]
+        pub lang: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    let implementation = attribute.get_value_implementation(&context, &None, &Some("Book"));
+
+    assert!(implementation
+      .to_string()
+      .contains("pub fn default_book_lang () -> String"));
+  }
+
+  #[test]
+  fn unknown_referenced_attribute() {
+    let attribute = Attribute {
+      reference: Some("lang".to_string()),
+      ..Default::default()
+    };
+
+    let context = XsdContext::new(
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:complexType name="Book">
+          <xs:attribute ref="lang"/>
+        </xs:complexType>
+      </xs:schema>"#,
+    )
+    .unwrap()
+    .with_location("book.xsd");
+
+    errors::take();
+    let implementation = attribute.implement(&TokenStream::new(), &None, &context, &None);
+
+    assert!(implementation.is_empty());
+
+    let errors = errors::take();
+    assert_eq!(errors[0].message, r#"Unknown attribute "lang""#);
+    assert_eq!(errors[0].location.as_ref().unwrap().position, Some((3, 11)));
+  }
+}
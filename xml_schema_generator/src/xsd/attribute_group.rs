@@ -0,0 +1,238 @@
+use crate::errors::{self, Error};
+use crate::xsd::{attribute::Attribute, Implementation, XsdContext};
+use proc_macro2::TokenStream;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+// no prefix: YaSerDe compares the root name with the local name, to not confuse the
+// group with the nested `attributeGroup` elements
+#[yaserde(
+  rename = "attributeGroup",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct AttributeGroup {
+  #[yaserde(prefix = "xs", attribute)]
+  pub name: Option<String>,
+  #[yaserde(rename = "ref", attribute)]
+  pub reference: String,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+}
+
+impl Implementation for AttributeGroup {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    self
+      .get_attributes(context)
+      .iter()
+      .map(|attribute| {
+        attribute.implement(namespace_definition, prefix, context, sub_types_name_prefix)
+      })
+      .collect()
+  }
+}
+
+impl AttributeGroup {
+  /// Replace the qualified names of the referenced types and groups, like `prefix:name`, to use
+  /// the group in another schema.
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    if !self.reference.is_empty() {
+      self.reference = map(&self.reference);
+    }
+    for attribute in self.attributes.iter_mut() {
+      attribute.map_qualified_names(map);
+    }
+    for attribute_group in self.attribute_groups.iter_mut() {
+      attribute_group.map_qualified_names(map);
+    }
+  }
+
+  /// Attributes of the group, the referenced groups being expanded.
+  pub fn get_attributes(&self, context: &XsdContext) -> Vec<Attribute> {
+    self.collect_attributes(context, &mut vec![])
+  }
+
+  fn collect_attributes(
+    &self,
+    context: &XsdContext,
+    references: &mut Vec<String>,
+  ) -> Vec<Attribute> {
+    if !self.reference.is_empty() {
+      if references.contains(&self.reference) {
+        errors::report(
+          Error::new(format!(
+            "Circular reference of attribute group {:?} through {:?}",
+            self.reference, references
+          ))
+          .at(context.locate(&["ref"], &self.reference)),
+        );
+        return vec![];
+      }
+
+      let attribute_group = match context.get_attribute_group(&self.reference) {
+        Some(attribute_group) => attribute_group,
+        None => {
+          errors::report(
+            Error::new(format!("Unknown attribute group {:?}", self.reference))
+              .at(context.locate(&["ref"], &self.reference))
+              .with_hint("declare the attribute group, or import the schema declaring it"),
+          );
+          return vec![];
+        }
+      };
+
+      references.push(self.reference.clone());
+      let attributes = attribute_group.collect_attributes(context, references);
+      references.pop();
+
+      return attributes;
+    }
+
+    let mut attributes = self.attributes.clone();
+    for attribute_group in &self.attribute_groups {
+      for attribute in attribute_group.collect_attributes(context, references) {
+        if !attributes
+          .iter()
+          .any(|existing| existing.name == attribute.name)
+        {
+          attributes.push(attribute);
+        }
+      }
+    }
+
+    attributes
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::complex_type::ComplexType;
+  use std::collections::BTreeMap;
+
+  fn context_with_groups(attribute_groups: Vec<AttributeGroup>) -> XsdContext {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let attribute_groups: BTreeMap<(String, String), AttributeGroup> = attribute_groups
+      .into_iter()
+      .map(|group| (("".to_string(), group.name.clone().unwrap()), group))
+      .collect();
+
+    context.with_attribute_groups(&attribute_groups)
+  }
+
+  fn attribute(name: &str) -> Attribute {
+    Attribute {
+      name: Some(name.to_string()),
+      kind: Some("xs:string".to_string()),
+      ..Default::default()
+    }
+  }
+
+  fn reference(name: &str) -> AttributeGroup {
+    AttributeGroup {
+      reference: name.to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn nested_references() {
+    let context = context_with_groups(vec![
+      AttributeGroup {
+        name: Some("common".to_string()),
+        attributes: vec![attribute("id")],
+        attribute_groups: vec![reference("language")],
+        ..Default::default()
+      },
+      AttributeGroup {
+        name: Some("language".to_string()),
+        attributes: vec![attribute("lang")],
+        ..Default::default()
+      },
+    ]);
+
+    let names: Vec<Option<String>> = reference("common")
+      .get_attributes(&context)
+      .into_iter()
+      .map(|attribute| attribute.name)
+      .collect();
+
+    assert_eq!(
+      names,
+      vec![Some("id".to_string()), Some("lang".to_string())]
+    );
+  }
+
+  #[test]
+  fn circular_references() {
+    let context = context_with_groups(vec![
+      AttributeGroup {
+        name: Some("first".to_string()),
+        attribute_groups: vec![reference("second")],
+        ..Default::default()
+      },
+      AttributeGroup {
+        name: Some("second".to_string()),
+        attribute_groups: vec![reference("first")],
+        ..Default::default()
+      },
+    ]);
+
+    errors::take();
+    reference("first").get_attributes(&context);
+
+    assert!(errors::take()[0]
+      .message
+      .starts_with("Circular reference of attribute group"));
+  }
+
+  #[test]
+  fn unknown_reference() {
+    let context = context_with_groups(vec![]);
+
+    errors::take();
+    assert!(reference("unknown").get_attributes(&context).is_empty());
+    assert_eq!(
+      errors::take()[0].message,
+      r#"Unknown attribute group "unknown""#
+    );
+  }
+
+  #[test]
+  fn attribute_declared_twice() {
+    let context = context_with_groups(vec![AttributeGroup {
+      name: Some("common".to_string()),
+      attributes: vec![attribute("id"), attribute("lang")],
+      ..Default::default()
+    }]);
+
+    let complex_type = ComplexType {
+      name: "Section".to_string(),
+      attributes: vec![attribute("id")],
+      attribute_groups: vec![reference("common")],
+      ..Default::default()
+    };
+
+    errors::take();
+    let implementation = complex_type
+      .implement(&TokenStream::new(), &None, &context, &None)
+      .to_string();
+
+    assert_eq!(implementation.matches("pub id :").count(), 1);
+    assert!(implementation.contains("pub lang :"));
+    assert_eq!(
+      errors::take()[0].message,
+      r#"Attribute "id" is declared several times in type "Section""#
+    );
+  }
+}
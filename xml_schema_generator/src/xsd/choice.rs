@@ -0,0 +1,816 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  annotation::Annotation, element::Element, group::Group, max_occurences::MaxOccurences,
+  sequence::Sequence, Implementation, XsdContext,
+};
+use heck::CamelCase;
+use proc_macro2::{Literal, Span, TokenStream};
+use std::io::Read;
+use syn::Ident;
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, YaDeserialize};
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Choice {
+  pub min_occurences: Option<u64>,
+  pub max_occurences: Option<MaxOccurences>,
+  pub elements: Vec<Element>,
+  pub groups: Vec<Group>,
+  pub sequences: Vec<Sequence>,
+  pub choices: Vec<Choice>,
+  pub annotation: Option<Annotation>,
+}
+
+// Deserialized by hand, the derive does not support a child with the name of the structure.
+impl YaDeserialize for Choice {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let mut choice = Choice::default();
+
+    match reader.next_event()? {
+      XmlEvent::StartElement {
+        name, attributes, ..
+      } if name.local_name == "choice" => {
+        for attribute in attributes {
+          let value = attribute.value.trim();
+          match attribute.name.local_name.as_str() {
+            "minOccurs" => {
+              choice.min_occurences = Some(
+                value
+                  .parse()
+                  .map_err(|error| format!("Invalid minOccurs {:?}: {}", value, error))?,
+              )
+            }
+            "maxOccurs" => {
+              choice.max_occurences = Some(match value {
+                "unbounded" => MaxOccurences::Unbounded,
+                value => MaxOccurences::Number {
+                  value: value
+                    .parse()
+                    .map_err(|error| format!("Invalid maxOccurs {:?}: {}", value, error))?,
+                },
+              })
+            }
+            _ => {}
+          }
+        }
+      }
+      event => return Err(format!("Expected choice start, got {:?}", event)),
+    }
+
+    loop {
+      match reader.peek()?.to_owned() {
+        XmlEvent::StartElement { name, .. } => {
+          match name.local_name.as_str() {
+            "element" => choice.elements.push(Element::deserialize(reader)?),
+            "group" => choice.groups.push(Group::deserialize(reader)?),
+            "sequence" => choice.sequences.push(Sequence::deserialize(reader)?),
+            "choice" => choice.choices.push(Choice::deserialize(reader)?),
+            "annotation" => choice.annotation = Some(Annotation::deserialize(reader)?),
+            _ => {
+              reader.next_event()?;
+              reader.skip_element(|_| {})?;
+              continue;
+            }
+          }
+          reader.next_event()?;
+        }
+        XmlEvent::EndElement { .. } | XmlEvent::EndDocument => break,
+        _ => {
+          reader.next_event()?;
+        }
+      }
+    }
+
+    Ok(choice)
+  }
+}
+
+impl Choice {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    for element in self.elements.iter_mut() {
+      element.map_qualified_names(map);
+    }
+    for group in self.groups.iter_mut() {
+      group.map_qualified_names(map);
+    }
+    for sequence in self.sequences.iter_mut() {
+      sequence.map_qualified_names(map);
+    }
+    for choice in self.choices.iter_mut() {
+      choice.map_qualified_names(map);
+    }
+  }
+
+  /// Replace the group references by their elements, each one being an alternative.
+  ///
+  /// The alternatives of a nested choice, and the element of a nested sequence, are alternatives
+  /// of this choice. A nested sequence of several elements is an alternative too, the other
+  /// nested particles which can not be represented by a single alternative are reported.
+  pub fn expand_groups(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    for group in std::mem::take(&mut self.groups) {
+      let group = group.expand(context, references);
+      self.elements.extend(group.get_elements());
+    }
+
+    for mut choice in std::mem::take(&mut self.choices) {
+      choice.expand_groups(context, references);
+
+      if choice.is_multiple() {
+        errors::report(
+          Error::new("Repeated choice nested in a choice is not supported")
+            .at(context.locate_schema())
+            .with_hint("move the repeated choice into a named complex type"),
+        );
+        continue;
+      }
+      if choice.is_optional() {
+        self.min_occurences = Some(0);
+      }
+      self.elements.extend(choice.elements);
+    }
+
+    for mut sequence in std::mem::take(&mut self.sequences) {
+      sequence.expand_groups(context, references);
+
+      if !sequence.choices.is_empty() || !sequence.groups.is_empty() {
+        Choice::report_sequence(
+          &sequence,
+          context,
+          "Choice or repeated group in a sequence nested in a choice is not supported",
+        );
+        continue;
+      }
+
+      match sequence.elements.as_slice() {
+        [] => self.min_occurences = Some(0),
+        [element] => self.elements.push(element.clone()),
+        [first, ..] if first.min_occurences == Some(0) => Choice::report_sequence(
+          &sequence,
+          context,
+          "Optional first element of a sequence nested in a choice is not supported",
+        ),
+        elements
+          if elements
+            .iter()
+            .any(|element| is_multiple(&element.max_occurences)) =>
+        {
+          Choice::report_sequence(
+            &sequence,
+            context,
+            "Repeated element in a sequence nested in a choice is not supported",
+          )
+        }
+        _ => self.sequences.push(sequence),
+      }
+    }
+
+    for element in self.elements.iter_mut() {
+      element.expand_groups(context, references);
+    }
+  }
+
+  /// Report a sequence nested in this choice at its first element, or at the schema.
+  fn report_sequence(sequence: &Sequence, context: &XsdContext, message: &str) {
+    let location = sequence
+      .elements
+      .first()
+      .and_then(|element| match &element.refers {
+        Some(reference) => context.locate(&["ref"], reference),
+        None => context.locate(&["name"], &element.name),
+      })
+      .or_else(|| context.locate_schema());
+
+    errors::report(
+      Error::new(message)
+        .at(location)
+        .with_hint("move the sequence into an element with a complex type"),
+    );
+  }
+
+  /// Name of the enum generated for the `index`-th choice of a structure.
+  pub fn get_enum_name(struct_name: &str, index: usize) -> Ident {
+    let name = if index == 0 {
+      format!("{struct_name}Choice")
+    } else {
+      format!("{struct_name}Choice{}", index + 1)
+    };
+
+    Ident::new(&name, Span::call_site())
+  }
+
+  /// Name of the field holding the `index`-th choice of a structure.
+  pub fn get_field_name(&self, index: usize) -> Ident {
+    let name = if self.is_multiple() {
+      "choices"
+    } else {
+      "choice"
+    };
+
+    let name = if index == 0 {
+      name.to_string()
+    } else {
+      format!("{name}_{}", index + 1)
+    };
+
+    Ident::new(&name, Span::call_site())
+  }
+
+  /// The choice can be repeated, directly or through one of its elements.
+  pub fn is_multiple(&self) -> bool {
+    is_multiple(&self.max_occurences)
+      || self
+        .elements
+        .iter()
+        .any(|element| is_multiple(&element.max_occurences))
+  }
+
+  /// The choice can be absent, directly or through one of its elements.
+  pub fn is_optional(&self) -> bool {
+    self.min_occurences == Some(0)
+      || self
+        .elements
+        .iter()
+        .any(|element| element.min_occurences == Some(0))
+  }
+
+  /// Minimum count of alternatives of the choice, its minimum occurrences times the smallest one
+  /// of its elements.
+  pub fn get_min_items(&self) -> u64 {
+    if self.is_optional() {
+      return 0;
+    }
+
+    let element_min_occurences = self
+      .elements
+      .iter()
+      .map(|element| element.min_occurences.unwrap_or(1))
+      .min()
+      .unwrap_or(1);
+
+    self.min_occurences.unwrap_or(1) * element_min_occurences
+  }
+
+  pub fn get_field_implementation(&self, enum_name: &Ident, index: usize) -> TokenStream {
+    let field_name = self.get_field_name(index);
+
+    let rust_type = if self.is_multiple() {
+      match self.get_min_items() {
+        0 => quote!(xml_schema::choice::Choices<#enum_name>),
+        min_items => {
+          let min_items = Literal::u64_unsuffixed(min_items);
+          quote!(xml_schema::choice::Choices<#enum_name, #min_items>)
+        }
+      }
+    } else if self.is_optional() {
+      quote!(xml_schema::choice::OptionalChoice<#enum_name>)
+    } else {
+      quote!(#enum_name)
+    };
+
+    quote!(
+      #[yaserde(flatten)]
+      pub #field_name: #rust_type,
+    )
+  }
+
+  pub fn get_enum_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    enum_name: &Ident,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    let elements: Vec<(&Element, String)> = self
+      .elements
+      .iter()
+      .map(|element| (element, element.get_name(context)))
+      .filter(|(_, element_name)| !element_name.is_empty())
+      .collect();
+
+    let sequences: Vec<(Ident, Vec<(&Element, String, Ident)>)> = self
+      .sequences
+      .iter()
+      .map(|sequence| {
+        let elements: Vec<(&Element, String, Ident)> = sequence
+          .elements
+          .iter()
+          .map(|element| {
+            let field_name = Ident::new(&element.get_field_name(context), Span::call_site());
+            (element, element.get_name(context), field_name)
+          })
+          .filter(|(_, element_name, _)| !element_name.is_empty())
+          .collect();
+
+        let variant_name: String = elements
+          .iter()
+          .map(|(_, element_name, _)| element_name.replace('.', "_").to_camel_case())
+          .collect();
+
+        (Ident::new(&variant_name, Span::call_site()), elements)
+      })
+      .filter(|(_, elements)| !elements.is_empty())
+      .collect();
+
+    if elements.is_empty() && sequences.is_empty() {
+      errors::report(
+        Error::new(format!("Missing elements for choice {enum_name}"))
+          .at(context.locate_schema())
+          .with_hint("add at least one named element to the `xs:choice`"),
+      );
+      return quote!();
+    }
+
+    let variant_names: Vec<Ident> = elements
+      .iter()
+      .map(|(_, element_name)| {
+        Ident::new(
+          &element_name.replace('.', "_").to_camel_case(),
+          Span::call_site(),
+        )
+      })
+      .collect();
+
+    let variants: TokenStream = elements
+      .iter()
+      .zip(variant_names.iter())
+      .map(|((element, _), variant_name)| {
+        let docs = element
+          .annotation
+          .as_ref()
+          .map(|annotation| annotation.implement(namespace_definition, prefix, context, &None))
+          .unwrap_or_default();
+        let rust_type = element.get_type_implementation(context, sub_types_name_prefix);
+
+        quote!(
+          #docs
+          #variant_name(#rust_type),
+        )
+      })
+      .collect();
+
+    let sequence_variants: TokenStream = sequences
+      .iter()
+      .map(|(variant_name, elements)| {
+        let fields: TokenStream = elements
+          .iter()
+          .map(|(element, _, field_name)| {
+            let docs = element
+              .annotation
+              .as_ref()
+              .map(|annotation| annotation.implement(namespace_definition, prefix, context, &None))
+              .unwrap_or_default();
+            let rust_type = element.get_type_implementation(context, sub_types_name_prefix);
+            let rust_type = if element.min_occurences == Some(0) {
+              quote!(Option<#rust_type>)
+            } else {
+              rust_type
+            };
+
+            quote!(
+              #docs
+              #field_name: #rust_type,
+            )
+          })
+          .collect();
+
+        quote!(
+          #variant_name {
+            #fields
+          },
+        )
+      })
+      .collect();
+
+    let deserialize_variants: TokenStream = elements
+      .iter()
+      .zip(variant_names.iter())
+      .map(|((element, element_name), variant_name)| {
+        let deserialize = if element.has_text_content(context) {
+          quote!(xml_schema::choice::deserialize_text)
+        } else {
+          quote!(xml_schema::choice::deserialize_element)
+        };
+
+        quote!(#element_name => Some(#deserialize(reader).map(#enum_name::#variant_name)),)
+      })
+      .collect();
+
+    let deserialize_sequence_variants: TokenStream = sequences
+      .iter()
+      .map(|(variant_name, elements)| {
+        let fields: Vec<&Ident> = elements
+          .iter()
+          .map(|(_, _, field_name)| field_name)
+          .collect();
+
+        let statements: TokenStream = elements
+          .iter()
+          .enumerate()
+          .map(|(index, (element, element_name, field_name))| {
+            let deserialize = if element.has_text_content(context) {
+              quote!(xml_schema::choice::deserialize_text)
+            } else {
+              quote!(xml_schema::choice::deserialize_element)
+            };
+
+            if index == 0 {
+              quote!(let #field_name = #deserialize(reader)?;)
+            } else if element.min_occurences == Some(0) {
+              quote!(
+                let #field_name = if xml_schema::choice::next_element_is(#element_name, reader)? {
+                  Some(#deserialize(reader)?)
+                } else {
+                  None
+                };
+              )
+            } else {
+              let missing =
+                format!("Missing element {element_name} of the {variant_name} alternative");
+              quote!(
+                let #field_name = if xml_schema::choice::next_element_is(#element_name, reader)? {
+                  #deserialize(reader)?
+                } else {
+                  return Err(#missing.to_string());
+                };
+              )
+            }
+          })
+          .collect();
+
+        let first_element_name = &elements[0].1;
+
+        quote!(
+          #first_element_name => Some((|| -> Result<Self, String> {
+            #statements
+            Ok(#enum_name::#variant_name { #(#fields),* })
+          })()),
+        )
+      })
+      .collect();
+
+    let serialize_variants: TokenStream = elements
+      .iter()
+      .zip(variant_names.iter())
+      .map(|((element, element_name), variant_name)| {
+        let element_name = match prefix {
+          Some(prefix) => format!("{}:{}", prefix, element_name),
+          None => element_name.to_string(),
+        };
+        let serialize = if element.has_text_content(context) {
+          quote!(xml_schema::choice::serialize_text)
+        } else {
+          quote!(xml_schema::choice::serialize_element)
+        };
+
+        quote!(#enum_name::#variant_name(content) => #serialize(content, #element_name, writer),)
+      })
+      .collect();
+
+    let serialize_sequence_variants: TokenStream = sequences
+      .iter()
+      .map(|(variant_name, elements)| {
+        let fields: Vec<&Ident> = elements
+          .iter()
+          .map(|(_, _, field_name)| field_name)
+          .collect();
+
+        let statements: TokenStream = elements
+          .iter()
+          .map(|(element, element_name, field_name)| {
+            let element_name = match prefix {
+              Some(prefix) => format!("{}:{}", prefix, element_name),
+              None => element_name.to_string(),
+            };
+            let serialize = if element.has_text_content(context) {
+              quote!(xml_schema::choice::serialize_text)
+            } else {
+              quote!(xml_schema::choice::serialize_element)
+            };
+
+            if element.min_occurences == Some(0) {
+              quote!(
+                if let Some(#field_name) = #field_name {
+                  #serialize(#field_name, #element_name, writer)?;
+                }
+              )
+            } else {
+              quote!(#serialize(#field_name, #element_name, writer)?;)
+            }
+          })
+          .collect();
+
+        quote!(
+          #enum_name::#variant_name { #(#fields),* } => {
+            #statements
+            Ok(())
+          }
+        )
+      })
+      .collect();
+
+    let default_value = match variant_names.first() {
+      Some(variant_name) => quote!(#enum_name::#variant_name(Default::default())),
+      None => {
+        let (variant_name, elements) = &sequences[0];
+        let fields = elements.iter().map(|(_, _, field_name)| field_name);
+        quote!(#enum_name::#variant_name { #(#fields: Default::default()),* })
+      }
+    };
+
+    let docs = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.implement(namespace_definition, prefix, context, &None))
+      .unwrap_or_default();
+
+    quote!(
+      #docs
+      #[derive(Clone, Debug, PartialEq)]
+      pub enum #enum_name {
+        #variants
+        #sequence_variants
+      }
+
+      impl Default for #enum_name {
+        fn default() -> Self {
+          #default_value
+        }
+      }
+
+      impl xml_schema::choice::Choice for #enum_name {
+        fn deserialize_alternative<R: std::io::Read>(element_name: &str, reader: &mut yaserde::de::Deserializer<R>) -> Option<Result<Self, String>> {
+          match element_name {
+            #deserialize_variants
+            #deserialize_sequence_variants
+            _ => None,
+          }
+        }
+      }
+
+      impl yaserde::YaDeserialize for #enum_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          xml_schema::choice::deserialize(reader)
+        }
+      }
+
+      impl yaserde::YaSerialize for #enum_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          match self {
+            #serialize_variants
+            #serialize_sequence_variants
+          }
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    )
+  }
+
+  pub fn get_sub_types_implementation(
+    &self,
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    self
+      .elements
+      .iter()
+      .chain(
+        self
+          .sequences
+          .iter()
+          .flat_map(|sequence| &sequence.elements),
+      )
+      .map(|element| {
+        element.get_subtypes_implementation(
+          namespace_definition,
+          prefix,
+          context,
+          sub_types_name_prefix,
+        )
+      })
+      .collect()
+  }
+}
+
+fn is_multiple(max_occurences: &Option<MaxOccurences>) -> bool {
+  max_occurences.is_some() && *max_occurences != Some(MaxOccurences::Number { value: 1 })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  fn element(name: &str, kind: &str) -> Element {
+    Element {
+      name: name.to_string(),
+      kind: Some(kind.to_string()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn field_implementation() {
+    let choice = Choice {
+      elements: vec![element("Text", "xs:string")],
+      ..Default::default()
+    };
+    let enum_name = Choice::get_enum_name("Content", 0);
+
+    assert_eq!(
+      choice.get_field_implementation(&enum_name, 0).to_string(),
+      quote!(
+        #[yaserde(flatten)]
+        pub choice: ContentChoice,
+      )
+      .to_string()
+    );
+
+    let choice = Choice {
+      max_occurences: Some(MaxOccurences::Unbounded),
+      ..choice
+    };
+    let enum_name = Choice::get_enum_name("Content", 1);
+
+    assert_eq!(
+      choice.get_field_implementation(&enum_name, 1).to_string(),
+      quote!(
+        #[yaserde(flatten)]
+        pub choices_2: xml_schema::choice::Choices<ContentChoice2, 1>,
+      )
+      .to_string()
+    );
+
+    let choice = Choice {
+      min_occurences: Some(0),
+      ..choice
+    };
+
+    assert_eq!(
+      choice.get_field_implementation(&enum_name, 1).to_string(),
+      quote!(
+        #[yaserde(flatten)]
+        pub choices_2: xml_schema::choice::Choices<ContentChoice2>,
+      )
+      .to_string()
+    );
+  }
+
+  #[test]
+  fn enum_implementation() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let choice = Choice {
+      elements: vec![element("Text", "xs:string"), element("Image", "Image")],
+      ..Default::default()
+    };
+    let enum_name = Choice::get_enum_name("Content", 0);
+
+    let implementation =
+      choice.get_enum_implementation(&TokenStream::new(), &None, &context, &enum_name, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum ContentChoice {
+          Text(String),
+          Image(Image),
+        }
+
+        impl Default for ContentChoice {
+          fn default() -> Self {
+            ContentChoice::Text(Default::default())
+          }
+        }
+
+        impl xml_schema::choice::Choice for ContentChoice {
+          fn deserialize_alternative<R: std::io::Read>(element_name: &str, reader: &mut yaserde::de::Deserializer<R>) -> Option<Result<Self, String>> {
+            match element_name {
+              "Text" => Some(xml_schema::choice::deserialize_text(reader).map(ContentChoice::Text)),
+              "Image" => Some(xml_schema::choice::deserialize_element(reader).map(ContentChoice::Image)),
+              _ => None,
+            }
+          }
+        }
+
+        impl yaserde::YaDeserialize for ContentChoice {
+          fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+            xml_schema::choice::deserialize(reader)
+          }
+        }
+
+        impl yaserde::YaSerialize for ContentChoice {
+          fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+            match self {
+              ContentChoice::Text(content) => xml_schema::choice::serialize_text(content, "Text", writer),
+              ContentChoice::Image(content) => xml_schema::choice::serialize_element(content, "Image", writer),
+            }
+          }
+
+          fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute> , source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute> , xml::namespace::Namespace), String> {
+            Ok((source_attributes, source_namespace))
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn nested_particles() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let mut choice: Choice = yaserde::de::from_str(
+      r#"
+        <xs:choice xmlns:xs="http://www.w3.org/2001/XMLSchema" maxOccurs="unbounded">
+          <xs:element name="Text" type="xs:string"/>
+          <xs:choice minOccurs="0">
+            <xs:element name="Bold" type="xs:string"/>
+            <xs:element name="Italic" type="xs:string"/>
+          </xs:choice>
+          <xs:sequence>
+            <xs:element name="Break" type="xs:string"/>
+          </xs:sequence>
+          <xs:sequence>
+            <xs:element name="Term" type="xs:string"/>
+            <xs:element name="Definition" type="xs:string"/>
+          </xs:sequence>
+        </xs:choice>
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(choice.max_occurences, Some(MaxOccurences::Unbounded));
+    assert_eq!(choice.choices.len(), 1);
+    assert_eq!(choice.sequences.len(), 2);
+
+    errors::take();
+    choice.expand_groups(&context, &mut vec![]);
+
+    let names: Vec<&str> = choice
+      .elements
+      .iter()
+      .map(|element| element.name.as_str())
+      .collect();
+    assert_eq!(names, vec!["Text", "Bold", "Italic", "Break"]);
+    assert_eq!(choice.min_occurences, Some(0));
+    assert_eq!(choice.sequences.len(), 1);
+    assert_eq!(choice.sequences[0].elements.len(), 2);
+    assert!(errors::take().is_empty());
+  }
+
+  #[test]
+  fn unsupported_nested_sequence() {
+    let content = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:complexType name="Entry">
+          <xs:choice>
+            <xs:element name="Text" type="xs:string"/>
+            <xs:sequence>
+              <xs:element name="Term" type="xs:string" minOccurs="0"/>
+              <xs:element name="Definition" type="xs:string"/>
+            </xs:sequence>
+          </xs:choice>
+        </xs:complexType>
+      </xs:schema>"#;
+    let context = XsdContext::new(content).unwrap().with_location("entry.xsd");
+
+    let mut choice: Choice = yaserde::de::from_str(
+      r#"
+        <xs:choice xmlns:xs="http://www.w3.org/2001/XMLSchema">
+          <xs:element name="Text" type="xs:string"/>
+          <xs:sequence>
+            <xs:element name="Term" type="xs:string" minOccurs="0"/>
+            <xs:element name="Definition" type="xs:string"/>
+          </xs:sequence>
+        </xs:choice>
+      "#,
+    )
+    .unwrap();
+
+    errors::take();
+    choice.expand_groups(&context, &mut vec![]);
+
+    assert!(choice.sequences.is_empty());
+    let errors = errors::take();
+    assert_eq!(
+      errors[0].message,
+      "Optional first element of a sequence nested in a choice is not supported"
+    );
+    let location = errors[0].location.as_ref().unwrap();
+    assert_eq!(location.file, "entry.xsd");
+    assert_eq!(location.position, Some((6, 15)));
+  }
+}
@@ -0,0 +1,41 @@
+use crate::xsd::{extension::Extension, restriction::Restriction, xsd_context::XsdContext};
+use proc_macro2::TokenStream;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct ComplexContent {
+  pub extension: Option<Extension>,
+  pub restriction: Option<Restriction>,
+}
+
+impl ComplexContent {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    if let Some(extension) = self.extension.as_mut() {
+      extension.map_qualified_names(map);
+    }
+    if let Some(restriction) = self.restriction.as_mut() {
+      restriction.map_qualified_names(map);
+    }
+  }
+
+  pub fn expand_groups(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    if let Some(extension) = self.extension.as_mut() {
+      extension.expand_groups(context, references);
+    }
+    if let Some(restriction) = self.restriction.as_mut() {
+      restriction.expand_groups(context, references);
+    }
+  }
+
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+  ) -> TokenStream {
+    self
+      .extension
+      .as_ref()
+      .map(|extension| extension.get_field_implementation(context, prefix))
+      .unwrap_or_default()
+  }
+}
@@ -0,0 +1,614 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  annotation::Annotation,
+  attribute::{Attribute, Required},
+  attribute_group::AttributeGroup,
+  choice::Choice,
+  complex_content::ComplexContent,
+  group::Group,
+  restriction::Restriction,
+  rust_types_mapping::RustTypesMapping,
+  sequence::{Particle, Sequence},
+  simple_content::SimpleContent,
+  Implementation, XsdContext,
+};
+use heck::CamelCase;
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "complexType"
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct ComplexType {
+  #[yaserde(attribute)]
+  pub name: String,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+  pub sequence: Option<Sequence>,
+  pub choice: Option<Choice>,
+  pub group: Option<Group>,
+  #[yaserde(rename = "simpleContent")]
+  pub simple_content: Option<SimpleContent>,
+  #[yaserde(rename = "complexContent")]
+  pub complex_content: Option<ComplexContent>,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
+}
+
+impl Implementation for ComplexType {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    let _scope = context.enter("complexType", "name", &self.name);
+    let struct_name = Ident::new(
+      &self.name.replace('.', "_").to_camel_case(),
+      Span::call_site(),
+    );
+
+    let my_sub_types_name_prefix = match *sub_types_name_prefix {
+      Some(prefix) => prefix.to_owned() + self.name.as_str(),
+      None => self.name.to_owned(),
+    };
+
+    let content = self.get_content_field_implementation(context, prefix, &struct_name, &Some(&my_sub_types_name_prefix));
+
+    let simple_content = self
+      .simple_content
+      .as_ref()
+      .map(|simple_content| simple_content.get_fields_implementation(namespace_definition, prefix, context, &struct_name, &Some(&my_sub_types_name_prefix)))
+      .unwrap_or_else(TokenStream::new);
+
+    let complex_content = self
+      .complex_content
+      .as_ref()
+      .map(|complex_content| {
+        let complex_content_type = complex_content.get_field_implementation(context, prefix);
+        quote!(
+          #[yaserde(flatten)]
+          #complex_content_type,
+        )
+      })
+      .unwrap_or_else(TokenStream::new);
+
+    let attributes: TokenStream = self
+      .get_own_attributes(context)
+      .iter()
+      .map(|attribute| attribute.implement(namespace_definition, prefix, context, &Some(&my_sub_types_name_prefix)))
+      .collect();
+
+    let sub_types_implementation = self
+      .sequence
+      .as_ref()
+      .map(|sequence| sequence.get_sub_types_implementation(context, namespace_definition, prefix, &Some(&my_sub_types_name_prefix)))
+      .unwrap_or_else(TokenStream::new);
+
+    let choices_implementation = self.get_choices_implementation(namespace_definition, prefix, context, &struct_name, &Some(&my_sub_types_name_prefix));
+
+    let groups_implementation = self.get_groups_implementation(namespace_definition, prefix, context, &struct_name, sub_types_name_prefix);
+
+    let simple_content_implementation = self
+      .simple_content
+      .as_ref()
+      .map(|simple_content| simple_content.get_restriction_implementation(namespace_definition, prefix, context, &struct_name))
+      .unwrap_or_default();
+
+    let attribute_values_implementation: TokenStream = self
+      .get_attributes(context)
+      .iter()
+      .map(|attribute| attribute.get_value_implementation(context, prefix, &Some(&my_sub_types_name_prefix)))
+      .collect();
+
+    let docs = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.implement(namespace_definition, prefix, context, sub_types_name_prefix))
+      .unwrap_or_else(TokenStream::new);
+
+    quote! {
+      #docs
+
+      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub struct #struct_name {
+        #content
+        #simple_content
+        #complex_content
+        #attributes
+      }
+
+      #sub_types_implementation
+      #choices_implementation
+      #groups_implementation
+      #simple_content_implementation
+      #attribute_values_implementation
+    }
+  }
+}
+
+impl ComplexType {
+  /// Replace the qualified names of the referenced types, elements and groups, like
+  /// `prefix:name`, to use the type in another schema.
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    for attribute in self.attributes.iter_mut() {
+      attribute.map_qualified_names(map);
+    }
+    for attribute_group in self.attribute_groups.iter_mut() {
+      attribute_group.map_qualified_names(map);
+    }
+    if let Some(sequence) = self.sequence.as_mut() {
+      sequence.map_qualified_names(map);
+    }
+    if let Some(choice) = self.choice.as_mut() {
+      choice.map_qualified_names(map);
+    }
+    if let Some(group) = self.group.as_mut() {
+      group.map_qualified_names(map);
+    }
+    if let Some(simple_content) = self.simple_content.as_mut() {
+      simple_content.map_qualified_names(map);
+    }
+    if let Some(complex_content) = self.complex_content.as_mut() {
+      complex_content.map_qualified_names(map);
+    }
+  }
+
+  /// Replace the group references by the content of the groups.
+  pub fn expand_groups(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    if let Some(group) = self.group.take() {
+      self
+        .sequence
+        .get_or_insert_with(Sequence::default)
+        .push_group(group);
+    }
+
+    if let Some(sequence) = self.sequence.as_mut() {
+      sequence.expand_groups(context, references);
+    }
+    if let Some(choice) = self.choice.as_mut() {
+      choice.expand_groups(context, references);
+    }
+    if let Some(complex_content) = self.complex_content.as_mut() {
+      complex_content.expand_groups(context, references);
+    }
+  }
+
+  /// Replace a `complexContent` derivation by plain fields:
+  /// - an extension gets the fields of the base type, followed by the elements and attributes
+  ///   it adds,
+  /// - a restriction keeps its own content model, with the attributes of the base type
+  ///   overridden or removed (`use="prohibited"`) by the ones it declares.
+  ///
+  /// Base types which are not defined in the loaded schemas are kept as a flattened field.
+  /// The attributes of a `simpleContent` restriction are resolved the same way, and it keeps the
+  /// facets of a restricted base which it does not redefine.
+  pub fn expand_derivation(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    let complex_content = self.complex_content.clone().unwrap_or_default();
+
+    if let Some(extension) = complex_content.extension {
+      if let Some(mut base) = Self::get_base(context, &extension.base, references) {
+        let mut sequence = base.sequence.take().unwrap_or_default();
+        if let Some(choice) = base.choice.take() {
+          sequence.push_choice(choice);
+        }
+        for extension_sequence in extension.sequences {
+          sequence.append(extension_sequence);
+        }
+        for extension_choice in extension.choices {
+          sequence.push_choice(extension_choice);
+        }
+
+        if !sequence.get_particles().is_empty() {
+          self.sequence = Some(sequence);
+        }
+
+        base.attributes.extend(extension.attributes);
+        base.attributes.append(&mut self.attributes);
+        self.attributes = base.attributes;
+
+        base.attribute_groups.extend(extension.attribute_groups);
+        base.attribute_groups.append(&mut self.attribute_groups);
+        self.attribute_groups = base.attribute_groups;
+
+        self.complex_content = None;
+      }
+    } else if let Some(restriction) = complex_content.restriction {
+      let base_attributes = restriction
+        .base
+        .as_ref()
+        .and_then(|base| Self::get_base(context, base, references))
+        .map(|base| base.get_attributes(context))
+        .unwrap_or_default();
+
+      self.sequence = restriction.sequence.clone();
+      self.choice = restriction.choice.clone();
+      self.attributes = Self::restrict_attributes(
+        base_attributes,
+        Self::get_restriction_attributes(context, &restriction),
+      );
+      self.attribute_groups = vec![];
+      self.complex_content = None;
+    }
+
+    let simple_content_restriction = self
+      .simple_content
+      .as_mut()
+      .and_then(|simple_content| simple_content.restriction.as_mut());
+
+    if let Some(restriction) = simple_content_restriction {
+      let base = restriction
+        .base
+        .as_ref()
+        .and_then(|base| Self::get_base(context, base, references));
+
+      if let Some(base) = base {
+        restriction.attributes = Self::restrict_attributes(
+          base.get_attributes(context),
+          Self::get_restriction_attributes(context, restriction),
+        );
+        restriction.attribute_groups = vec![];
+
+        let base_restriction = base
+          .simple_content
+          .as_ref()
+          .and_then(|simple_content| simple_content.restriction.as_ref());
+        if let Some(base_restriction) = base_restriction {
+          restriction.inherit_facets(base_restriction);
+        }
+
+        if let Some(text_base) = base.get_text_base() {
+          restriction.base = Some(text_base);
+        }
+      }
+    }
+
+    let inline_complex_types = self
+      .sequence
+      .iter_mut()
+      .flat_map(|sequence| {
+        let group_elements = sequence
+          .groups
+          .iter_mut()
+          .filter_map(|group| group.sequence.as_mut())
+          .flat_map(|sequence| sequence.elements.iter_mut());
+
+        sequence
+          .elements
+          .iter_mut()
+          .chain(sequence.choices.iter_mut().flat_map(|choice| choice.elements.iter_mut()))
+          .chain(group_elements)
+      })
+      .chain(self.choice.iter_mut().flat_map(|choice| choice.elements.iter_mut()))
+      .filter_map(|element| element.complex_type.as_mut());
+
+    for complex_type in inline_complex_types {
+      complex_type.expand_derivation(context, &mut vec![]);
+    }
+  }
+
+  /// Base type of a derivation, with its own derivation expanded.
+  fn get_base(
+    context: &XsdContext,
+    base: &str,
+    references: &mut Vec<String>,
+  ) -> Option<ComplexType> {
+    let mut base_type = if RustTypesMapping::is_xml_schema_type(context, base) {
+      ComplexType::default()
+    } else {
+      context.get_complex_type(base)?.clone()
+    };
+
+    if references.iter().any(|reference| reference == base) {
+      errors::report(
+        Error::new(format!(
+          "Circular derivation of type {:?} through {:?}",
+          base, references
+        ))
+        .at(context.locate(&["base"], base)),
+      );
+      return None;
+    }
+
+    references.push(base.to_string());
+    base_type.expand_groups(context, &mut vec![]);
+    base_type.expand_derivation(context, references);
+    references.pop();
+
+    Some(base_type)
+  }
+
+  /// All the attributes of the type, the attribute groups being expanded.
+  fn get_attributes(&self, context: &XsdContext) -> Vec<Attribute> {
+    let mut attributes = self.attributes.clone();
+    for attribute_group in &self.attribute_groups {
+      for attribute in attribute_group.get_attributes(context) {
+        if !attributes.iter().any(|existing| Self::same_name(existing, &attribute)) {
+          attributes.push(attribute);
+        }
+      }
+    }
+
+    if let Some(simple_content) = &self.simple_content {
+      if let Some(extension) = &simple_content.extension {
+        attributes.extend(extension.attributes.iter().cloned());
+        for attribute_group in &extension.attribute_groups {
+          attributes.extend(attribute_group.get_attributes(context));
+        }
+      }
+      if let Some(restriction) = &simple_content.restriction {
+        attributes.extend(Self::get_restriction_attributes(context, restriction));
+      }
+    }
+
+    attributes
+  }
+
+  /// Attributes declared by the type and its attribute groups, an attribute declared twice is
+  /// reported and generated once.
+  fn get_own_attributes(&self, context: &XsdContext) -> Vec<Attribute> {
+    let mut attributes: Vec<Attribute> = vec![];
+    let declared = self.attributes.iter().cloned().chain(
+      self
+        .attribute_groups
+        .iter()
+        .flat_map(|attribute_group| attribute_group.get_attributes(context)),
+    );
+
+    for attribute in declared {
+      if let Some(name) = attributes
+        .iter()
+        .find(|existing| Self::same_name(existing, &attribute))
+        .and_then(|existing| existing.name.as_ref())
+      {
+        errors::report(
+          Error::new(format!(
+            "Attribute {name:?} is declared several times in type {:?}",
+            self.name
+          ))
+          .at(context.locate(&["name"], name))
+          .with_hint("declare the attribute once, directly or through an attribute group"),
+        );
+        continue;
+      }
+      attributes.push(attribute);
+    }
+
+    attributes
+  }
+
+  fn same_name(left: &Attribute, right: &Attribute) -> bool {
+    left.name.is_some() && left.name == right.name
+  }
+
+  /// Type of the text content of a `simpleContent`.
+  fn get_text_base(&self) -> Option<String> {
+    let simple_content = self.simple_content.as_ref()?;
+
+    simple_content
+      .extension
+      .as_ref()
+      .map(|extension| extension.base.clone())
+      .or_else(|| {
+        simple_content
+          .restriction
+          .as_ref()
+          .and_then(|restriction| restriction.base.clone())
+      })
+  }
+
+  fn get_restriction_attributes(context: &XsdContext, restriction: &Restriction) -> Vec<Attribute> {
+    let mut attributes = restriction.attributes.clone();
+    for attribute_group in &restriction.attribute_groups {
+      attributes.extend(attribute_group.get_attributes(context));
+    }
+    attributes
+  }
+
+  /// Attributes of the base type, overridden by the ones of the restriction with the same name,
+  /// the prohibited ones being removed.
+  fn restrict_attributes(base: Vec<Attribute>, restricted: Vec<Attribute>) -> Vec<Attribute> {
+    let same_name = Self::same_name;
+
+    let mut attributes: Vec<Attribute> = base
+      .into_iter()
+      .map(|attribute| {
+        restricted
+          .iter()
+          .find(|restricted| same_name(restricted, &attribute))
+          .cloned()
+          .unwrap_or(attribute)
+      })
+      .collect();
+
+    for attribute in restricted {
+      if !attributes.iter().any(|existing| same_name(existing, &attribute)) {
+        attributes.push(attribute);
+      }
+    }
+
+    attributes.retain(|attribute| attribute.required != Required::Prohibited);
+    attributes
+  }
+
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+
+    sub_type_name_prefix: &Option<&str>
+  ) -> TokenStream {
+    if self.sequence.is_some() || self.choice.is_some() {
+      self.get_content_field_implementation(context, prefix, struct_name, sub_type_name_prefix)
+    } else {
+      self
+        .simple_content
+        .as_ref()
+        .map(|simple_content| simple_content.get_field_implementation(context, prefix, struct_name))
+        .unwrap_or_else(TokenStream::new)
+    }
+  }
+
+  /// Fields of the sequence and choices, the choices being placed between the elements in the
+  /// schema order so that they are serialized in place.
+  fn get_content_field_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+
+    sub_type_name_prefix: &Option<&str>
+  ) -> TokenStream {
+    let choice_field = |index: usize, choice: &Choice| {
+      let enum_name = Choice::get_enum_name(&struct_name.to_string(), index);
+      choice.get_field_implementation(&enum_name, index)
+    };
+
+    let mut fields: TokenStream = self.choice.iter().map(|choice| choice_field(0, choice)).collect();
+
+    if let Some(sequence) = &self.sequence {
+      let mut elements = sequence.elements.iter();
+      let mut choices = sequence.choices.iter();
+      let mut groups = sequence.groups.iter();
+      let mut choice_index = self.choice.iter().count();
+
+      for particle in sequence.get_particles() {
+        match particle {
+          Particle::Element => fields.extend(
+            elements
+              .next()
+              .map(|element| element.get_field_implementation(context, prefix, sub_type_name_prefix)),
+          ),
+          Particle::Choice => {
+            fields.extend(choices.next().map(|choice| choice_field(choice_index, choice)));
+            choice_index += 1;
+          }
+          Particle::Group => fields.extend(groups.next().map(|group| {
+            let field_name = group.get_field_name();
+            let group_struct_name = group.get_struct_name(&struct_name.to_string());
+
+            quote!(
+              #[yaserde(flatten)]
+              pub #field_name: xml_schema::group::Groups<#group_struct_name>,
+            )
+          })),
+        }
+      }
+    }
+
+    fields
+  }
+
+  fn get_choices(&self) -> Vec<&Choice> {
+    self
+      .choice
+      .iter()
+      .chain(self.sequence.iter().flat_map(|sequence| sequence.choices.iter()))
+      .collect()
+  }
+
+  pub fn get_choices_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    self
+      .get_choices()
+      .iter()
+      .enumerate()
+      .map(|(index, choice)| {
+        let enum_name = Choice::get_enum_name(&struct_name.to_string(), index);
+        let enum_implementation = choice.get_enum_implementation(namespace_definition, prefix, context, &enum_name, sub_types_name_prefix);
+        let sub_types_implementation = choice.get_sub_types_implementation(context, namespace_definition, prefix, sub_types_name_prefix);
+
+        quote!(
+          #enum_implementation
+          #sub_types_implementation
+        )
+      })
+      .collect()
+  }
+
+  /// Structures holding an occurence of the repeated groups of the sequence.
+  pub fn get_groups_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    self
+      .sequence
+      .iter()
+      .flat_map(|sequence| sequence.groups.iter())
+      .map(|group| {
+        let group_struct_name = group.get_struct_name(&struct_name.to_string());
+        let group_type = ComplexType {
+          name: group_struct_name.to_string(),
+          sequence: group.sequence.clone(),
+          ..Default::default()
+        };
+        let implementation = group_type.implement(namespace_definition, prefix, context, sub_types_name_prefix);
+
+        let positions: TokenStream = group
+          .get_element_positions(context)
+          .iter()
+          .map(|(element_name, position, multiple)| quote!(#element_name => Some((#position, #multiple)),))
+          .collect();
+
+        quote!(
+          #implementation
+
+          impl xml_schema::group::Group for #group_struct_name {
+            fn position(element_name: &str) -> Option<(usize, bool)> {
+              match element_name {
+                #positions
+                _ => None,
+              }
+            }
+          }
+        )
+      })
+      .collect()
+  }
+
+  pub fn get_integrated_implementation(
+    &self,
+    parent_name: &str,
+
+    sub_type_name_prefix: &Option<&str>
+  ) -> TokenStream {
+    if self.simple_content.is_some() {
+      return quote!(String);
+    }
+
+    if self.sequence.is_some() || self.choice.is_some() {
+      let name = match *sub_type_name_prefix {
+        Some(name) => name.to_owned() + parent_name,
+        None => parent_name.to_owned(),
+      };
+
+      log::debug!("Get integrated implementation for {name} - {parent_name} - {sub_type_name_prefix}", name = self.name, sub_type_name_prefix = sub_type_name_prefix.unwrap_or("None"));
+
+      let list_wrapper = Ident::new(&name, Span::call_site());
+      return quote!(#list_wrapper);
+    }
+
+    quote!(String)
+  }
+}
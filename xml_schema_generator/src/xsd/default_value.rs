@@ -0,0 +1,433 @@
+//! Valid default values of the types restricted by facets. The deserialization of a required
+//! element or attribute starts from the `Default` value of its type, so it has to satisfy the
+//! facets of the type and of its bases.
+
+use crate::xsd::{
+  facet::{translate_pattern, Facet},
+  restriction::Restriction,
+  rust_types_mapping::RustTypesMapping,
+  simple_type::SimpleType,
+  XsdContext,
+};
+use regex::Regex;
+
+/// Bases followed at most, to stop on circular definitions.
+const MAX_DEPTH: usize = 16;
+
+/// First value of a restricted type satisfying its facets and the ones of its bases, among the
+/// default value of the built-in type, the bounds, and the strings of the minimum length filled
+/// with `a`, `A` or `0`. The dates, durations and binary data only have their inclusive bounds.
+/// There is none for the other bases, like the types substituted with `type_mapping`.
+pub fn get_default_value(context: &XsdContext, restriction: &Restriction) -> Option<String> {
+  let space = Space::new(context, restriction.base.as_ref()?, vec![restriction], 0)?;
+
+  space
+    .candidates()
+    .into_iter()
+    .find(|candidate| space.accepts(candidate))
+}
+
+/// Whether a `default` or `fixed` value is valid for the type `kind`, or for an inline simple
+/// type. `None` when the values of the type are not known.
+pub fn is_valid_value(
+  context: &XsdContext,
+  kind: Option<&str>,
+  simple_type: Option<&SimpleType>,
+  value: &str,
+) -> Option<bool> {
+  let space = match (kind, simple_type) {
+    (Some(kind), _) => Space::new(context, kind, vec![], 0)?,
+    (None, Some(simple_type)) => Space::of_simple_type(context, simple_type, vec![], 0)?,
+    (None, None) => return None,
+  };
+
+  Some(space.accepts(value))
+}
+
+/// Values of a type: the ones of its built-in base, list or enumeration, restricted by the facets
+/// of the derived types.
+struct Space<'a> {
+  kind: Kind<'a>,
+  restrictions: Vec<&'a Restriction>,
+}
+
+enum Kind<'a> {
+  Text,
+  Boolean,
+  Number,
+  Integer {
+    min: i128,
+    max: i128,
+  },
+  Enumeration(Vec<&'a str>),
+  List(Box<Space<'a>>),
+  /// Dates, durations and binary data, whose values are not compared when generating.
+  Other,
+}
+
+impl<'a> Space<'a> {
+  fn new(
+    context: &'a XsdContext,
+    kind: &str,
+    restrictions: Vec<&'a Restriction>,
+    depth: usize,
+  ) -> Option<Self> {
+    if let Some(name) = RustTypesMapping::get_builtin_name(context, kind) {
+      let kind = Kind::builtin(name)?;
+      return Some(Space { kind, restrictions });
+    }
+
+    if depth > MAX_DEPTH || context.get_type_mapping(kind).is_some() {
+      return None;
+    }
+
+    let simple_type = context.get_simple_type(kind)?;
+    Space::of_simple_type(context, simple_type, restrictions, depth + 1)
+  }
+
+  fn of_simple_type(
+    context: &'a XsdContext,
+    simple_type: &'a SimpleType,
+    mut restrictions: Vec<&'a Restriction>,
+    depth: usize,
+  ) -> Option<Self> {
+    if let Some(restriction) = &simple_type.restriction {
+      if restriction.is_enumeration() {
+        let values = restriction
+          .enumerations
+          .iter()
+          .map(|enumeration| enumeration.value.as_str())
+          .collect();
+
+        return Some(Space {
+          kind: Kind::Enumeration(values),
+          restrictions,
+        });
+      }
+
+      restrictions.push(restriction);
+      return Space::new(context, restriction.base.as_ref()?, restrictions, depth);
+    }
+
+    let list = simple_type.list.as_ref()?;
+    let item = match (&list.item_type, list.simple_types.first()) {
+      (Some(item_type), _) => Space::new(context, item_type, vec![], depth)?,
+      (None, Some(item_type)) => Space::of_simple_type(context, item_type, vec![], depth)?,
+      (None, None) => return None,
+    };
+
+    Some(Space {
+      kind: Kind::List(Box::new(item)),
+      restrictions,
+    })
+  }
+
+  fn candidates(&self) -> Vec<String> {
+    let min_length = self
+      .restrictions
+      .iter()
+      .flat_map(|restriction| [&restriction.length, &restriction.min_length])
+      .filter_map(|facet| facet.as_ref())
+      .map(|facet| facet.get_usize_value())
+      .max()
+      .unwrap_or_default();
+
+    let mut candidates: Vec<String> = match &self.kind {
+      Kind::Text => ["", "a", "A", "0"]
+        .iter()
+        .map(|filler| filler.repeat(min_length.max(1)))
+        .collect(),
+      Kind::Boolean => vec!["false".to_string()],
+      Kind::Number | Kind::Integer { .. } => vec!["0".to_string()],
+      Kind::Other => vec![],
+      Kind::Enumeration(values) => values.iter().map(|value| value.to_string()).collect(),
+      Kind::List(item) => {
+        let item = item.candidates().into_iter().find(|candidate| {
+          !candidate.is_empty()
+            && !candidate.contains(char::is_whitespace)
+            && item.accepts(candidate)
+        });
+
+        let mut candidates = vec!["".to_string()];
+        candidates.extend(item.map(|item| vec![item; min_length.max(1)].join(" ")));
+        candidates
+      }
+    };
+
+    for restriction in &self.restrictions {
+      candidates.extend(
+        [&restriction.min_inclusive, &restriction.max_inclusive]
+          .iter()
+          .filter_map(|facet| facet.as_ref())
+          .map(|facet| facet.value.trim().to_string()),
+      );
+
+      if let Kind::Integer { .. } = self.kind {
+        let bound = |facet: &Option<Facet>, offset: i128| {
+          let value = facet.as_ref()?.value.trim().parse::<i128>().ok()?;
+          Some((value + offset).to_string())
+        };
+        candidates.extend(bound(&restriction.min_exclusive, 1));
+        candidates.extend(bound(&restriction.max_exclusive, -1));
+      }
+    }
+
+    candidates
+  }
+
+  /// Whether a value is in the lexical space of the type, checked as the generated code does.
+  fn accepts(&self, value: &str) -> bool {
+    let length = match &self.kind {
+      Kind::Text => Some(value.chars().count()),
+      Kind::List(item) => {
+        if !value.split_whitespace().all(|value| item.accepts(value)) {
+          return false;
+        }
+        Some(value.split_whitespace().count())
+      }
+      kind => {
+        if !kind.accepts(value) {
+          return false;
+        }
+        None
+      }
+    };
+
+    // the inclusive bounds of the other types are their only candidates, and they satisfy the
+    // other bounds of a valid schema
+    let ordered = !matches!(self.kind, Kind::Other);
+
+    self
+      .restrictions
+      .iter()
+      .all(|restriction| satisfies(restriction, value, length, ordered))
+  }
+}
+
+impl Kind<'_> {
+  fn builtin(name: &str) -> Option<Self> {
+    let kind = match name {
+      "string" | "normalizedString" | "token" | "language" | "Name" | "NCName" | "NMTOKEN"
+      | "ID" | "IDREF" | "ENTITY" | "anyURI" => Kind::Text,
+      "boolean" => Kind::Boolean,
+      "float" | "double" | "decimal" => Kind::Number,
+      "integer" => Kind::integer(i128::MIN, i128::MAX),
+      "nonPositiveInteger" => Kind::integer(i128::MIN, 0),
+      "negativeInteger" => Kind::integer(i128::MIN, -1),
+      "nonNegativeInteger" => Kind::integer(0, i128::MAX),
+      "positiveInteger" => Kind::integer(1, i128::MAX),
+      "long" => Kind::integer(i64::MIN.into(), i64::MAX.into()),
+      "int" => Kind::integer(i32::MIN.into(), i32::MAX.into()),
+      "short" => Kind::integer(i16::MIN.into(), i16::MAX.into()),
+      "byte" => Kind::integer(i8::MIN.into(), i8::MAX.into()),
+      "unsignedLong" => Kind::integer(0, u64::MAX.into()),
+      "unsignedInt" => Kind::integer(0, u32::MAX.into()),
+      "unsignedShort" => Kind::integer(0, u16::MAX.into()),
+      "unsignedByte" => Kind::integer(0, u8::MAX.into()),
+      "dateTime" | "dateTimeStamp" | "date" | "time" | "duration" | "dayTimeDuration"
+      | "yearMonthDuration" | "gYear" | "gYearMonth" | "gMonthDay" | "gDay" | "gMonth"
+      | "hexBinary" | "base64Binary" => Kind::Other,
+      _ => return None,
+    };
+
+    Some(kind)
+  }
+
+  fn integer(min: i128, max: i128) -> Self {
+    Kind::Integer { min, max }
+  }
+
+  fn accepts(&self, value: &str) -> bool {
+    match self {
+      Kind::Text | Kind::List(_) | Kind::Other => true,
+      Kind::Boolean => value == "true" || value == "false",
+      Kind::Number => value.parse::<f64>().is_ok(),
+      Kind::Integer { min, max } => value
+        .parse::<i128>()
+        .map(|value| *min <= value && value <= *max)
+        .unwrap_or_default(),
+      Kind::Enumeration(values) => values.contains(&value),
+    }
+  }
+}
+
+/// Whether a value satisfies the facets of a restriction, its length being the number of
+/// characters or items for the strings and lists. The bounds are checked for the `ordered`
+/// numbers only.
+fn satisfies(restriction: &Restriction, value: &str, length: Option<usize>, ordered: bool) -> bool {
+  let check_length = |facet: &Option<Facet>, check: fn(usize, usize) -> bool| match facet {
+    Some(facet) => length
+      .map(|length| check(length, facet.get_usize_value()))
+      .unwrap_or_default(),
+    None => true,
+  };
+
+  if !check_length(&restriction.length, |length, facet| length == facet)
+    || !check_length(&restriction.min_length, |length, facet| length >= facet)
+    || !check_length(&restriction.max_length, |length, facet| length <= facet)
+  {
+    return false;
+  }
+
+  let mut regexes = restriction
+    .patterns
+    .iter()
+    .map(|pattern| Regex::new(&translate_pattern(&pattern.value)));
+  if !restriction.patterns.is_empty()
+    && !regexes.any(|regex| regex.map(|regex| regex.is_match(value)).unwrap_or_default())
+  {
+    return false;
+  }
+
+  let unsigned = value.trim_start_matches(['+', '-']);
+  let (integer_part, fraction_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+  let fraction_digits = fraction_part.trim_end_matches('0').len();
+  let total_digits = integer_part.trim_start_matches('0').len() + fraction_digits;
+  let check_digits = |facet: &Option<Facet>, count: usize| {
+    facet
+      .as_ref()
+      .map(|facet| count <= facet.get_usize_value())
+      .unwrap_or(true)
+  };
+  if !check_digits(&restriction.total_digits, total_digits)
+    || !check_digits(&restriction.fraction_digits, fraction_digits)
+  {
+    return false;
+  }
+
+  if !ordered {
+    return true;
+  }
+
+  let value = value.parse::<f64>().ok();
+  let check_bound = |facet: &Option<Facet>, check: fn(f64, f64) -> bool| match facet {
+    Some(facet) => match (value, facet.value.trim().parse::<f64>()) {
+      (Some(value), Ok(bound)) => check(value, bound),
+      _ => false,
+    },
+    None => true,
+  };
+
+  check_bound(&restriction.min_inclusive, |value, bound| value >= bound)
+    && check_bound(&restriction.max_inclusive, |value, bound| value <= bound)
+    && check_bound(&restriction.min_exclusive, |value, bound| value > bound)
+    && check_bound(&restriction.max_exclusive, |value, bound| value < bound)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::schema::Schema;
+  use std::collections::BTreeMap;
+
+  fn default_values(content: &str) -> Vec<(String, Option<String>)> {
+    let schema: Schema = yaserde::de::from_str(content).unwrap();
+
+    let simple_types: BTreeMap<(String, String), SimpleType> = schema
+      .simple_type
+      .iter()
+      .map(|simple_type| {
+        (
+          ("".to_string(), simple_type.name.clone()),
+          simple_type.clone(),
+        )
+      })
+      .collect();
+    let context = XsdContext::new(content)
+      .unwrap()
+      .with_simple_types(&simple_types);
+
+    schema
+      .simple_type
+      .iter()
+      .filter_map(|simple_type| {
+        let restriction = simple_type.restriction.as_ref()?;
+        let value = get_default_value(&context, restriction);
+        Some((simple_type.name.clone(), value))
+      })
+      .collect()
+  }
+
+  #[test]
+  fn restrictions() {
+    let values = default_values(
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="Code">
+          <xs:restriction base="xs:string">
+            <xs:length value="3"/>
+            <xs:pattern value="[A-Z][a-z]+"/>
+          </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="Country">
+          <xs:restriction base="xs:string">
+            <xs:length value="2"/>
+            <xs:pattern value="[A-Z]+"/>
+          </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="Volume">
+          <xs:restriction base="xs:int">
+            <xs:minExclusive value="10"/>
+            <xs:maxInclusive value="100"/>
+          </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="Gain">
+          <xs:restriction base="xs:double">
+            <xs:minInclusive value="-12.5"/>
+            <xs:maxExclusive value="-1"/>
+          </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="Birth">
+          <xs:restriction base="xs:date">
+            <xs:minInclusive value="1900-01-01"/>
+          </xs:restriction>
+        </xs:simpleType>
+      </xs:schema>"#,
+    );
+
+    assert_eq!(
+      values,
+      vec![
+        ("Code".to_string(), None),
+        ("Country".to_string(), Some("AA".to_string())),
+        ("Volume".to_string(), Some("100".to_string())),
+        ("Gain".to_string(), Some("-12.5".to_string())),
+        ("Birth".to_string(), Some("1900-01-01".to_string())),
+      ]
+    );
+  }
+
+  #[test]
+  fn derived_restrictions() {
+    let values = default_values(
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="Short">
+          <xs:restriction base="xs:string">
+            <xs:maxLength value="4"/>
+          </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="Tag">
+          <xs:restriction base="Short">
+            <xs:minLength value="2"/>
+          </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="Tags">
+          <xs:list itemType="Tag"/>
+        </xs:simpleType>
+        <xs:simpleType name="SomeTags">
+          <xs:restriction base="Tags">
+            <xs:minLength value="2"/>
+          </xs:restriction>
+        </xs:simpleType>
+      </xs:schema>"#,
+    );
+
+    assert_eq!(
+      values,
+      vec![
+        ("Short".to_string(), Some("".to_string())),
+        ("Tag".to_string(), Some("aa".to_string())),
+        ("SomeTags".to_string(), Some("aa aa".to_string())),
+      ]
+    );
+  }
+}
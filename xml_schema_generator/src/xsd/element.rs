@@ -0,0 +1,552 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  annotation::Annotation, complex_type::ComplexType, default_value, max_occurences::MaxOccurences,
+  rust_types_mapping::RustTypesMapping, simple_type::SimpleType, xsd_context::{GlobalElement, LocationScope},
+  Implementation, XsdContext,
+};
+use heck::{CamelCase, SnakeCase};
+use proc_macro2::{Span, TokenStream};
+use syn::{parse2, parse_quote, Ident};
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Element {
+  #[yaserde(attribute)]
+  pub name: String,
+  #[yaserde(rename = "type", attribute)]
+  pub kind: Option<String>,
+  #[yaserde(rename = "ref", attribute)]
+  pub refers: Option<String>,
+  #[yaserde(rename = "minOccurs", attribute)]
+  pub min_occurences: Option<u64>,
+  #[yaserde(rename = "maxOccurs", attribute)]
+  pub max_occurences: Option<MaxOccurences>,
+  #[yaserde(attribute)]
+  pub default: Option<String>,
+  #[yaserde(attribute)]
+  pub fixed: Option<String>,
+  #[yaserde(attribute)]
+  pub nillable: bool,
+  #[yaserde(rename = "complexType")]
+  pub complex_type: Option<ComplexType>,
+  #[yaserde(rename = "simpleType")]
+  pub simple_type: Option<SimpleType>,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
+}
+
+impl Implementation for Element {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+
+    name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    let _scope = self.enter(context);
+    let struct_name_string = (match *name_prefix {
+      Some(name) => name,
+      None => "",
+    }).to_owned() + &self.name;
+
+    let struct_name = Ident::new(
+      &struct_name_string.replace('.', "_").to_camel_case(),
+      Span::call_site(),
+    );
+
+    let (fields, extra_structs) = if let Some(kind) = &self.kind {
+      let subtype_mode = if RustTypesMapping::is_xs_string(context, kind) {
+        quote!(text)
+      } else {
+        quote!(flatten)
+      };
+
+      let extern_type = RustTypesMapping::get(context, kind);
+      let extern_type = if RustTypesMapping::is_xml_schema_type(context, kind)
+        || context.get_type_mapping(kind).is_some()
+      {
+        extern_type
+      } else {
+        quote!(types::#extern_type)
+      };
+
+      (
+        quote!(
+          #[yaserde(#subtype_mode)]
+          pub content: #extern_type,
+        ),
+        quote!(),
+      )
+    } else {
+      let fields_definition = self
+        .complex_type
+        .iter()
+        .map(|complex_type| complex_type.get_field_implementation(context, prefix, &struct_name, name_prefix))
+        .collect();
+
+      let choices_implementation: TokenStream = self
+        .complex_type
+        .iter()
+        .map(|complex_type| complex_type.get_choices_implementation(namespace_definition, prefix, context, &struct_name, name_prefix))
+        .collect();
+
+      let groups_implementation: TokenStream = self
+        .complex_type
+        .iter()
+        .map(|complex_type| complex_type.get_groups_implementation(namespace_definition, prefix, context, &struct_name, name_prefix))
+        .collect();
+
+      let simple_content_implementation: TokenStream = self
+        .complex_type
+        .iter()
+        .flat_map(|complex_type| complex_type.simple_content.iter())
+        .map(|simple_content| simple_content.get_restriction_implementation(namespace_definition, prefix, context, &struct_name))
+        .collect();
+
+      let values_implementation: TokenStream = self
+        .complex_type
+        .iter()
+        .flat_map(|complex_type| complex_type.sequence.iter())
+        .flat_map(|sequence| sequence.elements.iter())
+        .map(|element| element.get_value_implementation(context, name_prefix))
+        .collect();
+
+      (fields_definition, quote!(#choices_implementation #groups_implementation #simple_content_implementation #values_implementation))
+    };
+
+    let docs = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.implement(namespace_definition, prefix, context, name_prefix))
+      .unwrap_or_else(TokenStream::new);
+
+    quote! {
+      #docs
+      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub struct #struct_name {
+        #fields
+      }
+
+      #extra_structs
+    }
+  }
+}
+
+impl Element {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    for name in self.kind.iter_mut().chain(self.refers.iter_mut()) {
+      *name = map(name);
+    }
+    if let Some(complex_type) = self.complex_type.as_mut() {
+      complex_type.map_qualified_names(map);
+    }
+    if let Some(simple_type) = self.simple_type.as_mut() {
+      simple_type.map_qualified_names(map);
+    }
+  }
+
+  pub fn expand_groups(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    if let Some(complex_type) = self.complex_type.as_mut() {
+      complex_type.expand_groups(context, references);
+    }
+  }
+
+  pub fn get_subtypes_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    if self.complex_type.is_none() {
+      return quote!();
+    }
+
+    self.implement(namespace_definition, prefix, context, sub_types_name_prefix)
+  }
+
+  /// Global element referenced with the `ref` attribute.
+  fn get_reference<'a>(&self, context: &'a XsdContext) -> Option<&'a GlobalElement> {
+    let reference = self.refers.as_ref()?;
+    let global_element = context.get_global_element(reference);
+
+    if global_element.is_none() {
+      errors::report(
+        Error::new(format!("Unable to resolve the element reference {reference:?}"))
+          .at(context.locate(&["ref"], reference))
+          .with_hint("declare the element at the schema level, or import the schema declaring it"),
+      );
+    }
+
+    global_element
+  }
+
+  /// Scope of the locations of the errors of the element, see `XsdContext::enter`.
+  fn enter<'a>(&self, context: &'a XsdContext) -> LocationScope<'a> {
+    match &self.refers {
+      Some(reference) => context.enter("element", "ref", reference),
+      None => context.enter("element", "name", &self.name),
+    }
+  }
+
+  /// Name of the element, the referenced one for an element reference.
+  pub fn get_name(&self, context: &XsdContext) -> String {
+    match self.get_reference(context) {
+      Some(global_element) => global_element.name.to_string(),
+      None => self.name.to_string(),
+    }
+  }
+
+  /// Name of the field of the element, `kind` for a `type` element.
+  pub fn get_field_name(&self, context: &XsdContext) -> String {
+    let element_name = self.get_name(context);
+
+    if element_name.to_lowercase() == "type" {
+      "kind".to_string()
+    } else {
+      element_name.to_snake_case()
+    }
+  }
+
+  /// Declaration of this element when it is defined at the schema level.
+  pub fn get_global_element(&self, context: &XsdContext) -> GlobalElement {
+    let (rust_type, generated, builtin) = if self.complex_type.is_some() {
+      let struct_name = Ident::new(
+        &self.name.replace('.', "_").to_camel_case(),
+        Span::call_site(),
+      );
+      (parse_quote!(super::#struct_name), true, false)
+    } else if self.simple_type.is_some() || self.kind.is_some() {
+      let builtin = self.has_text_content(context);
+      let mapped = self
+        .kind
+        .as_ref()
+        .is_some_and(|kind| context.get_type_mapping(kind).is_some());
+      let rust_type = self.get_type_implementation(context, &None);
+
+      (parse2(rust_type).unwrap_or_else(|error| {
+        errors::report(
+          Error::new(format!("Invalid type of the element {:?}: {}", self.name, error))
+            .at(context.locate(&["name"], &self.name)),
+        );
+        parse_quote!(String)
+      }), !builtin && !mapped, builtin)
+    } else {
+      (parse_quote!(String), false, true)
+    };
+
+    GlobalElement {
+      name: self.name.to_string(),
+      rust_type,
+      generated,
+      builtin,
+    }
+  }
+
+  pub fn get_type_implementation(
+    &self,
+    context: &XsdContext,
+
+    sub_type_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    if let Some(global_element) = self.get_reference(context) {
+      let rust_type = &global_element.rust_type;
+      quote!(#rust_type)
+    } else if let Some(complex_type) = &self.complex_type {
+      complex_type.get_integrated_implementation(&self.name, sub_type_name_prefix)
+    } else if let Some(simple_type) = &self.simple_type {
+      simple_type.get_type_implementation(context, &Some(self.name.to_owned()))
+    } else if let Some(kind) = &self.kind {
+      RustTypesMapping::get(context, kind)
+    } else {
+      errors::report(
+        Error::new(format!("Missing type for the element {:?}", self.name))
+          .at(context.locate(&["name", "ref"], &self.name))
+          .with_hint("add a `type` attribute, or declare the type inside the element"),
+      );
+      quote!(String)
+    }
+  }
+
+  /// The element content is a single text value, without attributes nor children.
+  pub fn has_text_content(&self, context: &XsdContext) -> bool {
+    if let Some(global_element) = self.get_reference(context) {
+      return global_element.builtin;
+    }
+
+    let kind = self.kind.as_ref().or_else(|| {
+      self
+        .simple_type
+        .as_ref()
+        .and_then(|simple_type| simple_type.restriction.as_ref())
+        .and_then(|restriction| restriction.base.as_ref())
+    });
+
+    kind
+      .map(|kind| RustTypesMapping::is_xml_schema_type(context, kind))
+      .unwrap_or_default()
+  }
+
+  /// Type of the content, constrained by the `fixed`, `default` and `nillable` declarations.
+  fn get_content_type_implementation(
+    &self,
+    context: &XsdContext,
+    rust_type: TokenStream,
+
+    sub_type_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    let text_content = self.has_text_content(context);
+
+    let rust_type = match (&self.fixed, &self.default) {
+      (Some(_), _) if text_content => {
+        let fixed_value = self.get_value_name("fixed", sub_type_name_prefix);
+        quote!(xml_schema::fixed::Fixed<#rust_type, #fixed_value>)
+      }
+      (None, Some(_)) if text_content => {
+        let default_value = self.get_value_name("default", sub_type_name_prefix);
+        quote!(xml_schema::default::Defaulted<#rust_type, #default_value>)
+      }
+      _ => rust_type,
+    };
+
+    if !self.nillable {
+      rust_type
+    } else if text_content {
+      quote!(xml_schema::nillable::NillableText<#rust_type>)
+    } else {
+      quote!(xml_schema::nillable::Nillable<#rust_type>)
+    }
+  }
+
+  /// Marker type of the `fixed` or `default` value of the element, declared next to the
+  /// structure holding the element.
+  pub fn get_value_implementation(
+    &self,
+    context: &XsdContext,
+
+    sub_type_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    if !self.has_text_content(context) {
+      return quote!();
+    }
+
+    let (kind, value, value_trait) = match (&self.fixed, &self.default) {
+      (Some(fixed), _) => ("fixed", fixed, quote!(xml_schema::fixed::FixedValue)),
+      (None, Some(default)) => ("default", default, quote!(xml_schema::default::DefaultValue)),
+      (None, None) => return quote!(),
+    };
+
+    let valid = default_value::is_valid_value(
+      context,
+      self.kind.as_deref(),
+      self.simple_type.as_ref(),
+      value,
+    );
+    if valid == Some(false) {
+      errors::report(
+        Error::new(format!(
+          "Invalid {kind} value {value:?} for the element {:?}",
+          self.name
+        ))
+        .at(context.locate(&[kind], value))
+        .with_hint("use a value of the type of the element"),
+      );
+      return quote!();
+    }
+
+    let value_name = self.get_value_name(kind, sub_type_name_prefix);
+
+    quote!(
+      #[derive(Clone, Debug, Default, PartialEq)]
+      pub struct #value_name;
+
+      impl #value_trait for #value_name {
+        const VALUE: &'static str = #value;
+      }
+    )
+  }
+
+  fn get_value_name(&self, kind: &str, sub_type_name_prefix: &Option<&str>) -> Ident {
+    let name = format!(
+      "{}_{}_{}",
+      sub_type_name_prefix.unwrap_or_default(),
+      self.name.replace('.', "_"),
+      kind
+    );
+
+    Ident::new(&name.to_camel_case(), Span::call_site())
+  }
+
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+
+    sub_type_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    let _scope = self.enter(context);
+    let element_name = self.get_name(context);
+    if element_name.is_empty() {
+      return quote!();
+    }
+
+    let multiple = self.max_occurences.is_some()
+      && self.max_occurences != Some(MaxOccurences::Number { value: 1 });
+
+    let name = self.get_field_name(context);
+
+    log::info!("Generate element {:?}", name);
+
+    let name = if multiple { format!("{name}s") } else { name };
+
+    let attribute_name = Ident::new(&name, Span::call_site());
+    let yaserde_rename = &element_name;
+
+    let rust_type = self.get_type_implementation(context, sub_type_name_prefix);
+    let rust_type = self.get_content_type_implementation(context, rust_type, sub_type_name_prefix);
+
+    let rust_type = if multiple {
+      quote!(Vec<#rust_type>)
+    } else {
+      rust_type
+    };
+
+    let rust_type = if !multiple && self.min_occurences == Some(0) {
+      quote!(Option<#rust_type>)
+    } else {
+      rust_type
+    };
+
+    let prefix_attribute = if let Some(prefix) = prefix {
+      quote!(, prefix=#prefix)
+    } else {
+      quote!()
+    };
+
+    quote! {
+      #[yaserde(rename=#yaserde_rename #prefix_attribute)]
+      pub #attribute_name: #rust_type,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  static DERIVES: &str =
+    "#[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]";
+
+  static DOCS: &str = r#"#[doc = "Loudness measured in Decibels"]"#;
+
+  #[test]
+  fn extern_type() {
+    let element = Element {
+      name: "volume".to_string(),
+      kind: Some("books:volume-type".to_string()),
+      refers: None,
+      min_occurences: None,
+      max_occurences: None,
+      default: None,
+      fixed: None,
+      nillable: false,
+      complex_type: None,
+      simple_type: None,
+      annotation: Some(Annotation {
+        id: None,
+        attributes: vec![],
+        documentation: vec!["Loudness measured in Decibels".to_string()],
+      }),
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = element.implement(&quote!(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(&format!(
+      r#"
+        {DOCS}
+        {DERIVES}
+        pub struct Volume {{
+          #[yaserde(flatten)]
+          pub content: types::VolumeType,
+        }}"#
+    ))
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn nillable_default_field() {
+    let element = Element {
+      name: "unit".to_string(),
+      kind: Some("xs:string".to_string()),
+      default: Some("cm".to_string()),
+      nillable: true,
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = element.get_field_implementation(&context, &None, &Some("Measure"));
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(rename="unit")]
+        pub unit: xml_schema::nillable::NillableText<xml_schema::default::Defaulted<String, MeasureUnitDefault> > ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn xs_string_element() {
+    let element = Element {
+      name: "volume".to_string(),
+      kind: Some("xs:string".to_string()),
+      refers: None,
+      min_occurences: None,
+      max_occurences: None,
+      default: None,
+      fixed: None,
+      nillable: false,
+      complex_type: None,
+      simple_type: None,
+      annotation: Some(Annotation {
+        id: None,
+        attributes: vec![],
+        documentation: vec!["Loudness measured in Decibels".to_string()],
+      }),
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = element.implement(&quote!(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(&format!(
+      r#"
+        {DOCS}
+        {DERIVES}
+        pub struct Volume {{
+          #[yaserde(text)]
+          pub content: String,
+        }}"#
+    ))
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
@@ -0,0 +1,86 @@
+use crate::xsd::{annotation::Annotation, Implementation, XsdContext};
+use heck::CamelCase;
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "enumeration",
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Enumeration {
+  #[yaserde(attribute)]
+  pub value: String,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
+}
+
+impl Enumeration {
+  pub fn get_variant_name(&self) -> Ident {
+    let name = self.value.replace('.', "_").to_camel_case();
+
+    // `Self` is the only keyword in camel case, and it can not be a raw identifier
+    let name = if name.starts_with(|c: char| c.is_ascii_alphabetic()) && name != "Self" {
+      name
+    } else {
+      format!("Value{name}")
+    };
+
+    Ident::new(&name, Span::call_site())
+  }
+
+  pub fn get_variant_implementation(
+    &self,
+    variant_name: &Ident,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> TokenStream {
+    let value = &self.value;
+
+    let docs = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.implement(namespace_definition, prefix, context, &None))
+      .unwrap_or_default();
+
+    quote!(
+      #docs
+      #[yaserde(rename = #value)]
+      #variant_name,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn variant_names() {
+    let enumeration = Enumeration {
+      value: "full-range".to_string(),
+      annotation: None,
+    };
+    assert_eq!(enumeration.get_variant_name().to_string(), "FullRange");
+
+    let enumeration = Enumeration {
+      value: "1.0".to_string(),
+      annotation: None,
+    };
+    assert_eq!(enumeration.get_variant_name().to_string(), "Value10");
+
+    let enumeration = Enumeration {
+      value: "".to_string(),
+      annotation: None,
+    };
+    assert_eq!(enumeration.get_variant_name().to_string(), "Value");
+
+    let enumeration = Enumeration {
+      value: "self".to_string(),
+      annotation: None,
+    };
+    assert_eq!(enumeration.get_variant_name().to_string(), "ValueSelf");
+  }
+}
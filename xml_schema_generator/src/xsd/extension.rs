@@ -0,0 +1,259 @@
+use crate::xsd::{
+  attribute::Attribute, attribute_group::AttributeGroup, choice::Choice, group::Group,
+  rust_types_mapping::RustTypesMapping, sequence::Sequence, Implementation, XsdContext,
+};
+use proc_macro2::TokenStream;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  root = "extension",
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Extension {
+  #[yaserde(attribute)]
+  pub base: String,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+  #[yaserde(rename = "sequence")]
+  pub sequences: Vec<Sequence>,
+  #[yaserde(rename = "choice")]
+  pub choices: Vec<Choice>,
+  #[yaserde(rename = "group")]
+  pub groups: Vec<Group>,
+}
+
+impl Implementation for Extension {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    let content = self.get_content_implementation(context);
+
+    let attributes: TokenStream = self
+      .attributes
+      .iter()
+      .map(|attribute| attribute.implement(namespace_definition, prefix, context, sub_types_name_prefix))
+      .collect();
+
+    let attribute_groups: TokenStream = self
+      .attribute_groups
+      .iter()
+      .map(|attribute_group| attribute_group.implement(namespace_definition, prefix, context, sub_types_name_prefix))
+      .collect();
+
+    quote!(
+      #content
+      #attributes
+      #attribute_groups
+    )
+  }
+}
+
+impl Extension {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    self.base = map(&self.base);
+    for attribute in self.attributes.iter_mut() {
+      attribute.map_qualified_names(map);
+    }
+    for attribute_group in self.attribute_groups.iter_mut() {
+      attribute_group.map_qualified_names(map);
+    }
+    for sequence in self.sequences.iter_mut() {
+      sequence.map_qualified_names(map);
+    }
+    for choice in self.choices.iter_mut() {
+      choice.map_qualified_names(map);
+    }
+    for group in self.groups.iter_mut() {
+      group.map_qualified_names(map);
+    }
+  }
+
+  /// Replace the group references by the content of the groups, a referenced group being
+  /// expanded as a sequence of its own.
+  pub fn expand_groups(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    for group in std::mem::take(&mut self.groups) {
+      let mut sequence = Sequence::default();
+      sequence.push_group(group);
+      self.sequences.push(sequence);
+    }
+
+    for sequence in self.sequences.iter_mut() {
+      sequence.expand_groups(context, references);
+    }
+    for choice in self.choices.iter_mut() {
+      choice.expand_groups(context, references);
+    }
+  }
+
+  /// Field of the text content of a simple base type. The types without YaSerDe implementation,
+  /// like the numbers, are read and written as text with the `Text` wrapper.
+  fn get_content_implementation(&self, context: &XsdContext) -> TokenStream {
+    let rust_type = RustTypesMapping::get(context, &self.base);
+
+    if format!("{rust_type}") == "String" {
+      return quote!(
+        #[yaserde(text)]
+        pub content: #rust_type,
+      );
+    }
+    if context.get_complex_type(&self.base).is_some() {
+      return quote!(pub content: #rust_type,);
+    }
+
+    let is_primitive = RustTypesMapping::get_builtin_name(context, &self.base).is_some()
+      && !format!("{rust_type}").contains("::");
+    let rust_type = if is_primitive {
+      quote!(xml_schema::text_content::Text<#rust_type>)
+    } else {
+      rust_type
+    };
+
+    quote!(
+      #[yaserde(flatten)]
+      pub content: #rust_type,
+    )
+  }
+
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    _prefix: &Option<String>,
+  ) -> TokenStream {
+    let rust_type = RustTypesMapping::get(context, &self.base);
+    quote!(pub content : #rust_type)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  #[test]
+  fn extension() {
+    let st = Extension {
+      base: "xs:string".to_string(),
+      attributes: vec![],
+      attribute_groups: vec![],
+      sequences: vec![],
+      choices: vec![],
+      groups: vec![],
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = st.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(text)]
+        pub content: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn extension_with_attributes() {
+    use crate::xsd::attribute::Required;
+
+    let st = Extension {
+      base: "xs:string".to_string(),
+      attributes: vec![
+        Attribute {
+          name: Some("attribute_1".to_string()),
+          kind: Some("xs:string".to_string()),
+          reference: None,
+          required: Required::Required,
+          simple_type: None,
+          ..Default::default()
+        },
+        Attribute {
+          name: Some("attribute_2".to_string()),
+          kind: Some("xs:boolean".to_string()),
+          reference: None,
+          required: Required::Optional,
+          simple_type: None,
+          ..Default::default()
+        },
+      ],
+      attribute_groups: vec![],
+      sequences: vec![],
+      choices: vec![],
+      groups: vec![],
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = st.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(text)]
+        pub content: String,
+        #[yaserde(attribute)]
+        pub attribute_1: String,
+        #[yaserde(attribute)]
+        pub attribute_2: Option<bool> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn extension_of_number() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let st = Extension {
+      base: "xs:decimal".to_string(),
+      ..Default::default()
+    };
+
+    let implementation = st.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub content: xml_schema::decimal::generated::Decimal,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    let st = Extension {
+      base: "xs:unsignedInt".to_string(),
+      ..Default::default()
+    };
+
+    let implementation = st.implement(&TokenStream::new(), &None, &context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub content: xml_schema::text_content::Text<u32> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
@@ -0,0 +1,125 @@
+use crate::errors::{self, Error};
+use crate::xsd::XsdContext;
+use regex::Regex;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Facet {
+  #[yaserde(attribute)]
+  pub value: String,
+}
+
+impl Facet {
+  pub fn get_usize_value(&self) -> usize {
+    self.value.trim().parse().unwrap_or_else(|_| {
+      errors::report(
+        Error::new(format!("Invalid facet value {:?}", self.value))
+          .with_hint("expected a positive integer"),
+      );
+      0
+    })
+  }
+
+  /// Regular expression of a `pattern` facet, translated into the `regex` crate syntax. An
+  /// invalid pattern is reported when generated.
+  pub fn get_pattern(&self, context: &XsdContext) -> Option<String> {
+    let pattern = translate_pattern(&self.value);
+
+    match Regex::new(&pattern) {
+      Ok(_) => Some(pattern),
+      Err(error) => {
+        errors::report(
+          Error::new(format!("Invalid pattern {:?}: {}", self.value, error))
+            .at(context.locate(&["value"], &self.value))
+            .with_hint("use the regular expression syntax of XML Schema"),
+        );
+        None
+      }
+    }
+  }
+}
+
+/// Convert an XML Schema regular expression into the `regex` crate syntax.
+///
+/// XML Schema patterns are implicitly anchored, `^` and `$` are plain characters, and they support
+/// some specific escapes (`\i`, `\c`) and character class subtractions (`[a-z-[aeiou]]`).
+pub fn translate_pattern(pattern: &str) -> String {
+  let mut translated = String::new();
+  let mut characters = pattern.chars().peekable();
+  let mut class_depth = 0;
+
+  while let Some(character) = characters.next() {
+    match character {
+      '\\' => match characters.next() {
+        Some('i') => translated.push_str(r"[_:A-Za-z]"),
+        Some('I') => translated.push_str(r"[^_:A-Za-z]"),
+        Some('c') => translated.push_str(r"[\-._:A-Za-z0-9]"),
+        Some('C') => translated.push_str(r"[^\-._:A-Za-z0-9]"),
+        Some(escaped) => {
+          translated.push('\\');
+          translated.push(escaped);
+        }
+        None => translated.push_str(r"\\"),
+      },
+      '[' => {
+        class_depth += 1;
+        translated.push('[');
+      }
+      ']' => {
+        class_depth -= 1;
+        translated.push(']');
+      }
+      '-' if class_depth > 0 && characters.peek() == Some(&'[') => {
+        translated.push_str("--");
+      }
+      '^' | '$' if class_depth == 0 => {
+        translated.push('\\');
+        translated.push(character);
+      }
+      character => translated.push(character),
+    }
+  }
+
+  format!("^(?:{translated})$")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn matches(pattern: &str, value: &str) -> bool {
+    Regex::new(&translate_pattern(pattern))
+      .unwrap()
+      .is_match(value)
+  }
+
+  #[test]
+  fn patterns() {
+    assert!(matches("[A-Z]{2}[0-9]{2}", "AB12"));
+    assert!(!matches("[A-Z]{2}[0-9]{2}", "xAB12"));
+    assert!(!matches("[a-z-[aeiou]]", "a"));
+    assert!(matches("[a-z-[aeiou]]", "b"));
+    assert!(matches(r"\i\c*", "_name-1"));
+    assert!(!matches(r"\i\c*", "1name"));
+    assert!(matches("^[0-9]+$", "^12$"));
+    assert!(!matches("^[0-9]+$", "12"));
+    assert!(matches("[^0-9]", "a"));
+  }
+
+  #[test]
+  fn invalid_pattern() {
+    errors::take();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+    let facet = Facet {
+      value: "[a-z".to_string(),
+    };
+
+    assert_eq!(facet.get_pattern(&context), None);
+    assert!(errors::take()[0]
+      .message
+      .starts_with(r#"Invalid pattern "[a-z": "#));
+  }
+}
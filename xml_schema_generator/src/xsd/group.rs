@@ -0,0 +1,357 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  annotation::Annotation,
+  choice::Choice,
+  element::Element,
+  max_occurences::MaxOccurences,
+  sequence::{Particle, Sequence},
+  XsdContext,
+};
+use heck::{CamelCase, SnakeCase};
+use proc_macro2::Span;
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "group",
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Group {
+  #[yaserde(attribute)]
+  pub name: Option<String>,
+  #[yaserde(rename = "ref", attribute)]
+  pub reference: String,
+  #[yaserde(rename = "minOccurs", attribute)]
+  pub min_occurences: Option<u64>,
+  #[yaserde(rename = "maxOccurs", attribute)]
+  pub max_occurences: Option<MaxOccurences>,
+  pub sequence: Option<Sequence>,
+  pub choice: Option<Choice>,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
+}
+
+impl Group {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    if !self.reference.is_empty() {
+      self.reference = map(&self.reference);
+    }
+    if let Some(sequence) = self.sequence.as_mut() {
+      sequence.map_qualified_names(map);
+    }
+    if let Some(choice) = self.choice.as_mut() {
+      choice.map_qualified_names(map);
+    }
+  }
+
+  /// Content of the referenced group, with the occurences of the reference applied.
+  ///
+  /// The group references of the content are expanded, `references` is the chain of groups
+  /// being expanded to detect circular definitions.
+  pub fn expand(&self, context: &XsdContext, references: &mut Vec<String>) -> Group {
+    if references.contains(&self.reference) {
+      errors::report(
+        Error::new(format!(
+          "Circular reference of group {:?} through {:?}",
+          self.reference, references
+        ))
+        .at(context.locate(&["ref"], &self.reference)),
+      );
+      return Group::default();
+    }
+
+    let mut group = match context.get_group(&self.reference) {
+      Some(group) => group.clone(),
+      None => {
+        errors::report(
+          Error::new(format!("Unknown group {:?}", self.reference))
+            .at(context.locate(&["ref"], &self.reference))
+            .with_hint("declare the group, or import the schema declaring it"),
+        );
+        return Group::default();
+      }
+    };
+
+    references.push(self.reference.clone());
+
+    group.reference = self.reference.clone();
+    group.min_occurences = self.min_occurences;
+    group.max_occurences = self.max_occurences.clone();
+
+    if let Some(sequence) = group.sequence.as_mut() {
+      sequence.expand_groups(context, references);
+    }
+
+    // the particles of a repeated group stay together in the occurences of the group
+    if !group.is_repeated() {
+      if let Some(sequence) = group.sequence.as_mut() {
+        for element in sequence.elements.iter_mut() {
+          self.apply_occurences(&mut element.min_occurences, &mut element.max_occurences);
+        }
+        for choice in sequence.choices.iter_mut() {
+          self.apply_occurences(&mut choice.min_occurences, &mut choice.max_occurences);
+        }
+        for nested_group in sequence.groups.iter_mut() {
+          self.apply_occurences(
+            &mut nested_group.min_occurences,
+            &mut nested_group.max_occurences,
+          );
+        }
+      }
+    }
+
+    if let Some(choice) = group.choice.as_mut() {
+      choice.expand_groups(context, references);
+      self.apply_occurences(&mut choice.min_occurences, &mut choice.max_occurences);
+    }
+
+    references.pop();
+
+    group
+  }
+
+  /// The expanded group is repeated and has several particles, it is generated as a list of
+  /// structures to keep the particles of each occurence together.
+  pub fn is_repeated(&self) -> bool {
+    is_multiple(&self.max_occurences)
+      && self
+        .sequence
+        .as_ref()
+        .is_some_and(|sequence| sequence.get_particles().len() > 1)
+  }
+
+  /// Name of the structure generated for a repeated group referenced in `struct_name`.
+  pub fn get_struct_name(&self, struct_name: &str) -> Ident {
+    let name = self.name.clone().unwrap_or_default();
+
+    Ident::new(
+      &format!("{struct_name}{}", name.replace('.', "_").to_camel_case()),
+      Span::call_site(),
+    )
+  }
+
+  /// Name of the field holding the occurences of a repeated group.
+  pub fn get_field_name(&self) -> Ident {
+    let name = self.name.clone().unwrap_or_default();
+
+    Ident::new(
+      &format!("{}s", name.replace('.', "_").to_snake_case()),
+      Span::call_site(),
+    )
+  }
+
+  /// Elements of the group, used as alternatives when the group is referenced in a choice.
+  pub fn get_elements(&self) -> Vec<Element> {
+    let mut elements = vec![];
+
+    if let Some(sequence) = &self.sequence {
+      elements.extend(sequence.elements.iter().cloned());
+      for choice in &sequence.choices {
+        elements.extend(choice.elements.iter().cloned());
+      }
+      for group in &sequence.groups {
+        elements.extend(group.get_elements());
+      }
+    }
+    if let Some(choice) = &self.choice {
+      elements.extend(choice.elements.iter().cloned());
+    }
+
+    elements
+  }
+
+  /// Positions of the elements in the particles of the group, the alternatives of a choice
+  /// sharing the position of the choice, with whether their particle can be repeated.
+  pub fn get_element_positions(&self, context: &XsdContext) -> Vec<(String, usize, bool)> {
+    let sequence = match &self.sequence {
+      Some(sequence) => sequence,
+      None => return vec![],
+    };
+
+    let mut elements = sequence.elements.iter();
+    let mut choices = sequence.choices.iter();
+    let mut groups = sequence.groups.iter();
+    let mut positions = vec![];
+
+    for (position, particle) in sequence.get_particles().into_iter().enumerate() {
+      match particle {
+        Particle::Element => {
+          if let Some(element) = elements.next() {
+            let multiple = is_multiple(&element.max_occurences);
+            positions.push((element.get_name(context), position, multiple));
+          }
+        }
+        Particle::Choice => {
+          if let Some(choice) = choices.next() {
+            for element in &choice.elements {
+              positions.push((element.get_name(context), position, choice.is_multiple()));
+            }
+          }
+        }
+        Particle::Group => {
+          if let Some(group) = groups.next() {
+            for element in group.get_elements() {
+              positions.push((element.get_name(context), position, true));
+            }
+          }
+        }
+      }
+    }
+
+    positions
+  }
+
+  fn apply_occurences(
+    &self,
+    min_occurences: &mut Option<u64>,
+    max_occurences: &mut Option<MaxOccurences>,
+  ) {
+    if self.min_occurences == Some(0) {
+      *min_occurences = Some(0);
+    }
+
+    if is_multiple(&self.max_occurences) && !is_multiple(max_occurences) {
+      *max_occurences = self.max_occurences.clone();
+    }
+  }
+}
+
+fn is_multiple(max_occurences: &Option<MaxOccurences>) -> bool {
+  max_occurences.is_some() && *max_occurences != Some(MaxOccurences::Number { value: 1 })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::BTreeMap;
+
+  fn context_with_groups(groups: Vec<Group>) -> XsdContext {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let groups: BTreeMap<(String, String), Group> = groups
+      .into_iter()
+      .map(|group| (("".to_string(), group.name.clone().unwrap()), group))
+      .collect();
+
+    context.with_groups(&groups)
+  }
+
+  fn element(name: &str) -> Element {
+    Element {
+      name: name.to_string(),
+      kind: Some("xs:string".to_string()),
+      ..Default::default()
+    }
+  }
+
+  fn reference(name: &str) -> Group {
+    Group {
+      reference: name.to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn nested_references() {
+    let context = context_with_groups(vec![
+      Group {
+        name: Some("Block".to_string()),
+        sequence: Some(Sequence {
+          elements: vec![element("Title")],
+          groups: vec![reference("Inline")],
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+      Group {
+        name: Some("Inline".to_string()),
+        choice: Some(Choice {
+          elements: vec![element("Bold"), element("Italic")],
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+    ]);
+
+    let group = Group {
+      min_occurences: Some(0),
+      ..reference("Block")
+    };
+
+    let expanded = group.expand(&context, &mut vec![]);
+    assert!(!expanded.is_repeated());
+
+    let sequence = expanded.sequence.unwrap();
+
+    assert!(sequence.groups.is_empty());
+    assert_eq!(sequence.elements.len(), 1);
+    assert_eq!(sequence.elements[0].min_occurences, Some(0));
+    assert_eq!(sequence.choices.len(), 1);
+    assert_eq!(sequence.choices[0].elements.len(), 2);
+    assert_eq!(sequence.choices[0].min_occurences, Some(0));
+  }
+
+  #[test]
+  fn repeated_reference() {
+    let context = context_with_groups(vec![Group {
+      name: Some("Identity".to_string()),
+      sequence: Some(Sequence {
+        elements: vec![element("FirstName"), element("LastName")],
+        ..Default::default()
+      }),
+      ..Default::default()
+    }]);
+
+    let group = Group {
+      max_occurences: Some(MaxOccurences::Unbounded),
+      ..reference("Identity")
+    };
+
+    let expanded = group.expand(&context, &mut vec![]);
+
+    assert!(expanded.is_repeated());
+    assert_eq!(
+      expanded.sequence.as_ref().unwrap().elements[0].max_occurences,
+      None
+    );
+    assert_eq!(expanded.get_struct_name("Team").to_string(), "TeamIdentity");
+    assert_eq!(expanded.get_field_name().to_string(), "identitys");
+    assert_eq!(
+      expanded.get_element_positions(&context),
+      vec![
+        ("FirstName".to_string(), 0, false),
+        ("LastName".to_string(), 1, false)
+      ]
+    );
+
+    let mut sequence = Sequence::default();
+    sequence.push_element(element("Name"));
+    sequence.push_group(group);
+    sequence.expand_groups(&context, &mut vec![]);
+
+    assert_eq!(sequence.elements.len(), 1);
+    assert_eq!(sequence.groups.len(), 1);
+  }
+
+  #[test]
+  fn circular_references() {
+    let context = context_with_groups(vec![Group {
+      name: Some("Recursive".to_string()),
+      sequence: Some(Sequence {
+        groups: vec![reference("Recursive")],
+        ..Default::default()
+      }),
+      ..Default::default()
+    }]);
+
+    errors::take();
+    reference("Recursive").expand(&context, &mut vec![]);
+
+    assert!(errors::take()[0]
+      .message
+      .starts_with("Circular reference of group"));
+  }
+}
@@ -0,0 +1,210 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  rust_types_mapping::RustTypesMapping, simple_type::SimpleType, text_content, Implementation,
+  XsdContext,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct List {
+  #[yaserde(rename = "itemType", attribute)]
+  pub item_type: Option<String>,
+  /// Anonymous item type, declared in place of the `itemType` attribute.
+  #[yaserde(rename = "simpleType")]
+  pub simple_types: Vec<SimpleType>,
+}
+
+impl Implementation for List {
+  fn implement_childs(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let (item_type, inline_type) = match (&self.item_type, self.simple_types.first()) {
+      (Some(item_type), _) => (RustTypesMapping::get(context, item_type), None),
+      (None, Some(simple_type)) => {
+        let item_type_name = Ident::new(&format!("{struct_name}Item"), Span::call_site());
+
+        let simple_type = SimpleType {
+          name: item_type_name.to_string(),
+          ..simple_type.clone()
+        };
+
+        (
+          quote!(#item_type_name),
+          Some(simple_type.implement(namespace_definition, prefix, context, &None)),
+        )
+      }
+      (None, None) => {
+        errors::report(
+          Error::new(format!("Missing item type for list {struct_name}"))
+            .at(context.locate(&["name"], &struct_name.to_string()))
+            .with_hint("add an `itemType` attribute, or declare the item type inside the list"),
+        );
+        return quote!();
+      }
+    };
+
+    let yaserde_implementation = text_content::implement_yaserde(struct_name);
+
+    quote!(
+      #inline_type
+
+      #[derive(Clone, Debug, Default, PartialEq)]
+      pub struct #struct_name {
+        pub items: Vec<#item_type>
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          value
+            .parse::<xml_schema::list::List<#item_type>>()
+            .map(|items| #struct_name { items: items.into_inner() })
+            .map_err(|e| format!("{}: {}", stringify!(#struct_name), e))
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+              write!(formatter, " ")?;
+            }
+            write!(formatter, "{}", item)?;
+          }
+          Ok(())
+        }
+      }
+
+      impl xml_schema::facets::Length for #struct_name {
+        fn length(&self) -> usize {
+          self.items.len()
+        }
+      }
+
+      #yaserde_implementation
+    )
+  }
+}
+
+impl List {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    if let Some(item_type) = self.item_type.as_mut() {
+      *item_type = map(item_type);
+    }
+    for simple_type in self.simple_types.iter_mut() {
+      simple_type.map_qualified_names(map);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  #[test]
+  fn basic_list() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let list_type = List {
+      item_type: Some("xs:string".to_string()),
+      simple_types: vec![],
+    };
+
+    let struct_name = Ident::new("Parent", Span::call_site());
+
+    let implementation =
+      list_type.implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct Parent {
+          pub items: Vec<String>
+        }
+
+        impl std::str::FromStr for Parent {
+          type Err = String;
+
+          fn from_str(value: &str) -> Result<Self, Self::Err> {
+            value
+              .parse:: <xml_schema::list::List<String>>()
+              .map(|items| Parent { items: items.into_inner() })
+              .map_err(|e| format!("{}: {}", stringify!(Parent), e))
+          }
+        }
+
+        impl std::fmt::Display for Parent {
+          fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            for (index, item) in self.items.iter().enumerate() {
+              if index > 0 {
+                write!(formatter, " ")? ;
+              }
+              write!(formatter, "{}", item)? ;
+            }
+            Ok(())
+          }
+        }
+
+        impl xml_schema::facets::Length for Parent {
+          fn length(&self) -> usize {
+            self.items.len()
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    let yaserde_implementation = text_content::implement_yaserde(&struct_name);
+
+    assert_eq!(
+      implementation.to_string(),
+      format!("{expected} {yaserde_implementation}")
+    );
+  }
+
+  #[test]
+  fn inline_item_type() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let list_type = List {
+      item_type: None,
+      simple_types: vec![SimpleType::default()],
+    };
+
+    let struct_name = Ident::new("Sizes", Span::call_site());
+
+    let implementation = list_type
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(implementation.contains("pub struct SizesItem"));
+    assert!(implementation.contains("pub items : Vec < SizesItem >"));
+  }
+
+  #[test]
+  fn missing_item_type() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Sizes", Span::call_site());
+    errors::take();
+    List::default().implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    assert_eq!(
+      errors::take()[0].message,
+      "Missing item type for list Sizes"
+    );
+  }
+}
@@ -0,0 +1,691 @@
+mod annotation;
+mod attribute;
+mod attribute_group;
+mod choice;
+mod complex_content;
+mod complex_type;
+mod default_value;
+mod element;
+mod enumeration;
+mod extension;
+mod facet;
+mod group;
+mod import;
+mod include;
+mod list;
+mod max_occurences;
+mod qualification;
+mod restriction;
+mod rust_types_mapping;
+mod schema;
+mod sequence;
+mod simple_content;
+mod simple_type;
+mod text_content;
+mod union;
+mod xsd_context;
+mod xsd_set;
+
+use crate::errors::Error;
+use crate::loader::Loader;
+use heck::SnakeCase;
+use proc_macro2::{Ident, TokenStream};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use syn::Visibility;
+use xsd_context::XsdContext;
+use yaserde::de::from_str;
+
+pub use xsd_set::XsdSet;
+
+trait Implementation {
+  fn implement(
+    &self,
+    _namespace_definition: &TokenStream,
+    _prefix: &Option<String>,
+    _context: &XsdContext,
+    _sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    unimplemented!()
+  }
+
+  fn implement_childs(
+    &self,
+    _namespace_definition: &TokenStream,
+    _prefix: &Option<String>,
+    _context: &XsdContext,
+    _struct_name: &Ident,
+  ) -> TokenStream {
+    unimplemented!()
+  }
+}
+
+/// Schema loaded with its includes and imports, to generate the Rust types.
+#[derive(Clone, Debug)]
+pub struct Xsd {
+  name: String,
+  vis: Visibility,
+  context: XsdContext,
+  schema: schema::Schema,
+  imports: Vec<ImportedXsd>,
+  sources: BTreeSet<String>,
+}
+
+/// Schema loaded from an `xs:import`, generated in its own module.
+#[derive(Clone, Debug)]
+struct ImportedXsd {
+  target_prefix: Option<String>,
+  xsd: Xsd,
+  /// Generated once in a sibling module, for all the schemas importing it.
+  shared: bool,
+}
+
+/// Schemas already loaded, to load each of them once.
+#[derive(Debug, Default)]
+struct Loaded {
+  sources: BTreeSet<String>,
+  /// Locations of the schemas being loaded, to stop at circular imports.
+  loading: BTreeSet<String>,
+  /// Imported schemas by target namespace, each one generated once in a sibling module of the
+  /// schemas importing it.
+  shared: BTreeMap<String, ImportedXsd>,
+}
+
+impl Xsd {
+  pub fn new(
+    name: String,
+    vis: Visibility,
+    content: &str,
+    module_namespace_mappings: &BTreeMap<String, String>,
+  ) -> Result<Self, String> {
+    let context = XsdContext::new(content)?;
+    let context = context.with_module_namespace_mappings(module_namespace_mappings);
+    let schema: schema::Schema = from_str(content)?;
+
+    Ok(Xsd {
+      name,
+      vis,
+      context,
+      schema,
+      imports: vec![],
+      sources: BTreeSet::new(),
+    })
+  }
+
+  pub fn new_from_file(
+    name: String,
+    vis: Visibility,
+    source: &str,
+    module_namespace_mappings: &BTreeMap<String, String>,
+    loader: &Loader,
+  ) -> Result<Self, String> {
+    let mut loaded = Loaded::default();
+    let xsd = Xsd::load(
+      name,
+      vis,
+      source,
+      module_namespace_mappings,
+      loader,
+      &mut loaded,
+    )?;
+
+    Ok(xsd.with_loaded_imports(loaded))
+  }
+
+  /// Schema given as text, named `location` in the errors, with its includes and imports resolved
+  /// relatively to `directory`.
+  pub fn new_inline(
+    name: String,
+    vis: Visibility,
+    content: &str,
+    location: &str,
+    directory: &str,
+    module_namespace_mappings: &BTreeMap<String, String>,
+    loader: &Loader,
+  ) -> Result<Self, String> {
+    let mut xsd = Xsd::new(name, vis, content, module_namespace_mappings)
+      .map_err(|e| format!("{location}: {e}"))?;
+    xsd.context = xsd.context.with_location(location);
+
+    let mut loaded = Loaded::default();
+    let source = Path::new(directory).join(location);
+    let xsd = xsd.load_dependencies(
+      &source.to_string_lossy(),
+      module_namespace_mappings,
+      loader,
+      &mut loaded,
+    )?;
+
+    Ok(xsd.with_loaded_imports(loaded))
+  }
+
+  /// Generate all the schemas imported, directly or not, in the modules of this one, each
+  /// namespace once: the imported schemas importing it re-export its module.
+  fn with_loaded_imports(self, loaded: Loaded) -> Self {
+    let imports = loaded
+      .shared
+      .into_values()
+      .map(|import| ImportedXsd {
+        shared: false,
+        ..import
+      })
+      .collect();
+
+    Xsd {
+      imports,
+      sources: loaded.sources,
+      ..self
+    }
+  }
+
+  /// Paths and URLs of the schemas loaded, with the included and imported ones.
+  pub fn sources(&self) -> &BTreeSet<String> {
+    &self.sources
+  }
+
+  pub fn with_serialize_default_attributes(mut self, serialize_default_attributes: bool) -> Self {
+    self.context = self
+      .context
+      .with_serialize_default_attributes(serialize_default_attributes);
+
+    self.imports = self
+      .imports
+      .into_iter()
+      .map(|import| ImportedXsd {
+        xsd: import
+          .xsd
+          .with_serialize_default_attributes(serialize_default_attributes),
+        ..import
+      })
+      .collect();
+
+    self
+  }
+
+  /// Substitute Rust types to XML Schema types, named with the prefixes of this schema.
+  pub fn with_type_mappings(self, type_mappings: &BTreeMap<String, String>) -> Result<Self, Error> {
+    let type_mappings = self.context.resolve_type_mappings(type_mappings)?;
+    Ok(self.with_resolved_type_mappings(&type_mappings))
+  }
+
+  fn with_resolved_type_mappings(
+    mut self,
+    type_mappings: &BTreeMap<(String, String), String>,
+  ) -> Self {
+    self.context = self.context.with_type_mappings(type_mappings);
+
+    self.imports = self
+      .imports
+      .into_iter()
+      .map(|import| ImportedXsd {
+        xsd: import.xsd.with_resolved_type_mappings(type_mappings),
+        ..import
+      })
+      .collect();
+
+    self
+  }
+
+  /// Load a schema with its includes, and generate the imported schemas in sub-modules.
+  fn load(
+    name: String,
+    vis: Visibility,
+    source: &str,
+    module_namespace_mappings: &BTreeMap<String, String>,
+    loader: &Loader,
+    loaded: &mut Loaded,
+  ) -> Result<Self, String> {
+    let source = &loader.resolve(source);
+    loaded.sources.insert(source.to_string());
+
+    let content = loader.load(source)?;
+    let mut xsd = Xsd::new(name, vis, &content, module_namespace_mappings)
+      .map_err(|e| format!("{source}: {e}"))?;
+    xsd.context = xsd.context.with_location(source);
+
+    xsd.load_dependencies(source, module_namespace_mappings, loader, loaded)
+  }
+
+  /// Load the includes and imports of a schema, relatively to its `source`.
+  fn load_dependencies(
+    self,
+    source: &str,
+    module_namespace_mappings: &BTreeMap<String, String>,
+    loader: &Loader,
+    loaded: &mut Loaded,
+  ) -> Result<Self, String> {
+    let mut xsd = self;
+    xsd.schema.resolve_import_locations(source);
+    xsd.load_includes(source, loader, loaded, &mut BTreeSet::new())?;
+
+    loaded.loading.insert(source.to_string());
+
+    let mut module_namespace_mappings = module_namespace_mappings.clone();
+
+    for import in xsd.schema.imports.clone() {
+      let namespace = match import.namespace {
+        Some(namespace) => namespace,
+        None => continue,
+      };
+
+      if module_namespace_mappings.contains_key(&namespace) {
+        continue;
+      }
+
+      let location = match import
+        .schema_location
+        .or_else(|| loader.resolve_namespace(&namespace))
+      {
+        Some(location) => loader.resolve(&location),
+        None => continue,
+      };
+
+      if let Some(imported) = loaded.shared.get(&namespace) {
+        module_namespace_mappings.insert(namespace, format!("{}::types", imported.xsd.name));
+        xsd.imports.push(imported.clone());
+        continue;
+      }
+
+      if loaded.loading.contains(&location) {
+        continue;
+      }
+
+      let module_names: BTreeSet<&str> = xsd
+        .imports
+        .iter()
+        .chain(loaded.shared.values())
+        .map(|import| import.xsd.name.as_str())
+        .collect();
+      let module_name = get_unique_module_name(&location, &module_names);
+
+      log::info!(
+        "Import {} from {} in module {}",
+        namespace,
+        location,
+        module_name
+      );
+
+      let imported = Xsd::load(
+        module_name.clone(),
+        xsd.vis.clone(),
+        &location,
+        &module_namespace_mappings,
+        loader,
+        loaded,
+      )?;
+
+      let target_prefix = imported.schema.target_namespace.as_ref().map(|_| {
+        xsd
+          .context
+          .get_prefix(&namespace)
+          .unwrap_or_else(|| module_name.clone())
+      });
+
+      let imported = ImportedXsd {
+        target_prefix,
+        xsd: imported,
+        shared: true,
+      };
+      loaded.shared.insert(namespace.clone(), imported.clone());
+
+      module_namespace_mappings.insert(namespace, format!("{module_name}::types"));
+      xsd.imports.push(imported);
+    }
+
+    loaded.loading.remove(source);
+
+    xsd.context = xsd
+      .context
+      .with_module_namespace_mappings(&module_namespace_mappings);
+    xsd.register_global_definitions();
+    xsd.schema.expand_groups(&xsd.context);
+    xsd.schema.expand_derivations(&xsd.context);
+
+    Ok(xsd)
+  }
+
+  /// Register the global elements, attributes, attribute groups, groups, complex and simple types
+  /// of the schema and of the imported ones, to resolve references.
+  fn register_global_definitions(&mut self) {
+    let mut global_elements = BTreeMap::new();
+    let mut attributes = BTreeMap::new();
+    let mut attribute_groups = BTreeMap::new();
+    let mut groups = BTreeMap::new();
+    let mut complex_types = BTreeMap::new();
+    let mut simple_types = BTreeMap::new();
+
+    // the definitions of the imports use their own prefixes, resolved with their context
+    let context = &mut self.context;
+    for import in &self.imports {
+      let import_context = &import.xsd.context;
+      let mut map = |name: &str| context.import_name(import_context, &import.xsd.name, name);
+
+      for (key, global_element) in import_context.global_elements() {
+        global_elements.insert(key.clone(), global_element.in_module(&import.xsd.name));
+      }
+      for (key, attribute) in import_context.attributes() {
+        let mut attribute = attribute.clone();
+        attribute.map_qualified_names(&mut map);
+        attributes.insert(key.clone(), attribute);
+      }
+      for (key, attribute_group) in import_context.attribute_groups() {
+        let mut attribute_group = attribute_group.clone();
+        attribute_group.map_qualified_names(&mut map);
+        attribute_groups.insert(key.clone(), attribute_group);
+      }
+      for (key, group) in import_context.groups() {
+        let mut group = group.clone();
+        group.map_qualified_names(&mut map);
+        groups.insert(key.clone(), group);
+      }
+      for (key, complex_type) in import_context.complex_types() {
+        let mut complex_type = complex_type.clone();
+        complex_type.map_qualified_names(&mut map);
+        complex_types.insert(key.clone(), complex_type);
+      }
+      for (key, simple_type) in import_context.simple_types() {
+        let mut simple_type = simple_type.clone();
+        simple_type.map_qualified_names(&mut map);
+        simple_types.insert(key.clone(), simple_type);
+      }
+    }
+
+    let namespace = self.schema.target_namespace.clone().unwrap_or_default();
+    for element in &self.schema.elements {
+      if element.name.is_empty() {
+        continue;
+      }
+
+      global_elements.insert(
+        (namespace.clone(), element.name.clone()),
+        element.get_global_element(&self.context),
+      );
+    }
+
+    for attribute in &self.schema.attributes {
+      if let Some(name) = &attribute.name {
+        attributes.insert((namespace.clone(), name.clone()), attribute.clone());
+      }
+    }
+
+    for attribute_group in &self.schema.attribute_group {
+      if let Some(name) = &attribute_group.name {
+        attribute_groups.insert((namespace.clone(), name.clone()), attribute_group.clone());
+      }
+    }
+
+    for group in &self.schema.groups {
+      if let Some(name) = &group.name {
+        groups.insert((namespace.clone(), name.clone()), group.clone());
+      }
+    }
+
+    for complex_type in &self.schema.complex_type {
+      complex_types.insert(
+        (namespace.clone(), complex_type.name.clone()),
+        complex_type.clone(),
+      );
+    }
+
+    for simple_type in &self.schema.simple_type {
+      simple_types.insert(
+        (namespace.clone(), simple_type.name.clone()),
+        simple_type.clone(),
+      );
+    }
+
+    self.context = self
+      .context
+      .clone()
+      .with_global_elements(&global_elements)
+      .with_attributes(&attributes)
+      .with_attribute_groups(&attribute_groups)
+      .with_groups(&groups)
+      .with_complex_types(&complex_types)
+      .with_simple_types(&simple_types);
+  }
+
+  /// Merge the definitions of the included schemas, recursively. `included` are the locations
+  /// already merged in the module, a schema included by several modules is merged in each one.
+  fn load_includes(
+    &mut self,
+    source: &str,
+    loader: &Loader,
+    loaded: &mut Loaded,
+    included: &mut BTreeSet<String>,
+  ) -> Result<(), String> {
+    let includes = std::mem::take(&mut self.schema.includes);
+    included.insert(source.to_string());
+
+    for include in includes {
+      let location = loader.resolve(&resolve_location(source, &include.schema_location));
+      if !included.insert(location.clone()) {
+        continue;
+      }
+      loaded.sources.insert(location.clone());
+
+      log::info!("Include {}", location);
+      let content = loader.load(&location)?;
+      let mut schema = Xsd::new(
+        self.name.clone(),
+        self.vis.clone(),
+        &content,
+        &BTreeMap::new(),
+      )
+      .map_err(|e| format!("{location}: {e}"))?;
+      schema.schema.resolve_import_locations(&location);
+      schema.load_includes(&location, loader, loaded, included)?;
+
+      self.schema.merge(schema.schema);
+    }
+
+    Ok(())
+  }
+
+  pub fn implement(&self, target_prefix: &Option<String>) -> TokenStream {
+    let schema = self.implement_schema(target_prefix);
+
+    let mod_name = format_ident!("{}", self.name.to_snake_case());
+    let vis = &self.vis;
+
+    quote! {
+        mod #mod_name {
+            #schema
+        }
+
+        #vis use #mod_name::*;
+    }
+  }
+
+  /// Generated module only, without re-exporting its content.
+  pub fn implement_module(&self, target_prefix: &Option<String>) -> TokenStream {
+    let schema = self.implement_schema(target_prefix);
+
+    let mod_name = format_ident!("{}", self.name.to_snake_case());
+    let vis = &self.vis;
+
+    quote! {
+        #vis mod #mod_name {
+            #schema
+        }
+    }
+  }
+
+  /// Generated content of the module, with the imported schemas in sub-modules.
+  pub fn implement_schema(&self, target_prefix: &Option<String>) -> TokenStream {
+    let imports: TokenStream = self
+      .imports
+      .iter()
+      .map(|import| {
+        let mod_name = format_ident!("{}", import.xsd.name);
+        if import.shared {
+          return quote!(
+            pub use super::super::#mod_name;
+          );
+        }
+
+        let schema = import.xsd.implement_schema(&import.target_prefix);
+
+        quote!(
+          pub mod #mod_name {
+            #schema
+          }
+        )
+      })
+      .collect();
+
+    self.schema.implement_with_imports(
+      &TokenStream::new(),
+      target_prefix,
+      &self.context,
+      &None,
+      &imports,
+    )
+  }
+}
+
+/// Resolve a `schemaLocation` relatively to the schema referencing it.
+pub(crate) fn resolve_location(source: &str, location: &str) -> String {
+  if location.starts_with("http://")
+    || location.starts_with("https://")
+    || Path::new(location).is_absolute()
+  {
+    return location.to_string();
+  }
+
+  if source.starts_with("http://") || source.starts_with("https://") {
+    return match source.rfind('/') {
+      Some(position) => format!("{}/{}", &source[..position], location),
+      None => location.to_string(),
+    };
+  }
+
+  Path::new(source)
+    .parent()
+    .map(|directory| directory.join(location))
+    .unwrap_or_else(|| PathBuf::from(location))
+    .to_string_lossy()
+    .to_string()
+}
+
+/// Name of the module generated for an imported schema, based on its file name.
+pub(crate) fn get_module_name(location: &str) -> String {
+  let file_name = location.rsplit('/').next().unwrap_or(location);
+  let stem = file_name.split('.').next().unwrap_or(file_name);
+
+  stem.to_snake_case()
+}
+
+/// Name of the module generated for an imported schema, different from the `taken` ones: the
+/// name of the directory of the schema is added, then a number.
+pub(crate) fn get_unique_module_name(location: &str, taken: &BTreeSet<&str>) -> String {
+  let module_name = get_module_name(location);
+  if !taken.contains(module_name.as_str()) {
+    return module_name;
+  }
+
+  let directory = location
+    .rsplit('/')
+    .nth(1)
+    .unwrap_or_default()
+    .to_snake_case();
+  let module_name = if directory.is_empty() {
+    module_name
+  } else {
+    format!("{directory}_{module_name}")
+  };
+
+  let mut unique_name = module_name.clone();
+  let mut index = 1;
+  while taken.contains(unique_name.as_str()) {
+    index += 1;
+    unique_name = format!("{module_name}_{index}");
+  }
+  unique_name
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::errors;
+
+  #[test]
+  fn resolve_locations() {
+    assert_eq!(
+      resolve_location("schemas/main.xsd", "common.xsd"),
+      "schemas/common.xsd"
+    );
+    assert_eq!(resolve_location("main.xsd", "common.xsd"), "common.xsd");
+    assert_eq!(
+      resolve_location("http://example.com/schemas/main.xsd", "common.xsd"),
+      "http://example.com/schemas/common.xsd"
+    );
+    assert_eq!(
+      resolve_location("schemas/main.xsd", "http://example.com/common.xsd"),
+      "http://example.com/common.xsd"
+    );
+  }
+
+  #[test]
+  fn inline_schema_location() {
+    let xsd = Xsd::new_inline(
+      "Library".to_string(),
+      syn::parse_quote!(pub),
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:import namespace="http://example.com/common" schemaLocation="import_common.xsd"/>
+        <xs:complexType name="Book">
+          <xs:attribute ref="lang"/>
+        </xs:complexType>
+      </xs:schema>"#,
+      "<inline schema of Library>",
+      "../xml_schema/tests",
+      &BTreeMap::new(),
+      &Loader::new(),
+    )
+    .unwrap();
+
+    assert_eq!(xsd.imports[0].xsd.name, "import_common");
+
+    errors::take();
+    xsd.implement(&None);
+
+    let errors = errors::take();
+    let location = errors[0].location.as_ref().unwrap();
+    assert_eq!(location.file, "<inline schema of Library>");
+    assert_eq!(location.position, Some((4, 11)));
+  }
+
+  #[test]
+  fn diamond_import() {
+    let xsd = Xsd::new_from_file(
+      "Company".to_string(),
+      syn::parse_quote!(pub),
+      "../xml_schema/tests/import_diamond.xsd",
+      &BTreeMap::new(),
+      &Loader::new(),
+    )
+    .unwrap();
+
+    let module_names: Vec<&str> = xsd
+      .imports
+      .iter()
+      .map(|import| import.xsd.name.as_str())
+      .collect();
+    assert_eq!(module_names, vec!["import_common", "import_contact"]);
+
+    let implementation = xsd.implement(&None).to_string();
+    assert_eq!(implementation.matches("pub mod import_common").count(), 1);
+    assert!(implementation.contains("pub use super :: super :: import_common ;"));
+  }
+
+  #[test]
+  fn module_names() {
+    assert_eq!(get_module_name("schemas/common-types.xsd"), "common_types");
+    assert_eq!(get_module_name("http://example.com/xml.xsd"), "xml");
+
+    let taken = BTreeSet::from(["common", "b_common"]);
+    assert_eq!(get_unique_module_name("a/types.xsd", &taken), "types");
+    assert_eq!(get_unique_module_name("a/common.xsd", &taken), "a_common");
+    assert_eq!(get_unique_module_name("b/common.xsd", &taken), "b_common_2");
+  }
+}
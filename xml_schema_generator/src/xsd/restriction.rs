@@ -0,0 +1,775 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  attribute::Attribute, attribute_group::AttributeGroup, choice::Choice, default_value,
+  enumeration::Enumeration, facet::Facet, group::Group, rust_types_mapping::RustTypesMapping,
+  sequence::Sequence, text_content, Implementation, XsdContext,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+use std::collections::BTreeSet;
+
+/// Built-in types derived from `xs:decimal`, which accept the `totalDigits` and `fractionDigits`
+/// facets.
+const DECIMAL_TYPES: &[&str] = &[
+  "decimal",
+  "integer",
+  "nonPositiveInteger",
+  "negativeInteger",
+  "long",
+  "int",
+  "short",
+  "byte",
+  "nonNegativeInteger",
+  "positiveInteger",
+  "unsignedLong",
+  "unsignedInt",
+  "unsignedShort",
+  "unsignedByte",
+];
+
+/// Bases followed at most, to stop on circular definitions.
+const MAX_DEPTH: usize = 16;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Restriction {
+  #[yaserde(rename = "base", attribute)]
+  pub base: Option<String>,
+  #[yaserde(rename = "enumeration")]
+  pub enumerations: Vec<Enumeration>,
+  #[yaserde(rename = "minInclusive")]
+  pub min_inclusive: Option<Facet>,
+  #[yaserde(rename = "maxInclusive")]
+  pub max_inclusive: Option<Facet>,
+  #[yaserde(rename = "minExclusive")]
+  pub min_exclusive: Option<Facet>,
+  #[yaserde(rename = "maxExclusive")]
+  pub max_exclusive: Option<Facet>,
+  #[yaserde(rename = "length")]
+  pub length: Option<Facet>,
+  #[yaserde(rename = "minLength")]
+  pub min_length: Option<Facet>,
+  #[yaserde(rename = "maxLength")]
+  pub max_length: Option<Facet>,
+  #[yaserde(rename = "pattern")]
+  pub patterns: Vec<Facet>,
+  #[yaserde(rename = "totalDigits")]
+  pub total_digits: Option<Facet>,
+  #[yaserde(rename = "fractionDigits")]
+  pub fraction_digits: Option<Facet>,
+  // content of a `complexContent` or `simpleContent` restriction
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+  pub sequence: Option<Sequence>,
+  pub choice: Option<Choice>,
+  pub group: Option<Group>,
+}
+
+impl Implementation for Restriction {
+  fn implement_childs(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    if self.is_enumeration() {
+      self.implement_enumeration(namespace_definition, prefix, context, struct_name)
+    } else {
+      self.implement_facets(context, struct_name)
+    }
+  }
+}
+
+impl Restriction {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    if let Some(base) = self.base.as_mut() {
+      *base = map(base);
+    }
+    for attribute in self.attributes.iter_mut() {
+      attribute.map_qualified_names(map);
+    }
+    for attribute_group in self.attribute_groups.iter_mut() {
+      attribute_group.map_qualified_names(map);
+    }
+    if let Some(sequence) = self.sequence.as_mut() {
+      sequence.map_qualified_names(map);
+    }
+    if let Some(choice) = self.choice.as_mut() {
+      choice.map_qualified_names(map);
+    }
+    if let Some(group) = self.group.as_mut() {
+      group.map_qualified_names(map);
+    }
+  }
+
+  pub fn get_type_implementation(
+    &self,
+    context: &XsdContext,
+    _prefix: &Option<String>,
+  ) -> TokenStream {
+    if let Some(base) = &self.base {
+      RustTypesMapping::get(context, base)
+    } else {
+      errors::report(
+        Error::new("Missing base for restriction")
+          .at(context.locate_schema())
+          .with_hint("add a `base` attribute to the `xs:restriction`"),
+      );
+      quote!(String)
+    }
+  }
+
+  /// Replace the group references of a `complexContent` restriction by the content of the groups.
+  pub fn expand_groups(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    if let Some(group) = self.group.take() {
+      self
+        .sequence
+        .get_or_insert_with(Sequence::default)
+        .push_group(group);
+    }
+
+    if let Some(sequence) = self.sequence.as_mut() {
+      sequence.expand_groups(context, references);
+    }
+    if let Some(choice) = self.choice.as_mut() {
+      choice.expand_groups(context, references);
+    }
+  }
+
+  /// Take the facets of the restricted base which are not redefined by this restriction, for a
+  /// restriction of a type which is itself a restriction.
+  pub fn inherit_facets(&mut self, base: &Restriction) {
+    let inherit = |facet: &mut Option<Facet>, base_facet: &Option<Facet>| {
+      if facet.is_none() {
+        *facet = base_facet.clone();
+      }
+    };
+
+    inherit(&mut self.min_inclusive, &base.min_inclusive);
+    inherit(&mut self.max_inclusive, &base.max_inclusive);
+    inherit(&mut self.min_exclusive, &base.min_exclusive);
+    inherit(&mut self.max_exclusive, &base.max_exclusive);
+    inherit(&mut self.length, &base.length);
+    inherit(&mut self.min_length, &base.min_length);
+    inherit(&mut self.max_length, &base.max_length);
+    inherit(&mut self.total_digits, &base.total_digits);
+    inherit(&mut self.fraction_digits, &base.fraction_digits);
+
+    if self.patterns.is_empty() {
+      self.patterns = base.patterns.clone();
+    }
+    if self.enumerations.is_empty() {
+      self.enumerations = base.enumerations.clone();
+    }
+  }
+
+  /// Whether the base is `xs:decimal` or derived from it, following the restrictions of the
+  /// simple types of the schema. The bases which are not known are accepted.
+  fn is_decimal(&self, context: &XsdContext) -> bool {
+    let mut base = self.base.as_deref();
+
+    for _ in 0..MAX_DEPTH {
+      let kind = match base {
+        Some(kind) => kind,
+        None => return true,
+      };
+
+      if let Some(name) = RustTypesMapping::get_builtin_name(context, kind) {
+        return DECIMAL_TYPES.contains(&name);
+      }
+
+      base = context
+        .get_simple_type(kind)
+        .and_then(|simple_type| simple_type.restriction.as_ref())
+        .and_then(|restriction| restriction.base.as_deref());
+    }
+
+    true
+  }
+
+  pub fn is_enumeration(&self) -> bool {
+    !self.enumerations.is_empty()
+  }
+
+  pub fn has_facets(&self) -> bool {
+    self.min_inclusive.is_some()
+      || self.max_inclusive.is_some()
+      || self.min_exclusive.is_some()
+      || self.max_exclusive.is_some()
+      || self.length.is_some()
+      || self.min_length.is_some()
+      || self.max_length.is_some()
+      || !self.patterns.is_empty()
+      || self.total_digits.is_some()
+      || self.fraction_digits.is_some()
+  }
+
+  fn implement_enumeration(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let mut variant_names = BTreeSet::new();
+
+    let variants: Vec<(Ident, &String, TokenStream)> = self
+      .enumerations
+      .iter()
+      .enumerate()
+      .map(|(index, enumeration)| {
+        let mut variant_name = enumeration.get_variant_name();
+        let mut suffix = index;
+        while !variant_names.insert(variant_name.to_string()) {
+          variant_name = Ident::new(
+            &format!("{}{suffix}", enumeration.get_variant_name()),
+            Span::call_site(),
+          );
+          suffix += 1;
+        }
+
+        let implementation = enumeration.get_variant_implementation(
+          &variant_name,
+          namespace_definition,
+          prefix,
+          context,
+        );
+
+        (variant_name, &enumeration.value, implementation)
+      })
+      .collect();
+
+    let default_variant = &variants[0].0;
+    let definitions: TokenStream = variants
+      .iter()
+      .map(|(_, _, definition)| definition.clone())
+      .collect();
+    let parse_variants: TokenStream = variants
+      .iter()
+      .map(|(name, value, _)| quote!(#value => Ok(#struct_name::#name),))
+      .collect();
+    let display_variants: TokenStream = variants
+      .iter()
+      .map(|(name, value, _)| quote!(#struct_name::#name => #value,))
+      .collect();
+
+    quote!(
+      #[derive(Clone, Debug, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub enum #struct_name {
+        #definitions
+      }
+
+      impl Default for #struct_name {
+        fn default() -> Self {
+          #struct_name::#default_variant
+        }
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          match value {
+            #parse_variants
+            _ => Err(format!("Unknown value {:?} for {}", value, stringify!(#struct_name))),
+          }
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          let value = match self {
+            #display_variants
+          };
+          write!(formatter, "{}", value)
+        }
+      }
+    )
+  }
+
+  fn implement_facets(&self, context: &XsdContext, struct_name: &Ident) -> TokenStream {
+    let base_type = self.get_type_implementation(context, &None);
+
+    let bound_check = |facet: &Option<Facet>, name: &str, operator: TokenStream, message: &str| {
+      facet.as_ref().map(|facet| {
+        let value = &facet.value;
+        quote!(
+          let limit: #base_type = #value
+            .parse()
+            .map_err(|_| format!("Invalid {} facet {:?} for {}", #name, #value, stringify!(#struct_name)))?;
+          if *content #operator limit {
+            return Err(format!("{} must be {} {}, found {}", stringify!(#struct_name), #message, limit, content));
+          }
+        )
+      })
+    };
+
+    let min_inclusive = bound_check(
+      &self.min_inclusive,
+      "minInclusive",
+      quote!(<),
+      "greater than or equal to",
+    );
+    let max_inclusive = bound_check(
+      &self.max_inclusive,
+      "maxInclusive",
+      quote!(>),
+      "lower than or equal to",
+    );
+    let min_exclusive = bound_check(
+      &self.min_exclusive,
+      "minExclusive",
+      quote!(<=),
+      "greater than",
+    );
+    let max_exclusive = bound_check(
+      &self.max_exclusive,
+      "maxExclusive",
+      quote!(>=),
+      "lower than",
+    );
+
+    let length_check =
+      if self.length.is_some() || self.min_length.is_some() || self.max_length.is_some() {
+        let optional_facet = |facet: &Option<Facet>| {
+          facet
+            .as_ref()
+            .map(|facet| {
+              let value = facet.get_usize_value();
+              quote!(Some(#value))
+            })
+            .unwrap_or_else(|| quote!(None))
+        };
+
+        let length = optional_facet(&self.length);
+        let min_length = optional_facet(&self.min_length);
+        let max_length = optional_facet(&self.max_length);
+
+        Some(quote!(
+          xml_schema::facets::check_length(content, #length, #min_length, #max_length)
+            .map_err(|e| format!("{}: {}", stringify!(#struct_name), e))?;
+        ))
+      } else {
+        None
+      };
+
+    let patterns: Vec<(&String, String)> = self
+      .patterns
+      .iter()
+      .filter_map(|pattern| Some((&pattern.value, pattern.get_pattern(context)?)))
+      .collect();
+
+    let patterns_check = if patterns.is_empty() {
+      None
+    } else {
+      let patterns = patterns
+        .iter()
+        .map(|(pattern, regex)| quote!((#pattern, #regex)));
+
+      Some(quote!(
+        static PATTERNS: xml_schema::facets::Patterns = xml_schema::facets::Patterns::new(&[#(#patterns),*]);
+        PATTERNS.check(text)
+          .map_err(|e| format!("{}: {}", stringify!(#struct_name), e))?;
+      ))
+    };
+
+    let is_decimal = self.is_decimal(context);
+    let digits_facet = |facet: &&Facet, name: &str| {
+      if !is_decimal {
+        errors::report(
+          Error::new(format!(
+            "The {name} facet of {struct_name} applies to xs:decimal and its derived types only"
+          ))
+          .at(context.locate(&["value"], &facet.value))
+          .with_hint("remove the facet, or restrict xs:decimal or one of its derived types"),
+        );
+      }
+      is_decimal
+    };
+
+    let total_digits_check = self
+      .total_digits
+      .as_ref()
+      .filter(|facet| digits_facet(facet, "totalDigits"))
+      .map(|facet| {
+        let total_digits = facet.get_usize_value();
+        quote!(
+          xml_schema::facets::check_total_digits(text, #total_digits)
+            .map_err(|e| format!("{}: {}", stringify!(#struct_name), e))?;
+        )
+      });
+
+    let fraction_digits_check = self
+      .fraction_digits
+      .as_ref()
+      .filter(|facet| digits_facet(facet, "fractionDigits"))
+      .map(|facet| {
+        let fraction_digits = facet.get_usize_value();
+        quote!(
+          xml_schema::facets::check_fraction_digits(text, #fraction_digits)
+            .map_err(|e| format!("{}: {}", stringify!(#struct_name), e))?;
+        )
+      });
+
+    // the patterns and digits constrain the text as written, which can differ from the one of the
+    // parsed value, like `1.50` and `1.5`
+    let lexical_checks = [patterns_check, total_digits_check, fraction_digits_check];
+    let text = if lexical_checks.iter().all(Option::is_none) {
+      quote!(_text)
+    } else {
+      quote!(text)
+    };
+
+    let yaserde_implementation = text_content::implement_yaserde(struct_name);
+
+    // the deserialization of a required element or attribute starts from the default value
+    let default_implementation = match default_value::get_default_value(context, self) {
+      Some(value) => quote!(
+        impl Default for #struct_name {
+          fn default() -> Self {
+            #struct_name {
+              content: #value.parse().expect("default value checked against the facets"),
+            }
+          }
+        }
+      ),
+      None => {
+        errors::report(
+          Error::new(format!(
+            "No default value of {struct_name} satisfying its facets"
+          ))
+          .at(context.locate_scope())
+          .with_hint(
+            "the generated structures require a `Default` value: relax the facets so that the default value of the base type, a bound or a string of `a`, `A` or `0` satisfies them, or map the type to a Rust type with `type_mapping`",
+          ),
+        );
+        quote!()
+      }
+    };
+
+    quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      pub struct #struct_name {
+        content: #base_type,
+      }
+
+      impl #struct_name {
+        pub fn new(content: #base_type) -> Result<Self, String> {
+          Self::validate(&content)?;
+          Self::validate_lexical(&content.to_string())?;
+          Ok(#struct_name { content })
+        }
+
+        pub fn content(&self) -> &#base_type {
+          &self.content
+        }
+
+        pub fn into_content(self) -> #base_type {
+          self.content
+        }
+
+        /// Check the facets constraining the value.
+        pub fn validate(content: &#base_type) -> Result<(), String> {
+          #min_inclusive
+          #max_inclusive
+          #min_exclusive
+          #max_exclusive
+          #length_check
+
+          Ok(())
+        }
+
+        /// Check the facets constraining the text representation of the value.
+        pub fn validate_lexical(#text: &str) -> Result<(), String> {
+          #(#lexical_checks)*
+
+          Ok(())
+        }
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          Self::validate_lexical(value)?;
+          let content = value
+            .parse()
+            .map_err(|_| format!("Unable to parse {:?} for {}", value, stringify!(#struct_name)))?;
+          Self::validate(&content)?;
+          Ok(#struct_name { content })
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          write!(formatter, "{}", self.content)
+        }
+      }
+
+      #default_implementation
+
+      #yaserde_implementation
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  #[test]
+  fn enumeration() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: vec![
+        Enumeration {
+          value: "full".to_string(),
+          annotation: None,
+        },
+        Enumeration {
+          value: "narrow-range".to_string(),
+          annotation: None,
+        },
+      ],
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Range", Span::call_site());
+
+    let implementation =
+      restriction.implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+        pub enum Range {
+          #[yaserde(rename = "full")]
+          Full,
+          #[yaserde(rename = "narrow-range")]
+          NarrowRange,
+        }
+
+        impl Default for Range {
+          fn default() -> Self {
+            Range::Full
+          }
+        }
+
+        impl std::str::FromStr for Range {
+          type Err = String;
+
+          fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+              "full" => Ok(Range::Full),
+              "narrow-range" => Ok(Range::NarrowRange),
+              _ => Err(format!("Unknown value {:?} for {}", value, stringify!(Range))),
+            }
+          }
+        }
+
+        impl std::fmt::Display for Range {
+          fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let value = match self {
+              Range::Full => "full",
+              Range::NarrowRange => "narrow-range",
+            };
+            write!(formatter, "{}", value)
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn duplicated_variant_names() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: vec![
+        Enumeration {
+          value: "1.0".to_string(),
+          annotation: None,
+        },
+        Enumeration {
+          value: "10".to_string(),
+          annotation: None,
+        },
+      ],
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Version", Span::call_site());
+
+    let implementation = restriction
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(implementation.contains("Value10 ,"));
+    assert!(implementation.contains("Value101 ,"));
+
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: ["X2", "X", "x"]
+        .iter()
+        .map(|value| Enumeration {
+          value: value.to_string(),
+          annotation: None,
+        })
+        .collect(),
+      ..Default::default()
+    };
+
+    let struct_name = Ident::new("Letter", Span::call_site());
+
+    let implementation = restriction
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(implementation
+      .contains("X2 , # [yaserde (rename = \"X\")] X , # [yaserde (rename = \"x\")] X3 ,"));
+  }
+
+  #[test]
+  fn facets() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      max_length: Some(Facet {
+        value: "8".to_string(),
+      }),
+      patterns: vec![Facet {
+        value: "[a-z]+".to_string(),
+      }],
+      ..Default::default()
+    };
+
+    assert!(restriction.has_facets());
+    assert!(!restriction.is_enumeration());
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Code", Span::call_site());
+
+    let implementation = restriction
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    let expected_length = TokenStream::from_str(
+      r#"xml_schema::facets::check_length(content, None, None, Some(8usize))"#,
+    )
+    .unwrap();
+    let expected_patterns = TokenStream::from_str(
+      r#"static PATTERNS: xml_schema::facets::Patterns = xml_schema::facets::Patterns::new(&[("[a-z]+", "^(?:[a-z]+)$")]);"#,
+    )
+    .unwrap();
+    let expected_default = TokenStream::from_str(
+      r#"content: "a".parse().expect("default value checked against the facets"),"#,
+    )
+    .unwrap();
+
+    assert!(implementation.contains("pub struct Code { content : String , }"));
+    assert!(implementation.contains("pub fn new (content : String) -> Result < Self , String >"));
+    assert!(implementation.contains(&expected_length.to_string()));
+    assert!(implementation.contains(&expected_patterns.to_string()));
+    assert!(implementation.contains("pub fn validate_lexical (text : & str)"));
+    assert!(implementation.contains("Self :: validate_lexical (value) ? ;"));
+    assert!(implementation.contains(&expected_default.to_string()));
+  }
+
+  #[test]
+  fn digits_facets() {
+    let context = XsdContext::new(
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="Gain">
+          <xs:restriction base="xs:double">
+            <xs:totalDigits value="4"/>
+          </xs:restriction>
+        </xs:simpleType>
+      </xs:schema>"#,
+    )
+    .unwrap()
+    .with_location("gain.xsd");
+
+    let struct_name = Ident::new("Gain", Span::call_site());
+
+    let restriction = Restriction {
+      base: Some("xs:decimal".to_string()),
+      total_digits: Some(Facet {
+        value: "4".to_string(),
+      }),
+      ..Default::default()
+    };
+
+    errors::take();
+    let implementation = restriction
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(implementation.contains("xml_schema :: facets :: check_total_digits (text , 4usize)"));
+    assert!(errors::take().is_empty());
+
+    let restriction = Restriction {
+      base: Some("xs:double".to_string()),
+      ..restriction
+    };
+
+    let implementation = restriction
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(!implementation.contains("check_total_digits"));
+
+    let errors = errors::take();
+    assert_eq!(
+      errors[0].message,
+      "The totalDigits facet of Gain applies to xs:decimal and its derived types only"
+    );
+    assert_eq!(errors[0].location.as_ref().unwrap().position, Some((4, 13)));
+  }
+
+  #[test]
+  fn missing_default_value() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      length: Some(Facet {
+        value: "3".to_string(),
+      }),
+      patterns: vec![Facet {
+        value: "[A-Z][a-z]+".to_string(),
+      }],
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Code", Span::call_site());
+
+    errors::take();
+    let implementation = restriction
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(!implementation.contains("impl Default for Code"));
+    assert_eq!(
+      errors::take()[0].message,
+      "No default value of Code satisfying its facets"
+    );
+  }
+}
@@ -0,0 +1,426 @@
+use crate::errors::{self, Error};
+use crate::xsd::XsdContext;
+use heck::CamelCase;
+use proc_macro2::{Span, TokenStream};
+use syn::{parse_str, Ident, Type, TypePath};
+
+/// Attributes of the schema elements naming a type.
+const TYPE_ATTRIBUTES: &[&str] = &["type", "base", "itemType", "memberTypes"];
+
+#[derive(Debug)]
+pub struct RustTypesMapping {}
+
+impl RustTypesMapping {
+  pub fn get(context: &XsdContext, kind: &str) -> TokenStream {
+    if let Some(rust_type) = context.get_type_mapping(kind) {
+      return RustTypesMapping::mapped_type(context, kind, rust_type);
+    }
+
+    let items: Vec<&str> = kind.split(':').collect();
+
+    if items.len() == 2 {
+      if context.match_xml_schema_prefix(items.first().unwrap()) {
+        RustTypesMapping::basic_type(items.last().unwrap())
+          .unwrap_or_else(|| RustTypesMapping::unknown_type(context, kind))
+      } else {
+        RustTypesMapping::extern_type(context, items)
+      }
+    } else if items.len() == 1 {
+      if context.has_xml_schema_prefix() {
+        RustTypesMapping::extern_type(context, items)
+      } else {
+        RustTypesMapping::basic_type(items.last().unwrap())
+          .unwrap_or_else(|| RustTypesMapping::unknown_type(context, kind))
+      }
+    } else {
+      RustTypesMapping::unknown_type(context, kind)
+    }
+  }
+
+  pub fn is_xs_string(context: &XsdContext, kind: &str) -> bool {
+    if context.get_type_mapping(kind).is_some() {
+      return false;
+    }
+
+    let items: Vec<&str> = kind.split(':').collect();
+
+    if items.len() == 2 {
+      if context.match_xml_schema_prefix(items.first().unwrap()) {
+        return *items.last().unwrap() == "string";
+      }
+    } else if items.len() == 1 && !context.has_xml_schema_prefix() {
+      return *items.last().unwrap() == "string";
+    }
+
+    false
+  }
+
+  pub fn is_xml_schema_type(context: &XsdContext, kind: &str) -> bool {
+    let items: Vec<&str> = kind.split(':').collect();
+
+    if items.len() == 2 {
+      context.match_xml_schema_prefix(items.first().unwrap())
+    } else {
+      items.len() == 1 && !context.has_xml_schema_prefix()
+    }
+  }
+
+  /// Local name of a built-in type which is not substituted by a `type_mapping`.
+  pub fn get_builtin_name<'a>(context: &XsdContext, kind: &'a str) -> Option<&'a str> {
+    if context.get_type_mapping(kind).is_some() || !Self::is_xml_schema_type(context, kind) {
+      return None;
+    }
+
+    kind.rsplit(':').next()
+  }
+
+  /// Rust type of the XML Schema 1.0 and 1.1 built-in datatypes.
+  fn basic_type(item: &str) -> Option<TokenStream> {
+    let rust_type = match item {
+      "anyType" | "anySimpleType" | "anyAtomicType" | "error" => quote!(String),
+      // strings
+      "string" | "normalizedString" | "token" | "language" => quote!(String),
+      "Name" | "NCName" | "NMTOKEN" | "ID" | "IDREF" | "ENTITY" => quote!(String),
+      "QName" | "NOTATION" | "anyURI" => quote!(String),
+      "NMTOKENS" | "IDREFS" | "ENTITIES" => quote!(xml_schema::list::List<String>),
+      // numbers
+      "bool" | "boolean" => quote!(bool),
+      "float" => quote!(f32),
+      "double" => quote!(f64),
+      "decimal" => quote!(xml_schema::decimal::generated::Decimal),
+      "integer" => quote!(xml_schema::integer::Integer),
+      "nonPositiveInteger" => quote!(xml_schema::integer::NonPositiveInteger),
+      "negativeInteger" => quote!(xml_schema::integer::NegativeInteger),
+      "long" => quote!(i64),
+      "int" => quote!(i32),
+      "short" => quote!(i16),
+      "byte" => quote!(i8),
+      "nonNegativeInteger" => quote!(xml_schema::integer::NonNegativeInteger),
+      "positiveInteger" => quote!(xml_schema::integer::PositiveInteger),
+      "unsignedLong" => quote!(u64),
+      "unsignedInt" => quote!(u32),
+      "unsignedShort" => quote!(u16),
+      "unsignedByte" => quote!(u8),
+      // dates and times
+      "dateTime" => RustTypesMapping::date_time_type("DateTime"),
+      "dateTimeStamp" => RustTypesMapping::date_time_type("DateTimeStamp"),
+      "date" => RustTypesMapping::date_time_type("Date"),
+      "time" => RustTypesMapping::date_time_type("Time"),
+      "duration" | "dayTimeDuration" | "yearMonthDuration" => {
+        quote!(xml_schema::date_time::Duration)
+      }
+      "gYear" => quote!(xml_schema::date_time::GYear),
+      "gYearMonth" => quote!(xml_schema::date_time::GYearMonth),
+      "gMonthDay" => quote!(xml_schema::date_time::GMonthDay),
+      "gDay" => quote!(xml_schema::date_time::GDay),
+      "gMonth" => quote!(xml_schema::date_time::GMonth),
+      // binary data
+      "hexBinary" => quote!(xml_schema::binary::HexBinary),
+      "base64Binary" => quote!(xml_schema::binary::Base64Binary),
+      _ => return None,
+    };
+
+    Some(rust_type)
+  }
+
+  /// Rust type substituted with a `type_mapping` attribute. The substitutes of simple types are
+  /// parsed and formatted with `FromStr` and `Display`, the ones of complex types implement the
+  /// YaSerDe traits.
+  fn mapped_type(context: &XsdContext, kind: &str, rust_type: &str) -> TokenStream {
+    let rust_type = match parse_str::<Type>(rust_type) {
+      Ok(rust_type) => rust_type,
+      Err(error) => {
+        errors::report(
+          Error::new(format!(
+            "Invalid Rust type {rust_type:?} in the type mapping of {kind:?}: {error}"
+          ))
+          .with_hint("expected a Rust type path, like `crate::module::Type`"),
+        );
+        return quote!(String);
+      }
+    };
+
+    if context.get_complex_type(kind).is_none() {
+      quote!(xml_schema::text_content::Text<#rust_type>)
+    } else {
+      quote!(#rust_type)
+    }
+  }
+
+  /// Report a type which is not a built-in type, `String` being used in its place.
+  fn unknown_type(context: &XsdContext, kind: &str) -> TokenStream {
+    errors::report(
+      Error::new(format!("Unknown XML Schema type {kind:?}"))
+        .at(context.locate(TYPE_ATTRIBUTES, kind))
+        .with_hint("use a built-in type of XML Schema 1.1, or a type defined by the schema"),
+    );
+
+    quote!(String)
+  }
+
+  /// Date and time types of the runtime, backed by chrono when `xml_schema` has the `chrono`
+  /// feature.
+  fn date_time_type(name: &str) -> TokenStream {
+    let name = Ident::new(name, Span::call_site());
+
+    quote!(xml_schema::date_time::generated::#name)
+  }
+
+  fn extern_type(context: &XsdContext, items: Vec<&str>) -> TokenStream {
+    let struct_name = if items.last().unwrap().is_empty() {
+      "String".to_string()
+    } else {
+      (*items.last().unwrap().replace('.', "_").to_camel_case()).to_string()
+    };
+
+    let default_module = context
+      .get_module("")
+      .map(|module| format!("{module}::"))
+      .unwrap_or_else(|| "".to_string());
+
+    let module = if items.len() == 2 {
+      let prefix = items.first().unwrap();
+      if let Some(module) = context.get_module(prefix) {
+        module + "::"
+      } else {
+        default_module
+      }
+    } else {
+      default_module
+    };
+
+    let struct_name = format!("{module}{struct_name}");
+    let struct_name = parse_str::<TypePath>(&struct_name).unwrap();
+    quote!(#struct_name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::BTreeMap;
+
+  #[test]
+  fn rust_mapping_types() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert!(RustTypesMapping::get(&context, "xs:boolean").to_string() == "bool");
+    assert!(
+      RustTypesMapping::get(&context, "xs:positiveInteger").to_string()
+        == "xml_schema :: integer :: PositiveInteger"
+    );
+    assert!(RustTypesMapping::get(&context, "xs:byte").to_string() == "i8");
+    assert!(RustTypesMapping::get(&context, "xs:unsignedByte").to_string() == "u8");
+    assert!(RustTypesMapping::get(&context, "xs:short").to_string() == "i16");
+    assert!(RustTypesMapping::get(&context, "xs:unsignedShort").to_string() == "u16");
+    assert!(RustTypesMapping::get(&context, "xs:int").to_string() == "i32");
+    assert!(
+      RustTypesMapping::get(&context, "xs:integer").to_string()
+        == "xml_schema :: integer :: Integer"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:negativeInteger").to_string()
+        == "xml_schema :: integer :: NegativeInteger"
+    );
+    assert!(RustTypesMapping::get(&context, "xs:unsignedInt").to_string() == "u32");
+    assert!(RustTypesMapping::get(&context, "xs:long").to_string() == "i64");
+    assert!(RustTypesMapping::get(&context, "xs:unsignedLong").to_string() == "u64");
+    assert!(
+      RustTypesMapping::get(&context, "xs:nonNegativeInteger").to_string()
+        == "xml_schema :: integer :: NonNegativeInteger"
+    );
+    assert!(RustTypesMapping::get(&context, "xs:float").to_string() == "f32");
+    assert!(RustTypesMapping::get(&context, "xs:double").to_string() == "f64");
+    assert_eq!(
+      RustTypesMapping::get(&context, "xs:decimal").to_string(),
+      "xml_schema :: decimal :: generated :: Decimal"
+    );
+    assert!(RustTypesMapping::get(&context, "xs:string").to_string() == "String");
+    assert!(RustTypesMapping::get(&context, "xs:string").to_string() == "String");
+    assert!(RustTypesMapping::get(&context, "xs:ID").to_string() == "String");
+    assert!(RustTypesMapping::get(&context, "xs:IDREF").to_string() == "String");
+    assert!(
+      RustTypesMapping::get(&context, "xs:IDREFS").to_string()
+        == "xml_schema :: list :: List < String >"
+    );
+    assert!(RustTypesMapping::get(&context, "xs:NCName").to_string() == "String");
+    assert!(RustTypesMapping::get(&context, "xs:QName").to_string() == "String");
+    assert!(
+      RustTypesMapping::get(&context, "xs:yearMonthDuration").to_string()
+        == "xml_schema :: date_time :: Duration"
+    );
+    assert!(RustTypesMapping::get(&context, "xs:anyType").to_string() == "String");
+    assert!(
+      RustTypesMapping::get(&context, "xs:base64Binary").to_string()
+        == "xml_schema :: binary :: Base64Binary"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:hexBinary").to_string()
+        == "xml_schema :: binary :: HexBinary"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:duration").to_string()
+        == "xml_schema :: date_time :: Duration"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:gYear").to_string() == "xml_schema :: date_time :: GYear"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:gMonthDay").to_string()
+        == "xml_schema :: date_time :: GMonthDay"
+    );
+
+    assert_eq!(
+      RustTypesMapping::get(&context, "xs:dateTime").to_string(),
+      "xml_schema :: date_time :: generated :: DateTime"
+    );
+    assert_eq!(
+      RustTypesMapping::get(&context, "xs:dateTimeStamp").to_string(),
+      "xml_schema :: date_time :: generated :: DateTimeStamp"
+    );
+    assert_eq!(
+      RustTypesMapping::get(&context, "xs:date").to_string(),
+      "xml_schema :: date_time :: generated :: Date"
+    );
+
+    assert!(RustTypesMapping::get(&context, "other:type").to_string() == "Type");
+
+    let context =
+      XsdContext::new(r#"<schema xmlns="http://www.w3.org/2001/XMLSchema"></schema>"#).unwrap();
+
+    assert!(RustTypesMapping::get(&context, "boolean").to_string() == "bool");
+  }
+
+  #[test]
+  fn rust_bad_mapping_type() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    errors::take();
+    assert_eq!(
+      RustTypesMapping::get(&context, "xs:unknown").to_string(),
+      "String"
+    );
+
+    let context = context.with_location("schemas/sample.xsd");
+    RustTypesMapping::get(&context, "xs:other");
+
+    let messages: Vec<String> = errors::take()
+      .iter()
+      .map(|error| error.to_string())
+      .collect();
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].starts_with(r#"Unknown XML Schema type "xs:unknown""#));
+    assert!(messages[1].contains(" --> schemas/sample.xsd"));
+  }
+
+  #[test]
+  fn extern_types() {
+    let context = XsdContext::new(
+      r#"
+      <xs:schema
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        xmlns:example="http://example.com"
+        >
+      </xs:schema>
+    "#,
+    )
+    .unwrap();
+
+    let mut mapping = BTreeMap::new();
+    mapping.insert(
+      "http://example.com".to_string(),
+      "rust_example_module".to_string(),
+    );
+
+    let context = context.with_module_namespace_mappings(&mapping);
+    assert_eq!(
+      RustTypesMapping::get(&context, "example:MyType").to_string(),
+      "rust_example_module :: MyType"
+    );
+
+    assert_eq!(
+      RustTypesMapping::get(&context, "example:").to_string(),
+      "rust_example_module :: String"
+    );
+  }
+
+  #[test]
+  fn extern_types_in_default_namespace() {
+    let context = XsdContext::new(
+      r#"
+      <xs:schema
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        xmlns="http://example.com"
+        >
+      </xs:schema>
+    "#,
+    )
+    .unwrap();
+
+    let mut mapping = BTreeMap::new();
+    mapping.insert(
+      "http://example.com".to_string(),
+      "rust_example_module".to_string(),
+    );
+
+    let context = context.with_module_namespace_mappings(&mapping);
+    assert_eq!(
+      RustTypesMapping::get(&context, "MyType").to_string(),
+      "rust_example_module :: MyType"
+    );
+
+    assert_eq!(
+      RustTypesMapping::get(&context, "").to_string(),
+      "rust_example_module :: String"
+    );
+  }
+
+  #[test]
+  fn is_xs_string() {
+    let context = XsdContext::new(
+      r#"
+      <xs:schema
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        xmlns="http://example.com"
+        >
+      </xs:schema>
+    "#,
+    )
+    .unwrap();
+
+    assert!(RustTypesMapping::is_xs_string(&context, "xs:string"));
+    assert!(!RustTypesMapping::is_xs_string(&context, "MyType"));
+
+    let context = XsdContext::new(
+      r#"
+      <schema
+        xmlns="http://www.w3.org/2001/XMLSchema"
+        xmlns:example="http://example.com"
+        >
+      </schema>
+    "#,
+    )
+    .unwrap();
+
+    assert!(RustTypesMapping::is_xs_string(&context, "string"));
+    assert!(!RustTypesMapping::is_xs_string(&context, "example:MyType"));
+  }
+
+  #[test]
+  fn is_xml_schema_type() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert!(RustTypesMapping::is_xml_schema_type(&context, "xs:int"));
+    assert!(!RustTypesMapping::is_xml_schema_type(&context, "MyType"));
+    assert!(!RustTypesMapping::is_xml_schema_type(
+      &context,
+      "example:MyType"
+    ));
+  }
+}
@@ -1,6 +1,7 @@
+use crate::errors::{self, Error};
 use crate::xsd::{
-  attribute, attribute_group, complex_type, element, import, qualification, simple_type,
-  Implementation, XsdContext,
+  attribute, attribute_group, complex_type, element, group, import, include, qualification,
+  resolve_location, simple_type, Implementation, XsdContext,
 };
 use proc_macro2::TokenStream;
 
@@ -19,6 +20,8 @@ pub struct Schema {
   pub attribute_form_default: qualification::Qualification,
   #[yaserde(rename = "import")]
   pub imports: Vec<import::Import>,
+  #[yaserde(rename = "include")]
+  pub includes: Vec<include::Include>,
   #[yaserde(rename = "element")]
   pub elements: Vec<element::Element>,
   #[yaserde(rename = "simpleType")]
@@ -29,18 +32,86 @@ pub struct Schema {
   pub attributes: Vec<attribute::Attribute>,
   #[yaserde(rename = "attributeGroup")]
   pub attribute_group: Vec<attribute_group::AttributeGroup>,
+  #[yaserde(rename = "group")]
+  pub groups: Vec<group::Group>,
 }
 
 impl Implementation for Schema {
   fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    target_prefix: &Option<String>,
+    context: &XsdContext,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    self.implement_with_imports(
+      namespace_definition,
+      target_prefix,
+      context,
+      sub_types_name_prefix,
+      &TokenStream::new(),
+    )
+  }
+}
+
+impl Schema {
+  /// Append the definitions of an included schema, they share the same target namespace.
+  pub fn merge(&mut self, included: Schema) {
+    self.imports.extend(included.imports);
+    self.includes.extend(included.includes);
+    self.elements.extend(included.elements);
+    self.simple_type.extend(included.simple_type);
+    self.complex_type.extend(included.complex_type);
+    self.attributes.extend(included.attributes);
+    self.attribute_group.extend(included.attribute_group);
+    self.groups.extend(included.groups);
+  }
+
+  /// Replace the references to named groups by their content, in all the types of the schema.
+  pub fn expand_groups(&mut self, context: &XsdContext) {
+    for complex_type in self.complex_type.iter_mut() {
+      complex_type.expand_groups(context, &mut vec![]);
+    }
+
+    for element in self.elements.iter_mut() {
+      element.expand_groups(context, &mut vec![]);
+    }
+  }
+
+  /// Inline the fields of the base types in the derived types.
+  pub fn expand_derivations(&mut self, context: &XsdContext) {
+    for complex_type in self.complex_type.iter_mut() {
+      complex_type.expand_derivation(context, &mut vec![]);
+    }
+
+    for element in self.elements.iter_mut() {
+      if let Some(complex_type) = element.complex_type.as_mut() {
+        complex_type.expand_derivation(context, &mut vec![]);
+      }
+    }
+  }
+
+  /// Resolve the location of the imported schemas relatively to the source of this schema.
+  pub fn resolve_import_locations(&mut self, source: &str) {
+    for import in self.imports.iter_mut() {
+      if let Some(schema_location) = &import.schema_location {
+        import.schema_location = Some(resolve_location(source, schema_location));
+      }
+    }
+  }
+
+  /// Generate the schema, the modules of the imported schemas are generated with the types.
+  pub fn implement_with_imports(
     &self,
     _namespace_definition: &TokenStream,
     target_prefix: &Option<String>,
     context: &XsdContext,
 
     sub_types_name_prefix: &Option<&str>,
+    imports: &TokenStream,
   ) -> TokenStream {
-    let namespace_definition = generate_namespace_definition(target_prefix, &self.target_namespace);
+    let namespace_definition = generate_namespace_definition(target_prefix, &self.target_namespace, context);
 
     log::info!("Generate elements");
     let elements: TokenStream = self
@@ -56,6 +127,7 @@ impl Implementation for Schema {
     let simple_types: TokenStream = self
       .simple_type
       .iter()
+      .filter(|simple_type| !context.has_type_mapping(&self.target_namespace, &simple_type.name))
       .map(|simple_type| simple_type.implement(&namespace_definition, target_prefix, context, sub_types_name_prefix))
       .collect();
 
@@ -67,11 +139,13 @@ impl Implementation for Schema {
     let complex_types: TokenStream = self
       .complex_type
       .iter()
+      .filter(|complex_type| !context.has_type_mapping(&self.target_namespace, &complex_type.name))
       .map(|complex_type| complex_type.implement(&namespace_definition, target_prefix, context, sub_types_name_prefix))
       .collect();
 
     quote!(
       pub mod types {
+        #imports
         #simple_types
         #complex_types
       }
@@ -84,15 +158,26 @@ impl Implementation for Schema {
 fn generate_namespace_definition(
   target_prefix: &Option<String>,
   target_namespace: &Option<String>,
+  context: &XsdContext,
 ) -> TokenStream {
   match (target_prefix, target_namespace) {
     (None, None) => quote!(),
-    (None, Some(_target_namespace)) => {
-      panic!("undefined prefix attribute, a target namespace is defined")
+    (None, Some(target_namespace)) => {
+      errors::report(
+        Error::new("undefined prefix attribute, a target namespace is defined")
+          .at(context.locate(&["targetNamespace"], target_namespace))
+          .with_hint("add a `target_prefix` for this schema"),
+      );
+      quote!()
+    }
+    (Some(_prefix), None) => {
+      errors::report(
+        Error::new("a prefix attribute, but no target namespace is defined")
+          .at(context.locate_schema())
+          .with_hint("remove the `target_prefix` of this schema"),
+      );
+      quote!()
     }
-    (Some(_prefix), None) => panic!(
-      "a prefix attribute, but no target namespace is defined, please remove the prefix parameter"
-    ),
     (Some(prefix), Some(target_namespace)) => {
       let namespace = format!("{prefix}: {target_namespace}");
       quote!(#[yaserde(prefix=#prefix, namespace=#namespace)])
@@ -117,7 +202,6 @@ mod tests {
   }
 
   #[test]
-  #[should_panic]
   fn missing_prefix() {
     let schema = Schema {
       target_namespace: Some("http://example.com".to_string()),
@@ -128,11 +212,17 @@ mod tests {
       XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
         .unwrap();
 
+    errors::take();
     schema.implement(&TokenStream::new(), &None, &context, &None);
+
+    let errors = errors::take();
+    assert_eq!(
+      errors[0].message,
+      "undefined prefix attribute, a target namespace is defined"
+    );
   }
 
   #[test]
-  #[should_panic]
   fn missing_target_namespace() {
     let schema = Schema::default();
 
@@ -140,14 +230,26 @@ mod tests {
       XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
         .unwrap();
 
+    errors::take();
     schema.implement(&TokenStream::new(), &Some("ex".to_string()), &context, &None);
+
+    let errors = errors::take();
+    assert_eq!(
+      errors[0].message,
+      "a prefix attribute, but no target namespace is defined"
+    );
   }
 
   #[test]
   fn generate_namespace() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
     let definition = generate_namespace_definition(
       &Some("prefix".to_string()),
       &Some("http://example.com".to_string()),
+      &context,
     );
 
     let implementation = format!("{definition}");
@@ -0,0 +1,210 @@
+use crate::xsd::{choice::Choice, element::Element, group::Group, Implementation, XsdContext};
+use log::info;
+use proc_macro2::TokenStream;
+use std::io::Read;
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, YaDeserialize};
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Sequence {
+  pub elements: Vec<Element>,
+  pub choices: Vec<Choice>,
+  pub groups: Vec<Group>,
+  /// Order of the elements, choices and groups in the schema, see `get_particles`.
+  pub particles: Vec<Particle>,
+}
+
+/// Kind of a particle of a sequence. The elements, choices and groups are kept in their own
+/// lists, the particles record how they are interleaved.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Particle {
+  Element,
+  Choice,
+  Group,
+}
+
+// Deserialized by hand to keep the order of the elements, choices and group references.
+impl YaDeserialize for Sequence {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    match reader.next_event()? {
+      XmlEvent::StartElement { name, .. } if name.local_name == "sequence" => {}
+      event => return Err(format!("Expected sequence start, got {:?}", event)),
+    }
+
+    let mut sequence = Sequence::default();
+    loop {
+      match reader.peek()?.to_owned() {
+        XmlEvent::StartElement { name, .. } => {
+          match name.local_name.as_str() {
+            "element" => sequence.push_element(Element::deserialize(reader)?),
+            "choice" => sequence.push_choice(Choice::deserialize(reader)?),
+            "group" => sequence.push_group(Group::deserialize(reader)?),
+            _ => {
+              reader.next_event()?;
+              reader.skip_element(|_| {})?;
+              continue;
+            }
+          }
+          reader.next_event()?;
+        }
+        XmlEvent::EndElement { .. } | XmlEvent::EndDocument => break,
+        _ => {
+          reader.next_event()?;
+        }
+      }
+    }
+
+    Ok(sequence)
+  }
+}
+
+impl Implementation for Sequence {
+  fn implement(
+    &self,
+    _namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    self
+      .elements
+      .iter()
+      .map(|element| element.get_field_implementation(context, prefix, sub_types_name_prefix))
+      .collect()
+  }
+}
+
+impl Sequence {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    for element in self.elements.iter_mut() {
+      element.map_qualified_names(map);
+    }
+    for choice in self.choices.iter_mut() {
+      choice.map_qualified_names(map);
+    }
+    for group in self.groups.iter_mut() {
+      group.map_qualified_names(map);
+    }
+  }
+
+  pub fn push_element(&mut self, element: Element) {
+    self.elements.push(element);
+    self.particles.push(Particle::Element);
+  }
+
+  pub fn push_choice(&mut self, choice: Choice) {
+    self.choices.push(choice);
+    self.particles.push(Particle::Choice);
+  }
+
+  pub fn push_group(&mut self, group: Group) {
+    self.groups.push(group);
+    self.particles.push(Particle::Group);
+  }
+
+  /// Append the particles of an other sequence, in its order.
+  pub fn append(&mut self, sequence: Sequence) {
+    let particles = sequence.get_particles();
+    let mut elements = sequence.elements.into_iter();
+    let mut choices = sequence.choices.into_iter();
+    let mut groups = sequence.groups.into_iter();
+
+    for particle in particles {
+      match particle {
+        Particle::Element => self.push_element(elements.next().unwrap_or_default()),
+        Particle::Choice => self.push_choice(choices.next().unwrap_or_default()),
+        Particle::Group => self.push_group(groups.next().unwrap_or_default()),
+      }
+    }
+  }
+
+  /// Kinds of the particles in the schema order. The elements, choices and groups added to the
+  /// lists without being recorded follow the recorded ones.
+  pub fn get_particles(&self) -> Vec<Particle> {
+    let mut remaining = [self.elements.len(), self.choices.len(), self.groups.len()];
+    let index = |particle: &Particle| match particle {
+      Particle::Element => 0,
+      Particle::Choice => 1,
+      Particle::Group => 2,
+    };
+
+    let mut particles: Vec<Particle> = self
+      .particles
+      .iter()
+      .filter(|particle| {
+        let remaining = &mut remaining[index(particle)];
+        let recorded = *remaining > 0;
+        *remaining = remaining.saturating_sub(1);
+        recorded
+      })
+      .copied()
+      .collect();
+
+    for (particle, count) in [Particle::Element, Particle::Choice, Particle::Group]
+      .iter()
+      .zip(remaining.iter())
+    {
+      particles.extend(std::iter::repeat_n(*particle, *count));
+    }
+
+    particles
+  }
+
+  /// Replace the group references by the content of the groups, at their position. The
+  /// repeated groups are kept expanded, see `Group::is_repeated`.
+  pub fn expand_groups(&mut self, context: &XsdContext, references: &mut Vec<String>) {
+    let particles = self.get_particles();
+    let mut elements = std::mem::take(&mut self.elements).into_iter();
+    let mut choices = std::mem::take(&mut self.choices).into_iter();
+    let mut groups = std::mem::take(&mut self.groups).into_iter();
+    self.particles.clear();
+
+    for particle in particles {
+      match particle {
+        Particle::Element => self.push_element(elements.next().unwrap_or_default()),
+        Particle::Choice => self.push_choice(choices.next().unwrap_or_default()),
+        Particle::Group => {
+          let group = groups.next().unwrap_or_default().expand(context, references);
+
+          if group.is_repeated() {
+            self.push_group(group);
+            continue;
+          }
+          if let Some(sequence) = group.sequence {
+            self.append(sequence);
+          }
+          if let Some(choice) = group.choice {
+            self.push_choice(choice);
+          }
+        }
+      }
+    }
+
+    for choice in self.choices.iter_mut() {
+      choice.expand_groups(context, references);
+    }
+    for element in self.elements.iter_mut() {
+      element.expand_groups(context, references);
+    }
+  }
+
+  pub fn get_sub_types_implementation(
+    &self,
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    self
+      .elements
+      .iter()
+      .map(|element| {
+        let values = element.get_value_implementation(context, sub_types_name_prefix);
+        let subtypes = element.get_subtypes_implementation(namespace_definition, prefix, context, sub_types_name_prefix);
+        quote!(#values #subtypes)
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,142 @@
+use crate::xsd::{
+  extension::Extension, restriction::Restriction, rust_types_mapping::RustTypesMapping,
+  Implementation, XsdContext,
+};
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct SimpleContent {
+  #[yaserde(prefix = "xs", rename = "extension")]
+  pub extension: Option<Extension>,
+  #[yaserde(prefix = "xs", rename = "restriction")]
+  pub restriction: Option<Restriction>,
+}
+
+impl Implementation for SimpleContent {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    if let Some(extension) = &self.extension {
+      return extension.implement(namespace_definition, prefix, context, sub_types_name_prefix);
+    }
+
+    let attributes: TokenStream = self
+      .restriction
+      .iter()
+      .flat_map(|restriction| restriction.attributes.iter())
+      .map(|attribute| {
+        attribute.implement(namespace_definition, prefix, context, sub_types_name_prefix)
+      })
+      .collect();
+
+    quote!(
+      #[yaserde(text)]
+      pub content: String,
+      #attributes
+    )
+  }
+}
+
+impl SimpleContent {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    if let Some(extension) = self.extension.as_mut() {
+      extension.map_qualified_names(map);
+    }
+    if let Some(restriction) = self.restriction.as_mut() {
+      restriction.map_qualified_names(map);
+    }
+  }
+
+  /// Fields of the text content and of the attributes of `struct_name`.
+  pub fn get_fields_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+
+    sub_types_name_prefix: &Option<&str>,
+  ) -> TokenStream {
+    match self.get_content_name(context, struct_name) {
+      Some(content_name) => {
+        let attributes: TokenStream = self
+          .restriction
+          .iter()
+          .flat_map(|restriction| restriction.attributes.iter())
+          .map(|attribute| {
+            attribute.implement(namespace_definition, prefix, context, sub_types_name_prefix)
+          })
+          .collect();
+
+        quote!(
+          #[yaserde(flatten)]
+          pub content: #content_name,
+          #attributes
+        )
+      }
+      None => self.implement(namespace_definition, prefix, context, sub_types_name_prefix),
+    }
+  }
+
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    match (&self.extension, self.get_content_name(context, struct_name)) {
+      (Some(extension), _) => extension.get_field_implementation(context, prefix),
+      (None, Some(content_name)) => quote!(
+        #[yaserde(flatten)]
+        pub content: #content_name,
+      ),
+      (None, None) => quote!(pub content : String),
+    }
+  }
+
+  /// Name of the type generated for the text content of a restriction, checking its facets.
+  /// The text content is a plain `String` for a restriction of `xs:string` without facets.
+  fn get_content_name(&self, context: &XsdContext, struct_name: &Ident) -> Option<Ident> {
+    let restriction = self.restriction.as_ref()?;
+
+    let is_string = restriction
+      .base
+      .as_ref()
+      .is_none_or(|base| RustTypesMapping::is_xs_string(context, base));
+
+    if is_string && !restriction.is_enumeration() && !restriction.has_facets() {
+      return None;
+    }
+
+    Some(Ident::new(
+      &format!("{struct_name}Content"),
+      Span::call_site(),
+    ))
+  }
+
+  /// Type of the text content of a restriction, deserialized from its text representation.
+  pub fn get_restriction_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let (restriction, content_name) = match (
+      &self.restriction,
+      self.get_content_name(context, struct_name),
+    ) {
+      (Some(restriction), Some(content_name)) => (restriction, content_name),
+      _ => return TokenStream::new(),
+    };
+
+    restriction.implement_childs(namespace_definition, prefix, context, &content_name)
+  }
+}
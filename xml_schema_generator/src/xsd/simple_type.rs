@@ -1,3 +1,4 @@
+use crate::errors::{self, Error};
 use crate::xsd::{list::List, restriction::Restriction, union::Union, Implementation, XsdContext};
 use heck::CamelCase;
 use proc_macro2::{Span, TokenStream};
@@ -22,12 +23,23 @@ impl Implementation for SimpleType {
 
     _sub_types_name_prefix: &Option<&str>,
   ) -> TokenStream {
+    let _scope = context.enter("simpleType", "name", &self.name);
     let struct_name = Ident::new(&self.name.to_camel_case(), Span::call_site());
 
     if let Some(list) = &self.list {
       return list.implement_childs(namespace_definition, prefix, context, &struct_name);
     }
 
+    if let Some(union) = &self.union {
+      return union.implement_childs(namespace_definition, prefix, context, &struct_name);
+    }
+
+    if let Some(restriction) = &self.restriction {
+      if restriction.is_enumeration() || restriction.has_facets() {
+        return restriction.implement_childs(namespace_definition, prefix, context, &struct_name);
+      }
+    }
+
     quote!(
       #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
       #namespace_definition
@@ -40,6 +52,18 @@ impl Implementation for SimpleType {
 }
 
 impl SimpleType {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    if let Some(restriction) = self.restriction.as_mut() {
+      restriction.map_qualified_names(map);
+    }
+    if let Some(list) = self.list.as_mut() {
+      list.map_qualified_names(map);
+    }
+    if let Some(union) = self.union.as_mut() {
+      union.map_qualified_names(map);
+    }
+  }
+
   pub fn get_type_implementation(
     &self,
     context: &XsdContext,
@@ -48,7 +72,13 @@ impl SimpleType {
     if let Some(restriction) = &self.restriction {
       restriction.get_type_implementation(context, prefix)
     } else {
-      panic!("No restriction for this simple type {:?}", self);
+      let name = prefix.as_deref().unwrap_or(&self.name);
+      errors::report(
+        Error::new(format!("Unsupported anonymous simple type in {name:?}"))
+          .at(context.locate(&["name"], name))
+          .with_hint("declare the list or union as a named simple type, and reference it"),
+      );
+      quote!(String)
     }
   }
 }
@@ -0,0 +1,23 @@
+use proc_macro2::{Ident, TokenStream};
+
+/// YaSerDe implementation for generated types represented by a single text value,
+/// relying on their `FromStr` and `Display` implementations.
+pub fn implement_yaserde(struct_name: &Ident) -> TokenStream {
+  quote!(
+    impl yaserde::YaDeserialize for #struct_name {
+      fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+        xml_schema::text_content::deserialize(reader)
+      }
+    }
+
+    impl yaserde::YaSerialize for #struct_name {
+      fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+        xml_schema::text_content::serialize(self, stringify!(#struct_name), writer)
+      }
+
+      fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+        Ok((source_attributes, source_namespace))
+      }
+    }
+  )
+}
@@ -0,0 +1,295 @@
+use crate::errors::{self, Error};
+use crate::xsd::{
+  rust_types_mapping::RustTypesMapping, simple_type::SimpleType, text_content, Implementation,
+  XsdContext,
+};
+use heck::CamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use std::collections::BTreeSet;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Union {
+  #[yaserde(rename = "memberTypes", attribute)]
+  pub member_types: String,
+  #[yaserde(rename = "simpleType")]
+  pub simple_types: Vec<SimpleType>,
+}
+
+impl Implementation for Union {
+  fn implement_childs(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let member_types: Vec<&str> = self.member_types.split_whitespace().collect();
+
+    let mut members: Vec<(Ident, TokenStream)> = member_types
+      .iter()
+      .map(|member_type| {
+        // the prefix distinguishes the member types with the same local name
+        let name = if member_types
+          .iter()
+          .filter(|other| local_name(other) == local_name(member_type))
+          .count()
+          > 1
+        {
+          member_type.replace(':', "_")
+        } else {
+          local_name(member_type).to_string()
+        };
+        let variant_name = Ident::new(&name.replace('.', "_").to_camel_case(), Span::call_site());
+
+        (variant_name, RustTypesMapping::get(context, member_type))
+      })
+      .collect();
+
+    let mut inline_types = TokenStream::new();
+    for (index, simple_type) in self.simple_types.iter().enumerate() {
+      let variant_name = format!("Member{}", index + 1);
+      let member_type_name = Ident::new(&format!("{struct_name}{variant_name}"), Span::call_site());
+
+      let simple_type = SimpleType {
+        name: member_type_name.to_string(),
+        ..simple_type.clone()
+      };
+
+      inline_types.extend(simple_type.implement(namespace_definition, prefix, context, &None));
+      members.push((
+        Ident::new(&variant_name, Span::call_site()),
+        quote!(#member_type_name),
+      ));
+    }
+
+    let mut variant_names = BTreeSet::new();
+    for (variant_name, _) in &members {
+      if !variant_names.insert(variant_name.to_string()) {
+        errors::report(
+          Error::new(format!(
+            "Union {struct_name} has several members named {variant_name}"
+          ))
+          .at(context.locate(&["memberTypes"], &self.member_types))
+          .with_hint("list each member type once"),
+        );
+        return quote!();
+      }
+    }
+
+    if members.is_empty() {
+      errors::report(
+        Error::new(format!("Missing member types for union {struct_name}"))
+          .at(context.locate(&["name"], &struct_name.to_string()))
+          .with_hint("add a `memberTypes` attribute, or declare the member types inside the union"),
+      );
+      return quote!();
+    }
+
+    let default_variant = &members[0].0;
+    let variants: TokenStream = members
+      .iter()
+      .map(|(variant_name, rust_type)| quote!(#variant_name(#rust_type),))
+      .collect();
+    let parse_variants: TokenStream = members
+      .iter()
+      .map(|(variant_name, rust_type)| {
+        quote!(
+          if let Ok(content) = value.parse::<#rust_type>() {
+            return Ok(#struct_name::#variant_name(content));
+          }
+        )
+      })
+      .collect();
+    let display_variants: TokenStream = members
+      .iter()
+      .map(|(variant_name, _)| quote!(#struct_name::#variant_name(content) => write!(formatter, "{}", content),))
+      .collect();
+
+    let yaserde_implementation = text_content::implement_yaserde(struct_name);
+
+    quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      pub enum #struct_name {
+        #variants
+      }
+
+      impl Default for #struct_name {
+        fn default() -> Self {
+          #struct_name::#default_variant(Default::default())
+        }
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          #parse_variants
+
+          Err(format!("Unable to parse {:?} for {}", value, stringify!(#struct_name)))
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          match self {
+            #display_variants
+          }
+        }
+      }
+
+      #yaserde_implementation
+
+      #inline_types
+    )
+  }
+}
+
+impl Union {
+  pub fn map_qualified_names(&mut self, map: &mut dyn FnMut(&str) -> String) {
+    self.member_types = self
+      .member_types
+      .split_whitespace()
+      .map(&mut *map)
+      .collect::<Vec<String>>()
+      .join(" ");
+    for simple_type in self.simple_types.iter_mut() {
+      simple_type.map_qualified_names(map);
+    }
+  }
+}
+
+fn local_name(member_type: &str) -> &str {
+  member_type.rsplit(':').next().unwrap_or(member_type)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  #[test]
+  fn union_of_member_types() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let union = Union {
+      member_types: "xs:unsignedInt xs:string".to_string(),
+      simple_types: vec![],
+    };
+
+    let struct_name = Ident::new("Timecode", Span::call_site());
+
+    let implementation = union.implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum Timecode {
+          UnsignedInt(u32),
+          String(String),
+        }
+
+        impl Default for Timecode {
+          fn default() -> Self {
+            Timecode::UnsignedInt(Default::default())
+          }
+        }
+
+        impl std::str::FromStr for Timecode {
+          type Err = String;
+
+          fn from_str(value: &str) -> Result<Self, Self::Err> {
+            if let Ok(content) = value.parse:: <u32>() {
+              return Ok(Timecode::UnsignedInt(content));
+            }
+            if let Ok(content) = value.parse:: <String>() {
+              return Ok(Timecode::String(content));
+            }
+
+            Err(format!("Unable to parse {:?} for {}", value, stringify!(Timecode)))
+          }
+        }
+
+        impl std::fmt::Display for Timecode {
+          fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+              Timecode::UnsignedInt(content) => write!(formatter, "{}", content),
+              Timecode::String(content) => write!(formatter, "{}", content),
+            }
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    let yaserde_implementation = text_content::implement_yaserde(&struct_name);
+
+    assert_eq!(
+      implementation.to_string(),
+      format!("{expected} {yaserde_implementation}")
+    );
+  }
+
+  #[test]
+  fn union_with_inline_member() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let union = Union {
+      member_types: "xs:unsignedInt".to_string(),
+      simple_types: vec![SimpleType {
+        name: "".to_string(),
+        restriction: None,
+        list: None,
+        union: None,
+      }],
+    };
+
+    let struct_name = Ident::new("Timecode", Span::call_site());
+
+    let implementation = union
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(implementation.contains("Member1 (TimecodeMember1) ,"));
+    assert!(implementation.contains("pub struct TimecodeMember1"));
+  }
+
+  #[test]
+  fn union_of_prefixed_member_types() {
+    let context = XsdContext::new(
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns:a="http://example.com/a" xmlns:b="http://example.com/b"></xs:schema>"#,
+    )
+    .unwrap();
+
+    let union = Union {
+      member_types: "a:Code b:Code xs:string".to_string(),
+      simple_types: vec![],
+    };
+
+    let struct_name = Ident::new("AnyCode", Span::call_site());
+
+    let implementation = union
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(implementation.contains("ACode (Code) , BCode (Code) , String (String) ,"));
+
+    let union = Union {
+      member_types: "xs:string xs:string".to_string(),
+      simple_types: vec![],
+    };
+
+    errors::take();
+    let implementation = union.implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    assert!(implementation.is_empty());
+    assert_eq!(
+      errors::take()[0].message,
+      "Union AnyCode has several members named XsString"
+    );
+  }
+}
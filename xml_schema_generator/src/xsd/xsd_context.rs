@@ -0,0 +1,770 @@
+use crate::errors::{Error, Location};
+use crate::xsd::{
+  attribute::Attribute, attribute_group::AttributeGroup, complex_type::ComplexType, group::Group,
+  simple_type::SimpleType,
+};
+use proc_macro2::Span;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::rc::Rc;
+use syn::{Ident, PathSegment, Type, TypePath};
+use xml::common::Position;
+use xml::namespace::Namespace;
+use xml::reader::{EventReader, XmlEvent};
+
+const XML_SCHEMA_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+
+#[derive(Clone, Debug)]
+pub struct XsdContext {
+  module_namespace_mappings: BTreeMap<String, String>,
+  pub namespace: Namespace,
+  xml_schema_prefix: Option<String>,
+  global_elements: BTreeMap<(String, String), GlobalElement>,
+  attributes: BTreeMap<(String, String), Attribute>,
+  attribute_groups: BTreeMap<(String, String), AttributeGroup>,
+  groups: BTreeMap<(String, String), Group>,
+  complex_types: BTreeMap<(String, String), ComplexType>,
+  simple_types: BTreeMap<(String, String), SimpleType>,
+  serialize_default_attributes: bool,
+  location: Option<String>,
+  nodes: Rc<Vec<Node>>,
+  /// Range of the nodes of the definition being generated, searched first to locate errors.
+  scope: Cell<(usize, usize)>,
+  type_mappings: BTreeMap<(String, String), String>,
+}
+
+/// Attributes of an element of the schema, with its line and column, to locate errors.
+#[derive(Clone, Debug)]
+struct Node {
+  name: String,
+  attributes: Vec<(String, String)>,
+  position: (u64, u64),
+  /// Index following the last descendant of the node.
+  end: usize,
+}
+
+/// Scope of the locations entered with `XsdContext::enter`, the previous one being restored when
+/// it is dropped.
+pub struct LocationScope<'a> {
+  context: &'a XsdContext,
+  previous: (usize, usize),
+}
+
+impl Drop for LocationScope<'_> {
+  fn drop(&mut self) {
+    self.context.scope.set(self.previous);
+  }
+}
+
+/// Global `xs:element` declaration, which can be referenced by other elements.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalElement {
+  pub name: String,
+  pub rust_type: Type,
+  /// The type is generated for the schema, its path is relative to the `types` module of the
+  /// referencing schema.
+  pub generated: bool,
+  /// The type is a built-in XML Schema type, represented by a text content.
+  pub builtin: bool,
+}
+
+impl GlobalElement {
+  /// Same element, seen from the schema importing the module it is generated in.
+  pub fn in_module(&self, module: &str) -> Self {
+    let mut rust_type = self.rust_type.clone();
+
+    if let Type::Path(TypePath { qself: None, path }) = &mut rust_type {
+      if self.generated && path.leading_colon.is_none() {
+        let module = Ident::new(module, Span::call_site());
+        let prefix: Vec<PathSegment> = match path.segments.first() {
+          Some(segment) if segment.ident == "super" => vec![module.into()],
+          _ => vec![module.into(), Ident::new("types", Span::call_site()).into()],
+        };
+
+        let segments = path
+          .segments
+          .iter()
+          .skip_while(|segment| segment.ident == "super")
+          .cloned();
+        path.segments = prefix.into_iter().chain(segments).collect();
+      }
+    }
+
+    GlobalElement {
+      rust_type,
+      ..self.clone()
+    }
+  }
+}
+
+impl XsdContext {
+  pub fn new(content: &str) -> Result<Self, String> {
+    let cursor = Cursor::new(content);
+    let mut parser = EventReader::new(cursor);
+    let mut context: Option<XsdContext> = None;
+    let mut nodes: Vec<Node> = vec![];
+    let mut open_nodes = vec![];
+
+    loop {
+      match parser.next() {
+        Ok(XmlEvent::StartElement {
+          name,
+          namespace,
+          attributes,
+        }) => {
+          if context.is_none()
+            && name.namespace.as_deref() == Some(XML_SCHEMA_NAMESPACE)
+            && name.local_name == "schema"
+          {
+            let module_namespace_mappings = BTreeMap::new();
+            let xml_schema_prefix = name.prefix.clone();
+
+            context = Some(XsdContext {
+              module_namespace_mappings,
+              namespace,
+              xml_schema_prefix,
+              global_elements: BTreeMap::new(),
+              attributes: BTreeMap::new(),
+              attribute_groups: BTreeMap::new(),
+              groups: BTreeMap::new(),
+              complex_types: BTreeMap::new(),
+              simple_types: BTreeMap::new(),
+              serialize_default_attributes: false,
+              location: None,
+              nodes: Rc::new(vec![]),
+              scope: Cell::new((0, 0)),
+              type_mappings: BTreeMap::new(),
+            });
+          }
+
+          // the reader reports the end of the root element start tag, it is searched in the text
+          let position = if nodes.is_empty() {
+            let qualified_name = match &name.prefix {
+              Some(prefix) => format!("{prefix}:{}", name.local_name),
+              None => name.local_name.clone(),
+            };
+            root_position(content, &qualified_name)
+          } else {
+            let position = parser.position();
+            (position.row + 1, position.column + 1)
+          };
+
+          open_nodes.push(nodes.len());
+          nodes.push(Node {
+            name: name.local_name,
+            attributes: attributes
+              .into_iter()
+              .map(|attribute| (attribute.name.local_name, attribute.value))
+              .collect(),
+            position,
+            end: nodes.len() + 1,
+          });
+        }
+        Ok(XmlEvent::EndElement { .. }) => {
+          if let Some(index) = open_nodes.pop() {
+            nodes[index].end = nodes.len();
+          }
+        }
+        Ok(XmlEvent::EndDocument) | Err(_) => break,
+        _ => {}
+      }
+    }
+
+    context
+      .map(|context| XsdContext {
+        scope: Cell::new((0, nodes.len())),
+        nodes: Rc::new(nodes),
+        ..context
+      })
+      .ok_or_else(|| "Bad XML Schema, unable to found schema element.".to_string())
+  }
+
+  pub fn with_module_namespace_mappings(
+    mut self,
+    module_namespace_mappings: &BTreeMap<String, String>,
+  ) -> Self {
+    self.module_namespace_mappings = module_namespace_mappings.clone();
+    self
+  }
+
+  pub fn with_serialize_default_attributes(mut self, serialize_default_attributes: bool) -> Self {
+    self.serialize_default_attributes = serialize_default_attributes;
+    self
+  }
+
+  /// Attributes equal to their default value are serialized, instead of being omitted.
+  pub fn serialize_default_attributes(&self) -> bool {
+    self.serialize_default_attributes
+  }
+
+  /// Path or URL of the schema, to report errors.
+  pub fn with_location(mut self, location: &str) -> Self {
+    self.location = Some(location.to_string());
+    self
+  }
+
+  pub fn location(&self) -> Option<&str> {
+    self.location.as_deref()
+  }
+
+  /// Restrict the locations to the first `element` of the current scope having the attribute
+  /// with this value, like `enter("complexType", "name", "Person")`, and to its descendants, until
+  /// the returned scope is dropped. The current scope is kept when there is no such element.
+  pub fn enter(&self, element: &str, attribute: &str, value: &str) -> LocationScope<'_> {
+    let previous = self.scope.get();
+
+    let (start, end) = previous;
+    let node = self.nodes[start..end].iter().position(|node| {
+      node.name == element
+        && node
+          .attributes
+          .iter()
+          .any(|(name, node_value)| name == attribute && node_value == value)
+    });
+    if let Some(index) = node {
+      let index = start + index;
+      self.scope.set((index, self.nodes[index].end));
+    }
+
+    LocationScope {
+      context: self,
+      previous,
+    }
+  }
+
+  /// Location of the first element of the schema having one of the attributes with this value,
+  /// like `locate(&["type"], "xs:date")`, searched in the definition being generated first.
+  /// `None` when the schema location is unknown.
+  pub fn locate(&self, attributes: &[&str], value: &str) -> Option<Location> {
+    let file = self.location.clone()?;
+
+    let (start, end) = self.scope.get();
+    let matches = |node: &&Node| {
+      node.attributes.iter().any(|(name, node_value)| {
+        attributes.contains(&name.as_str())
+          && node_value.split_whitespace().any(|item| item == value)
+      })
+    };
+    let position = self.nodes[start..end]
+      .iter()
+      .find(matches)
+      .or_else(|| self.nodes.iter().find(matches))
+      .map(|node| node.position);
+
+    Some(Location { file, position })
+  }
+
+  /// Location of the definition being generated, see `enter`, or of the `xs:schema` element.
+  pub fn locate_scope(&self) -> Option<Location> {
+    let file = self.location.clone()?;
+    let (start, _) = self.scope.get();
+    let position = self.nodes.get(start).map(|node| node.position);
+
+    Some(Location { file, position })
+  }
+
+  /// Location of the `xs:schema` element, where the namespaces are declared.
+  pub fn locate_schema(&self) -> Option<Location> {
+    let file = self.location.clone()?;
+    let position = self.nodes.first().map(|node| node.position);
+
+    Some(Location { file, position })
+  }
+
+  /// Resolve the prefixes of the `type_mapping` attributes, like `prefix:name`, with the
+  /// namespaces declared in the schema.
+  pub fn resolve_type_mappings(
+    &self,
+    type_mappings: &BTreeMap<String, String>,
+  ) -> Result<BTreeMap<(String, String), String>, Error> {
+    type_mappings
+      .iter()
+      .map(|(xml_type, rust_type)| {
+        if let Some((prefix, _)) = xml_type.split_once(':') {
+          if self.namespace.get(prefix).is_none() {
+            return Err(
+              Error::new(format!(
+                "Unknown prefix {prefix:?} in the type mapping of {xml_type:?}"
+              ))
+              .at(self.locate_schema())
+              .with_hint("use a prefix declared on the `xs:schema` element"),
+            );
+          }
+        }
+
+        Ok((self.resolve_name(xml_type), rust_type.clone()))
+      })
+      .collect()
+  }
+
+  pub fn with_type_mappings(mut self, type_mappings: &BTreeMap<(String, String), String>) -> Self {
+    self.type_mappings = type_mappings.clone();
+    self
+  }
+
+  /// Rust type substituted to a type name, like `prefix:name`.
+  pub fn get_type_mapping(&self, kind: &str) -> Option<&String> {
+    self.type_mappings.get(&self.resolve_name(kind))
+  }
+
+  /// The type defined by the schema is substituted by a Rust type, it is not generated.
+  pub fn has_type_mapping(&self, namespace: &Option<String>, name: &str) -> bool {
+    let namespace = namespace.clone().unwrap_or_default();
+    self
+      .type_mappings
+      .contains_key(&(namespace, name.to_string()))
+  }
+
+  pub fn with_global_elements(
+    mut self,
+    global_elements: &BTreeMap<(String, String), GlobalElement>,
+  ) -> Self {
+    self.global_elements = global_elements.clone();
+    self
+  }
+
+  pub fn global_elements(&self) -> &BTreeMap<(String, String), GlobalElement> {
+    &self.global_elements
+  }
+
+  /// Resolve a `ref` attribute, like `prefix:name`, to a global element.
+  pub fn get_global_element(&self, reference: &str) -> Option<&GlobalElement> {
+    Self::get_definition(&self.global_elements, self.resolve_name(reference))
+  }
+
+  pub fn with_attributes(mut self, attributes: &BTreeMap<(String, String), Attribute>) -> Self {
+    self.attributes = attributes.clone();
+    self
+  }
+
+  pub fn attributes(&self) -> &BTreeMap<(String, String), Attribute> {
+    &self.attributes
+  }
+
+  /// Resolve a `ref` attribute, like `prefix:name`, to a global attribute.
+  pub fn get_attribute(&self, reference: &str) -> Option<&Attribute> {
+    Self::get_definition(&self.attributes, self.resolve_name(reference))
+  }
+
+  pub fn with_attribute_groups(
+    mut self,
+    attribute_groups: &BTreeMap<(String, String), AttributeGroup>,
+  ) -> Self {
+    self.attribute_groups = attribute_groups.clone();
+    self
+  }
+
+  pub fn attribute_groups(&self) -> &BTreeMap<(String, String), AttributeGroup> {
+    &self.attribute_groups
+  }
+
+  /// Resolve a `ref` attribute, like `prefix:name`, to a named attribute group.
+  pub fn get_attribute_group(&self, reference: &str) -> Option<&AttributeGroup> {
+    Self::get_definition(&self.attribute_groups, self.resolve_name(reference))
+  }
+
+  pub fn with_groups(mut self, groups: &BTreeMap<(String, String), Group>) -> Self {
+    self.groups = groups.clone();
+    self
+  }
+
+  pub fn groups(&self) -> &BTreeMap<(String, String), Group> {
+    &self.groups
+  }
+
+  /// Resolve a `ref` attribute, like `prefix:name`, to a named model group.
+  pub fn get_group(&self, reference: &str) -> Option<&Group> {
+    Self::get_definition(&self.groups, self.resolve_name(reference))
+  }
+
+  pub fn with_complex_types(
+    mut self,
+    complex_types: &BTreeMap<(String, String), ComplexType>,
+  ) -> Self {
+    self.complex_types = complex_types.clone();
+    self
+  }
+
+  pub fn complex_types(&self) -> &BTreeMap<(String, String), ComplexType> {
+    &self.complex_types
+  }
+
+  /// Resolve a type name, like `prefix:name`, to a global complex type.
+  pub fn get_complex_type(&self, reference: &str) -> Option<&ComplexType> {
+    Self::get_definition(&self.complex_types, self.resolve_name(reference))
+  }
+
+  pub fn with_simple_types(
+    mut self,
+    simple_types: &BTreeMap<(String, String), SimpleType>,
+  ) -> Self {
+    self.simple_types = simple_types.clone();
+    self
+  }
+
+  pub fn simple_types(&self) -> &BTreeMap<(String, String), SimpleType> {
+    &self.simple_types
+  }
+
+  /// Resolve a type name, like `prefix:name`, to a global simple type.
+  pub fn get_simple_type(&self, reference: &str) -> Option<&SimpleType> {
+    Self::get_definition(&self.simple_types, self.resolve_name(reference))
+  }
+
+  /// Namespace and local name of a qualified name.
+  fn resolve_name(&self, qualified_name: &str) -> (String, String) {
+    let (prefix, name) = match qualified_name.split_once(':') {
+      Some((prefix, name)) => (prefix, name),
+      None => ("", qualified_name),
+    };
+
+    let namespace = self.namespace.get(prefix).unwrap_or_default().to_string();
+    (namespace, name.to_string())
+  }
+
+  /// Definitions of a schema without target namespace are found for any namespace.
+  fn get_definition<T>(
+    definitions: &BTreeMap<(String, String), T>,
+    (namespace, name): (String, String),
+  ) -> Option<&T> {
+    definitions
+      .get(&(namespace, name.clone()))
+      .or_else(|| definitions.get(&("".to_string(), name)))
+  }
+
+  pub fn has_xml_schema_prefix(&self) -> bool {
+    self.xml_schema_prefix.is_some()
+  }
+
+  pub fn match_xml_schema_prefix(&self, value: &str) -> bool {
+    self.xml_schema_prefix == Some(value.to_string())
+  }
+
+  /// Prefix declared in the schema for a namespace.
+  pub fn get_prefix(&self, namespace: &str) -> Option<String> {
+    self
+      .namespace
+      .0
+      .iter()
+      .find(|(prefix, uri)| !prefix.is_empty() && uri.as_str() == namespace)
+      .map(|(prefix, _)| prefix.to_owned())
+  }
+
+  /// Qualified name of the schema generated in the `module` import, like `prefix:name`, with a
+  /// prefix of this schema. A namespace it does not declare is declared with a new prefix, its
+  /// types being found in the module of the import.
+  pub fn import_name(&mut self, import: &XsdContext, module: &str, qualified_name: &str) -> String {
+    let (namespace, name) = import.resolve_name(qualified_name);
+
+    if namespace == XML_SCHEMA_NAMESPACE {
+      return match &self.xml_schema_prefix {
+        Some(prefix) => format!("{prefix}:{name}"),
+        None => name,
+      };
+    }
+    if namespace.is_empty() {
+      return name;
+    }
+
+    let prefix = match self.get_prefix(&namespace) {
+      Some(prefix) => prefix,
+      None => {
+        let mut prefix = qualified_name
+          .split_once(':')
+          .map(|(prefix, _)| prefix.to_string())
+          .unwrap_or_else(|| module.to_string());
+        let mut index = 1;
+        while self.namespace.get(&prefix).is_some() {
+          index += 1;
+          prefix = format!("{module}{index}");
+        }
+        self.namespace.put(prefix.clone(), namespace.clone());
+        prefix
+      }
+    };
+
+    if let Some(import_module) = import.module_namespace_mappings.get(&namespace) {
+      self
+        .module_namespace_mappings
+        .entry(namespace)
+        .or_insert_with(|| format!("{module}::types::{import_module}"));
+    }
+
+    format!("{prefix}:{name}")
+  }
+
+  pub fn get_module(&self, prefix: &str) -> Option<String> {
+    self
+      .namespace
+      .get(prefix)
+      .map(|namespace| {
+        self
+          .module_namespace_mappings
+          .get(namespace)
+          .map(|module| module.to_owned())
+      })
+      .unwrap_or_else(|| None)
+  }
+}
+
+/// Line and column of the start tag of the root element.
+fn root_position(content: &str, qualified_name: &str) -> (u64, u64) {
+  let offset = content
+    .find(&format!("<{qualified_name}"))
+    .unwrap_or_default();
+  let before = &content[..offset];
+  let line = before.matches('\n').count() as u64 + 1;
+  let column = before
+    .rsplit('\n')
+    .next()
+    .unwrap_or_default()
+    .chars()
+    .count() as u64
+    + 1;
+
+  (line, column)
+}
+
+#[test]
+fn get_module() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:example="http://example.com"
+      >
+    </xs:schema>
+  "#,
+  )
+  .unwrap();
+
+  let mut mapping = BTreeMap::new();
+  mapping.insert(
+    "http://example.com".to_string(),
+    "crate::example".to_string(),
+  );
+  let context = context.with_module_namespace_mappings(&mapping);
+
+  assert_eq!(
+    context.get_module("example"),
+    Some("crate::example".to_string())
+  );
+  assert_eq!(context.get_module("other"), None);
+}
+
+#[test]
+fn get_global_element() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:example="http://example.com"
+      >
+    </xs:schema>
+  "#,
+  )
+  .unwrap();
+
+  let element = GlobalElement {
+    name: "Title".to_string(),
+    rust_type: syn::parse_quote!(String),
+    generated: false,
+    builtin: true,
+  };
+
+  let mut global_elements = BTreeMap::new();
+  global_elements.insert(
+    ("http://example.com".to_string(), "Title".to_string()),
+    element.clone(),
+  );
+  let context = context.with_global_elements(&global_elements);
+
+  assert_eq!(context.get_global_element("example:Title"), Some(&element));
+  assert_eq!(context.get_global_element("Title"), None);
+  assert_eq!(context.get_global_element("example:Other"), None);
+}
+
+#[test]
+fn global_element_in_module() {
+  let in_module = |rust_type: Type, generated: bool| {
+    let element = GlobalElement {
+      name: "Address".to_string(),
+      rust_type,
+      generated,
+      builtin: false,
+    };
+    let rust_type = element.in_module("common").rust_type;
+    quote!(#rust_type).to_string()
+  };
+
+  assert_eq!(
+    in_module(syn::parse_quote!(Address), true),
+    "common :: types :: Address"
+  );
+  assert_eq!(
+    in_module(syn::parse_quote!(super::Person), true),
+    "common :: Person"
+  );
+  assert_eq!(
+    in_module(syn::parse_quote!(other::types::Address), true),
+    "common :: types :: other :: types :: Address"
+  );
+  assert_eq!(
+    in_module(syn::parse_quote!(crate::Address), false),
+    "crate :: Address"
+  );
+  assert_eq!(
+    in_module(syn::parse_quote!(::chrono::NaiveDate), true),
+    ":: chrono :: NaiveDate"
+  );
+}
+
+#[test]
+fn resolve_type_mappings() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:tns="http://example.com"
+      >
+    </xs:schema>
+  "#,
+  )
+  .unwrap();
+
+  let mut type_mappings = BTreeMap::new();
+  type_mappings.insert("tns:Timecode".to_string(), "crate::Timecode".to_string());
+  let resolved = context.resolve_type_mappings(&type_mappings).unwrap();
+  let context = context.with_type_mappings(&resolved);
+
+  assert_eq!(
+    context.get_type_mapping("tns:Timecode"),
+    Some(&"crate::Timecode".to_string())
+  );
+  assert!(context.has_type_mapping(&Some("http://example.com".to_string()), "Timecode"));
+  assert!(!context.has_type_mapping(&None, "Timecode"));
+
+  type_mappings.insert("other:Name".to_string(), "String".to_string());
+  assert!(context.resolve_type_mappings(&type_mappings).is_err());
+}
+
+#[test]
+fn locate() {
+  let context = XsdContext::new(
+    r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+  <xs:complexType name="Person">
+    <xs:attribute name="birth" type="xs:date"/>
+  </xs:complexType>
+</xs:schema>"#,
+  )
+  .unwrap();
+
+  assert_eq!(context.locate(&["type"], "xs:date"), None);
+
+  let context = context.with_location("person.xsd");
+  let location = context.locate(&["type"], "xs:date").unwrap();
+  assert_eq!(location.file, "person.xsd");
+  assert_eq!(location.position, Some((3, 5)));
+
+  assert_eq!(context.locate(&["name"], "Other").unwrap().position, None);
+  assert_eq!(
+    context.locate(&["name"], "Person").unwrap().position,
+    Some((2, 3))
+  );
+  assert_eq!(context.locate_schema().unwrap().position, Some((1, 1)));
+
+  let context = XsdContext::new(
+    r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+  <xs:complexType name="Person">
+    <xs:attribute name="code" type="xs:string"/>
+  </xs:complexType>
+  <xs:complexType name="Place">
+    <xs:attribute name="code" type="xs:string"/>
+    <xs:attribute name="zone" type="xs:string"/>
+  </xs:complexType>
+</xs:schema>"#,
+  )
+  .unwrap()
+  .with_location("place.xsd");
+
+  {
+    let _scope = context.enter("complexType", "name", "Place");
+    assert_eq!(
+      context.locate(&["name"], "code").unwrap().position,
+      Some((6, 5))
+    );
+
+    let _scope = context.enter("attribute", "name", "zone");
+    assert_eq!(
+      context.locate(&["type"], "xs:string").unwrap().position,
+      Some((7, 5))
+    );
+    assert_eq!(
+      context.locate(&["name"], "Person").unwrap().position,
+      Some((2, 3))
+    );
+  }
+  assert_eq!(
+    context.locate(&["name"], "code").unwrap().position,
+    Some((3, 5))
+  );
+
+  let context = XsdContext::new(
+    r#"<?xml version="1.0"?>
+  <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#,
+  )
+  .unwrap()
+  .with_location("empty.xsd");
+  assert_eq!(context.locate_schema().unwrap().position, Some((2, 3)));
+}
+
+#[test]
+fn bad_schema_definition() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns="http://www.w3.org/2001/XMLSchema"
+      >
+    </xs:schema>
+  "#,
+  );
+
+  assert!(context.is_err());
+}
+
+#[test]
+fn import_name() {
+  let mut context = XsdContext::new(
+    r#"<xsd:schema xmlns:xsd="http://www.w3.org/2001/XMLSchema" xmlns:common="http://example.com/common"></xsd:schema>"#,
+  )
+  .unwrap();
+  let import = XsdContext::new(
+    r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns:c="http://example.com/common" xmlns:other="http://example.com/other"></xs:schema>"#,
+  )
+  .unwrap();
+
+  let mut module_namespace_mappings = BTreeMap::new();
+  module_namespace_mappings.insert(
+    "http://example.com/other".to_string(),
+    "other::types".to_string(),
+  );
+  let import = import.with_module_namespace_mappings(&module_namespace_mappings);
+
+  assert_eq!(
+    context.import_name(&import, "common", "xs:string"),
+    "xsd:string"
+  );
+  assert_eq!(
+    context.import_name(&import, "common", "c:Code"),
+    "common:Code"
+  );
+  assert_eq!(
+    context.import_name(&import, "common", "other:Code"),
+    "other:Code"
+  );
+  assert_eq!(
+    context.get_module("other"),
+    Some("common::types::other::types".to_string())
+  );
+}
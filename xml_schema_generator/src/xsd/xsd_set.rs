@@ -0,0 +1,312 @@
+use super::{get_module_name, ImportedXsd, Loaded, Xsd};
+use crate::errors::Error;
+use crate::loader::Loader;
+use heck::SnakeCase;
+use proc_macro2::TokenStream;
+use std::collections::{BTreeMap, BTreeSet};
+use syn::Visibility;
+
+/// Schemas of several sources, generated in a module per target namespace. A schema imported by
+/// several of them is generated once, the modules importing it re-export its module.
+///
+/// The prefix of a target namespace is the one given to its source, or the one declared in the
+/// schema, or the module name.
+#[derive(Clone, Debug)]
+pub struct XsdSet {
+  name: String,
+  vis: Visibility,
+  schemas: Vec<ImportedXsd>,
+  sources: BTreeSet<String>,
+}
+
+impl XsdSet {
+  pub fn new_from_files(
+    name: String,
+    vis: Visibility,
+    sources: &[String],
+    target_prefixes: &BTreeMap<String, String>,
+    module_namespace_mappings: &BTreeMap<String, String>,
+    loader: &Loader,
+  ) -> Result<Self, String> {
+    let mut loaded = Loaded::default();
+    let mut schemas: Vec<ImportedXsd> = vec![];
+
+    for source in sources {
+      let location = loader.resolve(source);
+      // already loaded as the import of a previous source
+      if loaded.sources.contains(&location) {
+        continue;
+      }
+
+      let xsd = Xsd::load(
+        get_module_name(&location),
+        vis.clone(),
+        source,
+        module_namespace_mappings,
+        loader,
+        &mut loaded,
+      )?;
+
+      let target_prefix = xsd.schema.target_namespace.as_ref().map(|namespace| {
+        xsd
+          .context
+          .get_prefix(namespace)
+          .unwrap_or_else(|| xsd.name.clone())
+      });
+
+      let schema = ImportedXsd {
+        target_prefix,
+        xsd,
+        shared: true,
+      };
+
+      if let Some(namespace) = &schema.xsd.schema.target_namespace {
+        if let Some(defined) = loaded.shared.get(namespace) {
+          return Err(format!(
+            "{source}: namespace {namespace} is already defined in module {}, include one of the schemas in the other",
+            defined.xsd.name
+          ));
+        }
+        loaded.shared.insert(namespace.clone(), schema.clone());
+      }
+
+      schemas.push(schema);
+    }
+
+    for imported in std::mem::take(&mut loaded.shared).into_values() {
+      let namespace = &imported.xsd.schema.target_namespace;
+      if !schemas
+        .iter()
+        .any(|schema| &schema.xsd.schema.target_namespace == namespace)
+      {
+        schemas.push(imported);
+      }
+    }
+
+    // a source can be loaded as the import of a previous one, its prefix is set once all loaded
+    let target_prefixes: BTreeMap<String, &String> = target_prefixes
+      .iter()
+      .map(|(source, target_prefix)| (loader.resolve(source), target_prefix))
+      .collect();
+    for schema in &mut schemas {
+      let target_prefix = schema
+        .xsd
+        .context
+        .location()
+        .and_then(|location| target_prefixes.get(location));
+      if let Some(target_prefix) = target_prefix {
+        schema.target_prefix = Some(target_prefix.to_string());
+      }
+    }
+
+    let mut module_names = BTreeSet::new();
+    for schema in &schemas {
+      if !module_names.insert(&schema.xsd.name) {
+        return Err(format!(
+          "module {} is generated for two schemas, rename one of their files",
+          schema.xsd.name
+        ));
+      }
+    }
+
+    Ok(XsdSet {
+      name,
+      vis,
+      schemas,
+      sources: loaded.sources,
+    })
+  }
+
+  /// Paths and URLs of the schemas loaded, with the included and imported ones.
+  pub fn sources(&self) -> &BTreeSet<String> {
+    &self.sources
+  }
+
+  pub fn with_serialize_default_attributes(mut self, serialize_default_attributes: bool) -> Self {
+    self.schemas = self
+      .schemas
+      .into_iter()
+      .map(|schema| ImportedXsd {
+        xsd: schema
+          .xsd
+          .with_serialize_default_attributes(serialize_default_attributes),
+        ..schema
+      })
+      .collect();
+
+    self
+  }
+
+  /// Substitute Rust types to XML Schema types, named with the prefixes of the first source.
+  pub fn with_type_mappings(
+    mut self,
+    type_mappings: &BTreeMap<String, String>,
+  ) -> Result<Self, Error> {
+    let type_mappings = match self.schemas.first() {
+      Some(schema) => schema.xsd.context.resolve_type_mappings(type_mappings)?,
+      None => BTreeMap::new(),
+    };
+
+    self.schemas = self
+      .schemas
+      .into_iter()
+      .map(|schema| ImportedXsd {
+        xsd: schema.xsd.with_resolved_type_mappings(&type_mappings),
+        ..schema
+      })
+      .collect();
+
+    Ok(self)
+  }
+
+  pub fn implement(&self) -> TokenStream {
+    let modules: TokenStream = self
+      .schemas
+      .iter()
+      .map(|schema| {
+        let content = schema.xsd.implement_schema(&schema.target_prefix);
+        let mod_name = format_ident!("{}", schema.xsd.name);
+
+        quote!(
+          pub mod #mod_name {
+            #content
+          }
+        )
+      })
+      .collect();
+
+    let mod_name = format_ident!("{}", self.name.to_snake_case());
+    let vis = &self.vis;
+
+    quote! {
+        mod #mod_name {
+            #modules
+        }
+
+        #vis use #mod_name::*;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn load(sources: &[&str]) -> Result<XsdSet, String> {
+    let sources: Vec<String> = sources.iter().map(|source| source.to_string()).collect();
+    XsdSet::new_from_files(
+      "Schemas".to_string(),
+      syn::parse_quote!(pub),
+      &sources,
+      &BTreeMap::new(),
+      &BTreeMap::new(),
+      &Loader::new(),
+    )
+  }
+
+  #[test]
+  fn module_per_namespace() {
+    let xsd_set = load(&[
+      "../xml_schema/tests/schema_set_customer.xsd",
+      "../xml_schema/tests/schema_set_order.xsd",
+    ])
+    .unwrap();
+
+    let module_names: Vec<&str> = xsd_set
+      .schemas
+      .iter()
+      .map(|schema| schema.xsd.name.as_str())
+      .collect();
+    assert_eq!(
+      module_names,
+      vec!["schema_set_customer", "schema_set_order", "import_common"]
+    );
+
+    let implementation = xsd_set.implement().to_string();
+    assert_eq!(implementation.matches("pub mod import_common").count(), 1);
+    assert_eq!(
+      implementation
+        .matches("pub use super :: super :: import_common ;")
+        .count(),
+      2
+    );
+    assert!(implementation.contains("pub use super :: super :: schema_set_customer ;"));
+  }
+
+  #[test]
+  fn target_prefix_of_imported_source() {
+    let sources = [
+      "../xml_schema/tests/schema_set_customer.xsd".to_string(),
+      "../xml_schema/tests/import_common.xsd".to_string(),
+    ];
+    let target_prefixes = BTreeMap::from([(
+      "../xml_schema/tests/import_common.xsd".to_string(),
+      "shared".to_string(),
+    )]);
+    let xsd_set = XsdSet::new_from_files(
+      "Schemas".to_string(),
+      syn::parse_quote!(pub),
+      &sources,
+      &target_prefixes,
+      &BTreeMap::new(),
+      &Loader::new(),
+    )
+    .unwrap();
+
+    let target_prefixes: Vec<(&str, Option<&str>)> = xsd_set
+      .schemas
+      .iter()
+      .map(|schema| (schema.xsd.name.as_str(), schema.target_prefix.as_deref()))
+      .collect();
+    assert_eq!(
+      target_prefixes,
+      vec![
+        ("schema_set_customer", Some("cust")),
+        ("import_common", Some("shared"))
+      ]
+    );
+  }
+
+  #[test]
+  fn same_source_by_several_paths() {
+    let xsd_set = load(&[
+      "../xml_schema/tests/import_common.xsd",
+      "../xml_schema/tests/./import_common.xsd",
+      "../xml_schema/tests/schema_set_customer.xsd",
+    ])
+    .unwrap();
+
+    let module_names: Vec<&str> = xsd_set
+      .schemas
+      .iter()
+      .map(|schema| schema.xsd.name.as_str())
+      .collect();
+    assert_eq!(module_names, vec!["import_common", "schema_set_customer"]);
+  }
+
+  #[test]
+  fn duplicated_namespace() {
+    let directory = std::env::temp_dir().join(format!(
+      "xml_schema_generator_duplicated_namespace_{}",
+      std::process::id()
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+    let schema = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" targetNamespace="http://example.com/common"/>"#;
+    let first = directory.join("first.xsd");
+    let second = directory.join("second.xsd");
+    std::fs::write(&first, schema).unwrap();
+    std::fs::write(&second, schema).unwrap();
+
+    let error = load(&[first.to_str().unwrap(), second.to_str().unwrap()]).unwrap_err();
+
+    assert_eq!(
+      error,
+      format!(
+        "{}: namespace http://example.com/common is already defined in module first, include one of the schemas in the other",
+        second.display()
+      )
+    );
+
+    std::fs::remove_dir_all(directory).unwrap();
+  }
+}
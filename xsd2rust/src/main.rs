@@ -0,0 +1,73 @@
+//! Command line generator of the Rust types of XSD - XML Schema - files, writing a module tree
+//! to inspect or to vendor the generated code.
+
+mod options;
+
+use options::{Command, Options, USAGE};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command as Process};
+
+fn main() {
+  let options = match Command::parse(std::env::args().skip(1)) {
+    Ok(Command::Generate(options)) => options,
+    Ok(Command::Help) => {
+      println!("{USAGE}");
+      return;
+    }
+    Err(error) => {
+      eprintln!("error: {error}\n\n{USAGE}");
+      process::exit(2);
+    }
+  };
+
+  let _ = simple_logger::init_with_level(options.log_level);
+
+  if let Err(errors) = generate(&options) {
+    for error in errors {
+      eprintln!("error: {error}");
+    }
+    process::exit(1);
+  }
+}
+
+/// Write a file by generated module, and the `mod.rs` declaring them.
+fn generate(options: &Options) -> Result<(), Vec<String>> {
+  let to_strings = |errors: Vec<_>| errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+  let modules = options.builder.generate_modules().map_err(to_strings)?;
+
+  fs::create_dir_all(&options.output)
+    .map_err(|e| vec![format!("{}: {}", options.output.display(), e)])?;
+
+  let mut files = vec![];
+  let mut declarations = String::new();
+  for (module_name, code) in &modules {
+    files.push(write(&options.output, &format!("{module_name}.rs"), code)?);
+    declarations.push_str(&format!("pub mod {module_name};\n"));
+  }
+  files.push(write(&options.output, "mod.rs", &declarations)?);
+
+  rustfmt(&files);
+  Ok(())
+}
+
+fn write(directory: &Path, file_name: &str, content: &str) -> Result<PathBuf, Vec<String>> {
+  let path = directory.join(file_name);
+  fs::write(&path, content).map_err(|e| vec![format!("{}: {}", path.display(), e)])?;
+  log::info!("Generated {}", path.display());
+  Ok(path)
+}
+
+/// Format the files with rustfmt when it is installed, to follow the configuration of the project
+/// they are written in.
+fn rustfmt(files: &[PathBuf]) {
+  match Process::new("rustfmt")
+    .args(["--edition", "2018"])
+    .args(files)
+    .status()
+  {
+    Ok(status) if status.success() => {}
+    Ok(status) => log::warn!("rustfmt failed: {}", status),
+    Err(error) => log::warn!("rustfmt is not available: {}", error),
+  }
+}
@@ -0,0 +1,181 @@
+use std::path::PathBuf;
+use xml_schema_generator::Builder;
+
+pub const USAGE: &str = "Generate the Rust types of XSD - XML Schema - files.
+
+Usage: xsd2rust [OPTIONS] --output <DIRECTORY> <SOURCE> [--target-prefix <PREFIX>] [<SOURCE> ...]
+
+Each source, a local file or an HTTP resource, is generated in a module file of the output
+directory, declared in its mod.rs.
+
+Options:
+  -o, --output <DIRECTORY>                   Directory of the generated modules
+      --target-prefix <PREFIX>               Prefix of the target namespace of the previous source
+      --module-namespace-mapping <MAPPING>   Map a namespace to a Rust module, as \"namespace: module\"
+      --type-mapping <MAPPING>               Substitute a Rust type to an XML Schema type, as \"xs:type: rust::Type\"
+      --serialize-default-attributes         Always serialize the attributes with a default value, as Defaulted fields
+      --catalog <FILE>                       Map the remote schemas to local files with an XML Catalog
+      --cache-directory <DIRECTORY>          Store the remote schemas in a directory, and load them from it
+      --offline                              Fail on the remote schemas neither in the catalog nor cached
+      --log-level <LEVEL>                    One of error, warn, info, debug, trace (warn by default)
+  -h, --help                                 Print this help";
+
+/// Command line options, with the same meaning as the `xml_schema` attribute ones.
+#[derive(Debug)]
+pub struct Options {
+  pub builder: Builder,
+  pub log_level: log::Level,
+  pub output: PathBuf,
+}
+
+/// Command to run, parsed from the arguments.
+#[derive(Debug)]
+pub enum Command {
+  Generate(Options),
+  Help,
+}
+
+impl Command {
+  pub fn parse<I: IntoIterator<Item = String>>(arguments: I) -> Result<Command, String> {
+    let mut builder = Builder::new();
+    let mut has_source = false;
+    let mut log_level = log::Level::Warn;
+    let mut output = None;
+
+    let mut arguments = arguments.into_iter();
+    while let Some(argument) = arguments.next() {
+      match argument.as_str() {
+        "-h" | "--help" => return Ok(Command::Help),
+        "--serialize-default-attributes" => {
+          builder = builder.with_serialize_default_attributes(true);
+        }
+        "--offline" => builder = builder.with_offline(true),
+        "--catalog" => builder = builder.with_catalog(&get_value(&mut arguments, &argument)?),
+        "--cache-directory" => {
+          builder = builder.with_cache_directory(&get_value(&mut arguments, &argument)?);
+        }
+        "-o" | "--output" => output = Some(PathBuf::from(get_value(&mut arguments, &argument)?)),
+        "--target-prefix" => {
+          if !has_source {
+            return Err("--target-prefix must follow the source it applies to".to_string());
+          }
+          builder = builder.with_target_prefix(&get_value(&mut arguments, &argument)?);
+        }
+        "--module-namespace-mapping" => {
+          let value = get_value(&mut arguments, &argument)?;
+          builder = match value.split_once(": ") {
+            Some((namespace, module)) => builder.with_module_namespace_mapping(namespace, module),
+            None => builder.with_module_namespace_mapping("", &value),
+          };
+        }
+        "--type-mapping" => {
+          let value = get_value(&mut arguments, &argument)?;
+          let (xml_type, rust_type) = value.split_once(": ").ok_or_else(|| {
+            format!("Bad type mapping: {value}, expected \"prefix:name: rust::Type\"")
+          })?;
+          builder = builder.with_type_mapping(xml_type, rust_type.trim());
+        }
+        "--log-level" => {
+          let value = get_value(&mut arguments, &argument)?;
+          log_level = match value.as_str() {
+            "trace" => log::Level::Trace,
+            "debug" => log::Level::Debug,
+            "info" => log::Level::Info,
+            "warn" => log::Level::Warn,
+            "error" => log::Level::Error,
+            _ => {
+              return Err(format!(
+                "Bad log level: {value}, expected one of trace, debug, info, warn, error"
+              ))
+            }
+          };
+        }
+        option if option.starts_with('-') => return Err(format!("Unknown option: {option}")),
+        source => {
+          builder = builder.with_source(source);
+          has_source = true;
+        }
+      }
+    }
+
+    if !has_source {
+      return Err("Missing source".to_string());
+    }
+
+    let output = output.ok_or_else(|| "Missing --output directory".to_string())?;
+
+    Ok(Command::Generate(Options {
+      builder,
+      log_level,
+      output,
+    }))
+  }
+}
+
+fn get_value(arguments: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+  arguments
+    .next()
+    .ok_or_else(|| format!("Missing value for {option}"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(arguments: &[&str]) -> Result<Command, String> {
+    Command::parse(arguments.iter().map(|argument| argument.to_string()))
+  }
+
+  #[test]
+  fn parse_options() {
+    let command = parse(&[
+      "schema.xsd",
+      "--target-prefix",
+      "sc",
+      "--output",
+      "generated",
+      "--log-level",
+      "debug",
+      "--type-mapping",
+      "xs:boolean: bool",
+    ])
+    .unwrap();
+
+    match command {
+      Command::Generate(options) => {
+        assert_eq!(options.log_level, log::Level::Debug);
+        assert_eq!(options.output, PathBuf::from("generated"));
+      }
+      Command::Help => panic!("expected generate command"),
+    }
+  }
+
+  #[test]
+  fn parse_help() {
+    assert!(matches!(parse(&["schema.xsd", "-h"]), Ok(Command::Help)));
+  }
+
+  #[test]
+  fn parse_errors() {
+    assert_eq!(parse(&["-o", "generated"]).unwrap_err(), "Missing source");
+    assert_eq!(
+      parse(&["schema.xsd"]).unwrap_err(),
+      "Missing --output directory"
+    );
+    assert_eq!(
+      parse(&["--target-prefix", "sc", "schema.xsd"]).unwrap_err(),
+      "--target-prefix must follow the source it applies to"
+    );
+    assert_eq!(
+      parse(&["schema.xsd", "--log-level"]).unwrap_err(),
+      "Missing value for --log-level"
+    );
+    assert_eq!(
+      parse(&["schema.xsd", "--verbose"]).unwrap_err(),
+      "Unknown option: --verbose"
+    );
+    assert!(parse(&["schema.xsd", "--type-mapping", "bool"])
+      .unwrap_err()
+      .starts_with("Bad type mapping: bool"));
+  }
+}